@@ -17,7 +17,7 @@ fn scatter_mapbox() {
             Mapbox::new()
                 .style(MapboxStyle::OpenStreetMap)
                 .center(Center::new(45.5017, -73.5673))
-                .zoom(5),
+                .zoom(5.0),
         );
 
     let mut plot = Plot::new();
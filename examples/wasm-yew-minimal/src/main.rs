@@ -1,10 +1,9 @@
-use plotly::{Plot, Scatter};
+use plotly::{bindings::events::PointsEvent, yew::PlotlyChart, Plot, Scatter};
 use yew::prelude::*;
 
 #[function_component(App)]
 pub fn plot_component() -> Html {
-    let p = yew_hooks::use_async::<_, _, ()>({
-        let id = "plot-div";
+    let plot = use_memo((), |_| {
         let mut plot = Plot::new();
         let trace = Scatter::new(vec![0, 1, 2], vec![2, 1, 0]);
         plot.add_trace(trace);
@@ -12,27 +11,18 @@ pub fn plot_component() -> Html {
         let layout =
             plotly::Layout::new().title(plotly::common::Title::new("Displaying a Chart in Yew"));
         plot.set_layout(layout);
-
-        async move {
-            plotly::bindings::new_plot(id, &plot).await;
-            Ok(())
-        }
+        plot
+    });
+    let on_click = use_callback((), |event: PointsEvent, _| {
+        log::info!("Clicked {:?}", event.points);
     });
-
-    use_effect_with_deps(
-        move |_| {
-            p.run();
-            || ()
-        },
-        (),
-    );
 
     html! {
-        <div id="plot-div"></div>
+        <PlotlyChart plot={plot} on_click={on_click} />
     }
 }
 
 fn main() {
     wasm_logger::init(wasm_logger::Config::default());
-    yew::start_app::<App>();
+    yew::Renderer::<App>::new().render();
 }
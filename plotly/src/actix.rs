@@ -0,0 +1,167 @@
+//! [actix-web](https://actix.rs) integration, enabled by the `actix` feature.
+//!
+//! A [`Plot`] returned from a handler is rendered in the format preferred by
+//! the `Accept` header of the request, see
+//! [`PlotFormat::from_accept`](crate::response::PlotFormat::from_accept). A
+//! handler can also choose the format itself by returning a [`PlotResponse`].
+//!
+//! Both render the plot on the async worker. Images rendered with Kaleido
+//! block it, so a handler which may return them should take an
+//! [`AcceptedFormat`] argument, call [`AcceptedFormat::render`] with
+//! `actix_web::web::block` instead, and return the [`RenderedPlot`].
+//!
+//! ```no_run
+//! use actix_web::{web, App};
+//! use plotly::{
+//!     response::{AcceptedFormat, RenderedPlot},
+//!     Plot, Scatter,
+//! };
+//!
+//! async fn plot() -> Plot {
+//!     let mut plot = Plot::new();
+//!     plot.add_trace(Scatter::new(vec![0, 1, 2], vec![2, 1, 0]));
+//!     plot
+//! }
+//!
+//! async fn any_format(format: AcceptedFormat) -> actix_web::Result<RenderedPlot> {
+//!     // A `Plot` cannot be sent to another thread, so it is built there.
+//!     let rendered = web::block(move || {
+//!         let mut plot = Plot::new();
+//!         plot.add_trace(Scatter::new(vec![0, 1, 2], vec![2, 1, 0]));
+//!         format.render(&plot)
+//!     })
+//!     .await??;
+//!     Ok(rendered)
+//! }
+//!
+//! let app = App::new()
+//!     .route("/plot", web::get().to(plot))
+//!     .route("/plot/any", web::get().to(any_format))
+//!     .route("/plotly.min.js", web::get().to(plotly::actix::plotly_js));
+//! ```
+
+use std::{
+    convert::Infallible,
+    future::{ready, Ready},
+};
+
+use actix_web::{
+    body::BoxBody,
+    dev::Payload,
+    http::header::{ACCEPT, CACHE_CONTROL, CONTENT_TYPE, VARY},
+    FromRequest, HttpRequest, HttpResponse, Responder, ResponseError,
+};
+
+use crate::{
+    plot::PLOTLY_JS,
+    response::{
+        AcceptedFormat, PlotResponse, RenderError, RenderedPlot, PLOTLY_JS_CACHE_CONTROL,
+        PLOTLY_JS_CONTENT_TYPE,
+    },
+    Plot,
+};
+
+fn accepted_format(req: &HttpRequest) -> AcceptedFormat {
+    AcceptedFormat::from_accept(req.headers().get(ACCEPT).and_then(|v| v.to_str().ok()))
+}
+
+impl Responder for Plot {
+    type Body = BoxBody;
+
+    fn respond_to(self, req: &HttpRequest) -> HttpResponse {
+        PlotResponse::accepted(self, accepted_format(req)).respond_to(req)
+    }
+}
+
+impl Responder for PlotResponse {
+    type Body = BoxBody;
+
+    fn respond_to(self, req: &HttpRequest) -> HttpResponse {
+        match self.render() {
+            Ok(rendered) => rendered.respond_to(req),
+            Err(e) => e.error_response(),
+        }
+    }
+}
+
+impl Responder for RenderedPlot {
+    type Body = BoxBody;
+
+    fn respond_to(self, _req: &HttpRequest) -> HttpResponse {
+        let mut response = HttpResponse::Ok();
+        response.insert_header((CONTENT_TYPE, self.format().content_type()));
+        if self.vary_accept() {
+            response.insert_header((VARY, "accept"));
+        }
+        response.body(self.into_body())
+    }
+}
+
+/// Returned as `500 Internal Server Error`.
+impl ResponseError for RenderError {}
+
+impl FromRequest for AcceptedFormat {
+    type Error = Infallible;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        ready(Ok(accepted_format(req)))
+    }
+}
+
+/// A handler serving the plotly.js bundled with this crate, for pages which
+/// embed plots with `Plot::to_inline_html` without network access to a CDN.
+pub async fn plotly_js() -> HttpResponse {
+    HttpResponse::Ok()
+        .insert_header((CONTENT_TYPE, PLOTLY_JS_CONTENT_TYPE))
+        .insert_header((CACHE_CONTROL, PLOTLY_JS_CACHE_CONTROL))
+        .body(PLOTLY_JS)
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{http::StatusCode, test::TestRequest};
+
+    use super::*;
+    use crate::{response::PlotFormat, Scatter};
+
+    fn plot() -> Plot {
+        let mut plot = Plot::new();
+        plot.add_trace(Scatter::new(vec![1, 2], vec![3, 4]));
+        plot
+    }
+
+    #[test]
+    fn test_plot_respond_to() {
+        let req = TestRequest::default().to_http_request();
+        let response = plot().respond_to(&req);
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get(CONTENT_TYPE).unwrap(),
+            "text/html; charset=utf-8"
+        );
+        assert_eq!(response.headers().get(VARY).unwrap(), "accept");
+
+        let req = TestRequest::default()
+            .insert_header((ACCEPT, "application/vnd.plotly.v1+json"))
+            .to_http_request();
+        let response = plot().respond_to(&req);
+        assert_eq!(
+            response.headers().get(CONTENT_TYPE).unwrap(),
+            "application/vnd.plotly.v1+json"
+        );
+    }
+
+    #[test]
+    fn test_plot_response_respond_to() {
+        let req = TestRequest::default()
+            .insert_header((ACCEPT, "application/json"))
+            .to_http_request();
+        let response = PlotResponse::new(plot(), PlotFormat::InlineHtml).respond_to(&req);
+        assert_eq!(
+            response.headers().get(CONTENT_TYPE).unwrap(),
+            "text/html; charset=utf-8"
+        );
+        assert!(response.headers().get(VARY).is_none());
+    }
+}
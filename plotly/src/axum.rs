@@ -0,0 +1,135 @@
+//! [axum](https://github.com/tokio-rs/axum) integration, enabled by the `axum`
+//! feature.
+//!
+//! As axum responses cannot read the request, a [`Plot`](crate::Plot) is returned from a
+//! handler as a [`PlotResponse`]. Handlers which take an [`AcceptedFormat`]
+//! argument get the format preferred by the `Accept` header of the request,
+//! and return the plot with `PlotResponse::accepted` in that format; others
+//! choose the format with `PlotResponse::new`.
+//!
+//! Both render the plot on the async worker. Images rendered with Kaleido
+//! block it, so a handler which may return them should call
+//! [`AcceptedFormat::render`] with `tokio::task::spawn_blocking` instead, and
+//! return the [`RenderedPlot`].
+//!
+//! ```no_run
+//! use axum::{routing::get, Router};
+//! use plotly::{
+//!     response::{AcceptedFormat, PlotFormat, PlotResponse, RenderError, RenderedPlot},
+//!     Plot, Scatter,
+//! };
+//!
+//! fn plot() -> Plot {
+//!     let mut plot = Plot::new();
+//!     plot.add_trace(Scatter::new(vec![0, 1, 2], vec![2, 1, 0]));
+//!     plot
+//! }
+//!
+//! async fn html() -> PlotResponse {
+//!     PlotResponse::new(plot(), PlotFormat::Html)
+//! }
+//!
+//! async fn accepted(format: AcceptedFormat) -> PlotResponse {
+//!     PlotResponse::accepted(plot(), format)
+//! }
+//!
+//! async fn any_format(format: AcceptedFormat) -> Result<RenderedPlot, RenderError> {
+//!     // A `Plot` cannot be sent to another thread, so it is built there.
+//!     tokio::task::spawn_blocking(move || format.render(&plot()))
+//!         .await
+//!         .unwrap()
+//! }
+//!
+//! let app: Router = Router::new()
+//!     .route("/plot", get(html))
+//!     .route("/plot/accepted", get(accepted))
+//!     .route("/plot/any", get(any_format))
+//!     .route("/plotly.min.js", get(plotly::axum::plotly_js));
+//! ```
+
+use std::convert::Infallible;
+
+use axum::{
+    extract::FromRequestParts,
+    http::{
+        header::{ACCEPT, CACHE_CONTROL, CONTENT_TYPE, VARY},
+        request::Parts,
+        StatusCode,
+    },
+    response::{IntoResponse, Response},
+};
+
+use crate::{
+    plot::PLOTLY_JS,
+    response::{
+        AcceptedFormat, PlotResponse, RenderError, RenderedPlot, PLOTLY_JS_CACHE_CONTROL,
+        PLOTLY_JS_CONTENT_TYPE,
+    },
+};
+
+impl IntoResponse for PlotResponse {
+    fn into_response(self) -> Response {
+        self.render().into_response()
+    }
+}
+
+impl IntoResponse for RenderedPlot {
+    fn into_response(self) -> Response {
+        let content_type = [(CONTENT_TYPE, self.format().content_type())];
+        let vary = self.vary_accept().then_some([(VARY, "accept")]);
+        (content_type, vary, self.into_body()).into_response()
+    }
+}
+
+impl IntoResponse for RenderError {
+    fn into_response(self) -> Response {
+        (StatusCode::INTERNAL_SERVER_ERROR, self.to_string()).into_response()
+    }
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for AcceptedFormat {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let accept = parts.headers.get(ACCEPT).and_then(|v| v.to_str().ok());
+        Ok(AcceptedFormat::from_accept(accept))
+    }
+}
+
+/// A handler serving the plotly.js bundled with this crate, for pages which
+/// embed plots with `Plot::to_inline_html` without network access to a CDN.
+pub async fn plotly_js() -> impl IntoResponse {
+    (
+        [
+            (CONTENT_TYPE, PLOTLY_JS_CONTENT_TYPE),
+            (CACHE_CONTROL, PLOTLY_JS_CACHE_CONTROL),
+        ],
+        PLOTLY_JS,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{response::PlotFormat, Plot, Scatter};
+
+    #[test]
+    fn test_plot_response_into_response() {
+        let mut plot = Plot::new();
+        plot.add_trace(Scatter::new(vec![1, 2], vec![3, 4]));
+
+        let response = PlotResponse::new(plot.clone(), PlotFormat::Html).into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[CONTENT_TYPE], "text/html; charset=utf-8");
+        assert!(response.headers().get(VARY).is_none());
+
+        let format = AcceptedFormat::from_accept(Some("application/json"));
+        let response = PlotResponse::accepted(plot, format).into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[CONTENT_TYPE],
+            "application/vnd.plotly.v1+json"
+        );
+        assert_eq!(response.headers()[VARY], "accept");
+    }
+}
@@ -0,0 +1,133 @@
+//! State of a chart drawn by one of the UI framework components, independent
+//! of the framework.
+
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use wasm_bindgen::{prelude::*, JsCast};
+use web_sys::{HtmlElement, ResizeObserver};
+
+use super::events::{
+    self, EventListener, LegendClickEvent, PointsEvent, RelayoutEvent, RestyleEvent, SelectionEvent,
+};
+use crate::Plot;
+
+/// The event handlers passed to a component, converted from the callback type
+/// of the framework.
+#[derive(Default)]
+pub(crate) struct EventHandlers {
+    pub(crate) on_click: Option<Box<dyn FnMut(PointsEvent)>>,
+    pub(crate) on_hover: Option<Box<dyn FnMut(PointsEvent)>>,
+    pub(crate) on_unhover: Option<Box<dyn FnMut(PointsEvent)>>,
+    pub(crate) on_selected: Option<Box<dyn FnMut(Option<SelectionEvent>)>>,
+    pub(crate) on_relayout: Option<Box<dyn FnMut(RelayoutEvent)>>,
+    pub(crate) on_restyle: Option<Box<dyn FnMut(RestyleEvent)>>,
+    pub(crate) on_legend_click: Option<Box<dyn FnMut(LegendClickEvent) -> bool>>,
+    pub(crate) on_double_click: Option<Box<dyn FnMut()>>,
+}
+
+impl EventHandlers {
+    fn register(self, element: &HtmlElement) -> Result<Vec<EventListener>, JsValue> {
+        let mut listeners = Vec::new();
+        if let Some(handler) = self.on_click {
+            listeners.push(events::on_click(element, handler)?);
+        }
+        if let Some(handler) = self.on_hover {
+            listeners.push(events::on_hover(element, handler)?);
+        }
+        if let Some(handler) = self.on_unhover {
+            listeners.push(events::on_unhover(element, handler)?);
+        }
+        if let Some(handler) = self.on_selected {
+            listeners.push(events::on_selected(element, handler)?);
+        }
+        if let Some(handler) = self.on_relayout {
+            listeners.push(events::on_relayout(element, handler)?);
+        }
+        if let Some(handler) = self.on_restyle {
+            listeners.push(events::on_restyle(element, handler)?);
+        }
+        if let Some(handler) = self.on_legend_click {
+            listeners.push(events::on_legend_click(element, handler)?);
+        }
+        if let Some(handler) = self.on_double_click {
+            listeners.push(events::on_double_click(element, handler)?);
+        }
+        Ok(listeners)
+    }
+}
+
+/// A chart drawn in the `div` of a component. The chart follows the size of
+/// the `div`, and is purged when this is dropped, i.e. when the component is
+/// unmounted.
+pub(crate) struct Chart {
+    element: HtmlElement,
+    listeners: Rc<RefCell<Vec<EventListener>>>,
+    dropped: Rc<Cell<bool>>,
+    resize_observer: Option<ResizeObserver>,
+    _on_resize: Closure<dyn FnMut()>,
+}
+
+impl Chart {
+    pub(crate) fn new(element: HtmlElement) -> Self {
+        let target = element.clone();
+        let on_resize = Closure::<dyn FnMut()>::new(move || {
+            let target = target.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                // Fails if the chart has not been drawn yet, in which case it
+                // is sized when it is drawn.
+                let _ = super::resize(target).await;
+            });
+        });
+        // `ResizeObserver` is missing in old browsers, which then only resize
+        // the chart with the window if the `responsive` configuration is set.
+        let resize_observer = ResizeObserver::new(on_resize.as_ref().unchecked_ref()).ok();
+        if let Some(observer) = &resize_observer {
+            observer.observe(&element);
+        }
+        Self {
+            element,
+            listeners: Rc::new(RefCell::new(Vec::new())),
+            dropped: Rc::new(Cell::new(false)),
+            resize_observer,
+            _on_resize: on_resize,
+        }
+    }
+
+    /// Draw `plot` with the plotly.js `react` function, and replace the event
+    /// handlers once it has been drawn. Errors are logged to the console.
+    pub(crate) fn render(&self, plot: Plot, handlers: EventHandlers) {
+        let element = self.element.clone();
+        let listeners = self.listeners.clone();
+        let dropped = self.dropped.clone();
+        wasm_bindgen_futures::spawn_local(async move {
+            let result = super::react(&element, &plot).await;
+            if dropped.get() {
+                // The component was unmounted while the chart was drawn.
+                let _ = super::purge(&element);
+                return;
+            }
+            let result = result.and_then(|_| {
+                listeners.borrow_mut().clear();
+                *listeners.borrow_mut() = handlers.register(&element)?;
+                Ok(())
+            });
+            if let Err(e) = result {
+                web_sys::console::error_1(&e);
+            }
+        });
+    }
+}
+
+impl Drop for Chart {
+    fn drop(&mut self) {
+        self.dropped.set(true);
+        if let Some(observer) = &self.resize_observer {
+            observer.disconnect();
+        }
+        self.listeners.borrow_mut().clear();
+        let _ = super::purge(&self.element);
+    }
+}
@@ -0,0 +1,181 @@
+//! Typed plotly.js events.
+//!
+//! The `on_*` functions register a Rust closure for one of the events emitted
+//! by a chart drawn with [`new_plot`](super::new_plot). Event data is
+//! deserialized into the structs of the [`events`](crate::events) module,
+//! which are re-exported here. Registering a handler fails if the element does
+//! not exist or no chart has been drawn in it yet.
+//!
+//! The returned [`EventListener`] removes the handler when it is dropped, so it
+//! should be kept alive for as long as the handler is needed, e.g. in the
+//! state of a UI component.
+
+use serde::de::DeserializeOwned;
+use wasm_bindgen::{prelude::*, JsCast};
+
+use super::PlotElement;
+pub use crate::events::{
+    AxisRange, LegendClickEvent, Point, PointIndex, PointsEvent, RelayoutEvent, RestyleEvent,
+    SelectionEvent,
+};
+
+#[wasm_bindgen]
+extern "C" {
+    /// A `div` element on which a chart has been drawn, which plotly.js
+    /// extends with the methods of a Node.js `EventEmitter`.
+    type PlotlyElement;
+
+    #[wasm_bindgen(method, catch)]
+    fn on(
+        this: &PlotlyElement,
+        event: &str,
+        handler: &js_sys::Function,
+    ) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(method, catch, js_name = removeListener)]
+    fn remove_listener(
+        this: &PlotlyElement,
+        event: &str,
+        handler: &js_sys::Function,
+    ) -> Result<JsValue, JsValue>;
+}
+
+/// A registered event handler, which is removed when this is dropped.
+#[must_use = "the event handler is removed as soon as the listener is dropped"]
+pub struct EventListener {
+    element: PlotlyElement,
+    event: &'static str,
+    closure: Closure<dyn FnMut(JsValue) -> JsValue>,
+}
+
+impl EventListener {
+    fn new(
+        element: PlotElement,
+        event: &'static str,
+        closure: Closure<dyn FnMut(JsValue) -> JsValue>,
+    ) -> Result<Self, JsValue> {
+        let element = element.html_element()?.unchecked_into::<PlotlyElement>();
+        // `on` is only defined once a chart has been drawn in the element.
+        element.on(event, closure.as_ref().unchecked_ref())?;
+        Ok(Self {
+            element,
+            event,
+            closure,
+        })
+    }
+
+    /// The name of the plotly.js event, e.g. "plotly_click".
+    pub fn event(&self) -> &str {
+        self.event
+    }
+
+    /// Keep the handler registered for the lifetime of the chart, rather than
+    /// removing it when the listener goes out of scope.
+    pub fn forget(self) {
+        std::mem::forget(self)
+    }
+}
+
+impl Drop for EventListener {
+    fn drop(&mut self) {
+        // Fails if the chart has been purged in the meantime, in which case
+        // plotly.js has already removed all of its listeners.
+        let _ = self
+            .element
+            .remove_listener(self.event, self.closure.as_ref().unchecked_ref());
+    }
+}
+
+fn listen<T: DeserializeOwned + 'static>(
+    element: impl Into<PlotElement>,
+    event: &'static str,
+    mut handler: impl FnMut(T) + 'static,
+) -> Result<EventListener, JsValue> {
+    let closure = Closure::<dyn FnMut(JsValue) -> JsValue>::new(move |data: JsValue| {
+        // Events whose data does not have the documented structure are
+        // ignored, rather than aborting the application.
+        if let Ok(data) = serde_wasm_bindgen::from_value(data) {
+            handler(data);
+        }
+        JsValue::UNDEFINED
+    });
+    EventListener::new(element.into(), event, closure)
+}
+
+/// Register a handler for clicks on data points of the chart in `element`.
+pub fn on_click(
+    element: impl Into<PlotElement>,
+    handler: impl FnMut(PointsEvent) + 'static,
+) -> Result<EventListener, JsValue> {
+    listen(element, "plotly_click", handler)
+}
+
+/// Register a handler for hovering over data points.
+pub fn on_hover(
+    element: impl Into<PlotElement>,
+    handler: impl FnMut(PointsEvent) + 'static,
+) -> Result<EventListener, JsValue> {
+    listen(element, "plotly_hover", handler)
+}
+
+/// Register a handler for the mouse leaving data points.
+pub fn on_unhover(
+    element: impl Into<PlotElement>,
+    handler: impl FnMut(PointsEvent) + 'static,
+) -> Result<EventListener, JsValue> {
+    listen(element, "plotly_unhover", handler)
+}
+
+/// Register a handler for box and lasso selections. The handler receives
+/// `None` when the selection is cleared.
+pub fn on_selected(
+    element: impl Into<PlotElement>,
+    handler: impl FnMut(Option<SelectionEvent>) + 'static,
+) -> Result<EventListener, JsValue> {
+    listen(element, "plotly_selected", handler)
+}
+
+/// Register a handler for layout changes, including zooming and panning.
+pub fn on_relayout(
+    element: impl Into<PlotElement>,
+    handler: impl FnMut(RelayoutEvent) + 'static,
+) -> Result<EventListener, JsValue> {
+    listen(element, "plotly_relayout", handler)
+}
+
+/// Register a handler for trace changes, e.g. toggling trace visibility.
+pub fn on_restyle(
+    element: impl Into<PlotElement>,
+    handler: impl FnMut(RestyleEvent) + 'static,
+) -> Result<EventListener, JsValue> {
+    listen(element, "plotly_restyle", handler)
+}
+
+/// Register a handler for clicks on legend items. The default behaviour of
+/// toggling the visibility of the trace is cancelled if the handler returns
+/// `false`.
+pub fn on_legend_click(
+    element: impl Into<PlotElement>,
+    mut handler: impl FnMut(LegendClickEvent) -> bool + 'static,
+) -> Result<EventListener, JsValue> {
+    let closure = Closure::<dyn FnMut(JsValue) -> JsValue>::new(move |data: JsValue| {
+        match serde_wasm_bindgen::from_value(data) {
+            Ok(data) => JsValue::from_bool(handler(data)),
+            Err(_) => JsValue::UNDEFINED,
+        }
+    });
+    EventListener::new(element.into(), "plotly_legendclick", closure)
+}
+
+/// Register a handler for double clicks on the chart, which by default reset
+/// the axes.
+pub fn on_double_click(
+    element: impl Into<PlotElement>,
+    mut handler: impl FnMut() + 'static,
+) -> Result<EventListener, JsValue> {
+    let closure = Closure::<dyn FnMut(JsValue) -> JsValue>::new(move |_: JsValue| {
+        handler();
+        JsValue::UNDEFINED
+    });
+    EventListener::new(element.into(), "plotly_doubleclick", closure)
+}
@@ -0,0 +1,717 @@
+//! Bindings to the underlying plotly.js Javascript API. To be used in a WASM
+//! context, where it is assumed that a remote copy of the Javascript Plotly
+//! library is available, (i.e. via a CDN).
+//!
+//! All functions return an error, rather than panicking, if plotly.js rejects
+//! the call or has not been loaded.
+
+#[cfg(any(feature = "yew", feature = "leptos", feature = "dioxus"))]
+pub(crate) mod chart;
+pub mod events;
+pub(crate) mod serializer;
+
+use js_sys::Object;
+use plotly_derive::FieldSetter;
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+use web_sys::HtmlElement;
+
+use crate::{private::merge_updates, Layout, Plot, Relayout, Restyle, Trace};
+
+const PLOTLY_NOT_LOADED: &str = "plotly.js has not been loaded (`window.Plotly` is undefined). \
+Add a <script> tag that loads plotly.js, e.g. from https://cdn.plot.ly, to the page before using the bindings.";
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(catch, js_namespace = Plotly, js_name = newPlot)]
+    async fn new_plot_(element: &JsValue, obj: &Object) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, js_namespace = Plotly, js_name = react)]
+    async fn react_(element: &JsValue, obj: &Object) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, js_namespace = Plotly, js_name = restyle)]
+    async fn restyle_(
+        element: &JsValue,
+        update: &JsValue,
+        traces: &JsValue,
+    ) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, js_namespace = Plotly, js_name = relayout)]
+    async fn relayout_(element: &JsValue, update: &JsValue) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, js_namespace = Plotly, js_name = update)]
+    async fn update_(
+        element: &JsValue,
+        data_update: &JsValue,
+        layout_update: &JsValue,
+        traces: &JsValue,
+    ) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, js_namespace = Plotly, js_name = extendTraces)]
+    async fn extend_traces_(
+        element: &JsValue,
+        update: &JsValue,
+        indices: &JsValue,
+        max_points: &JsValue,
+    ) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, js_namespace = Plotly, js_name = prependTraces)]
+    async fn prepend_traces_(
+        element: &JsValue,
+        update: &JsValue,
+        indices: &JsValue,
+        max_points: &JsValue,
+    ) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, js_namespace = Plotly, js_name = addTraces)]
+    async fn add_traces_(
+        element: &JsValue,
+        traces: &JsValue,
+        new_indices: &JsValue,
+    ) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, js_namespace = Plotly, js_name = deleteTraces)]
+    async fn delete_traces_(element: &JsValue, indices: &JsValue) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, js_namespace = Plotly, js_name = moveTraces)]
+    async fn move_traces_(
+        element: &JsValue,
+        current_indices: &JsValue,
+        new_indices: &JsValue,
+    ) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, js_namespace = Plotly, js_name = purge)]
+    fn purge_(element: &JsValue) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, js_namespace = ["Plotly", "Plots"], js_name = resize)]
+    async fn resize_(element: &JsValue) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, js_namespace = Plotly, js_name = toImage)]
+    async fn to_image_(element: &JsValue, options: &JsValue) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, js_namespace = Plotly, js_name = downloadImage)]
+    async fn download_image_(element: &JsValue, options: &JsValue) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, js_namespace = Plotly, js_name = animate)]
+    async fn animate_(
+        element: &JsValue,
+        frames: &JsValue,
+        options: &JsValue,
+    ) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch, js_namespace = Plotly, js_name = addFrames)]
+    async fn add_frames_(
+        element: &JsValue,
+        frames: &JsValue,
+        indices: &JsValue,
+    ) -> Result<JsValue, JsValue>;
+}
+
+/// The `div` element a chart is drawn in, given either by its `id` or as an
+/// `HtmlElement`, e.g. obtained through a `NodeRef` in a UI framework.
+#[derive(Clone, Debug)]
+pub enum PlotElement {
+    Id(String),
+    Element(HtmlElement),
+}
+
+impl PlotElement {
+    fn to_js_value(&self) -> JsValue {
+        match self {
+            Self::Id(id) => JsValue::from_str(id),
+            Self::Element(element) => element.clone().into(),
+        }
+    }
+
+    fn html_element(&self) -> Result<HtmlElement, JsValue> {
+        use wasm_bindgen::JsCast;
+
+        match self {
+            Self::Id(id) => web_sys::window()
+                .and_then(|window| window.document())
+                .and_then(|document| document.get_element_by_id(id))
+                .map(|element| element.unchecked_into())
+                .ok_or_else(|| {
+                    js_sys::Error::new(&format!("No element with id \"{}\" was found", id)).into()
+                }),
+            Self::Element(element) => Ok(element.clone()),
+        }
+    }
+}
+
+impl From<&str> for PlotElement {
+    fn from(id: &str) -> Self {
+        Self::Id(id.to_string())
+    }
+}
+
+impl From<String> for PlotElement {
+    fn from(id: String) -> Self {
+        Self::Id(id)
+    }
+}
+
+impl From<HtmlElement> for PlotElement {
+    fn from(element: HtmlElement) -> Self {
+        Self::Element(element)
+    }
+}
+
+impl From<&HtmlElement> for PlotElement {
+    fn from(element: &HtmlElement) -> Self {
+        Self::Element(element.clone())
+    }
+}
+
+/// Image format supported by the plotly.js `toImage` and `downloadImage`
+/// functions.
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageFormat {
+    Png,
+    Jpeg,
+    Webp,
+    Svg,
+    #[serde(rename = "full-json")]
+    FullJson,
+}
+
+/// Options for [`to_image`] and [`download_image`].
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct ImageOptions {
+    format: Option<ImageFormat>,
+    width: Option<usize>,
+    height: Option<usize>,
+    /// Multiplies the width and height of the image, to increase its
+    /// resolution.
+    scale: Option<f64>,
+    /// Sets the name of the downloaded file, without the extension. Only used
+    /// by [`download_image`].
+    filename: Option<String>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl ImageOptions {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+/// Determines how an animation is queued with respect to any animation that
+/// is already running.
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum AnimationMode {
+    Immediate,
+    Next,
+    AfterAll,
+}
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum AnimationDirection {
+    Forward,
+    Reverse,
+}
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub enum TransitionEasing {
+    Linear,
+    Quad,
+    Cubic,
+    Sin,
+    Exp,
+    Circle,
+    Elastic,
+    Back,
+    Bounce,
+    LinearIn,
+    QuadIn,
+    CubicIn,
+    SinIn,
+    ExpIn,
+    CircleIn,
+    ElasticIn,
+    BackIn,
+    BounceIn,
+    LinearOut,
+    QuadOut,
+    CubicOut,
+    SinOut,
+    ExpOut,
+    CircleOut,
+    ElasticOut,
+    BackOut,
+    BounceOut,
+    LinearInOut,
+    QuadInOut,
+    CubicInOut,
+    SinInOut,
+    ExpInOut,
+    CircleInOut,
+    ElasticInOut,
+    BackInOut,
+    BounceInOut,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct FrameSettings {
+    /// The duration in milliseconds of each frame.
+    duration: Option<usize>,
+    /// Redraw the plot at the completion of the transition. This is desirable
+    /// for transitions that include properties that cannot be transitioned,
+    /// but may significantly slow down updates that do not require a full
+    /// redraw of the plot.
+    redraw: Option<bool>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl FrameSettings {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct TransitionSettings {
+    /// The duration of the transition, in milliseconds. If equal to zero,
+    /// updates are synchronous.
+    duration: Option<usize>,
+    /// The easing function used for the transition.
+    easing: Option<TransitionEasing>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl TransitionSettings {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+/// Options for [`animate`].
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct AnimationOptions {
+    frame: Option<FrameSettings>,
+    transition: Option<TransitionSettings>,
+    mode: Option<AnimationMode>,
+    direction: Option<AnimationDirection>,
+    /// Play frames starting at the current frame instead of the beginning.
+    #[serde(rename = "fromcurrent")]
+    from_current: Option<bool>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl AnimationOptions {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+/// An animation frame, to be registered with [`add_frames`].
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, FieldSetter)]
+pub struct Frame {
+    /// The name of the frame, used to refer to it in [`animate`].
+    name: Option<String>,
+    /// A label by which to group frames, so that a group can be animated in
+    /// one go.
+    group: Option<String>,
+    /// The name of the frame on top of which this frame is applied.
+    #[serde(rename = "baseframe")]
+    base_frame: Option<String>,
+    /// The indices of the traces that `data` applies to. Defaults to the
+    /// first `data.len()` traces.
+    traces: Option<Vec<usize>>,
+    /// The trace data of the frame.
+    data: Option<Vec<Box<dyn Trace>>>,
+    /// The layout of the frame.
+    layout: Option<Layout>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl Frame {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+/// The frames to play in [`animate`].
+#[derive(Serialize, Clone)]
+#[serde(untagged)]
+pub enum AnimationFrames {
+    /// Play all frames registered with the plot, in order.
+    All,
+    /// Play the frames, or groups of frames, with the given names.
+    Named(Vec<String>),
+    /// Play the given frames, without registering them with the plot.
+    Frames(Vec<Frame>),
+}
+
+/// A wrapper around the plotly.js [newPlot](https://plotly.com/javascript/plotlyjs-function-reference/#plotlynewplot)
+/// function, which draws `plot` in `element`.
+pub async fn new_plot(element: impl Into<PlotElement>, plot: &Plot) -> Result<(), JsValue> {
+    check_plotly()?;
+    new_plot_(&element.into().to_js_value(), &plot.to_js_object()).await?;
+    Ok(())
+}
+
+/// A wrapper around the plotly.js [react](https://plotly.com/javascript/plotlyjs-function-reference/#react)
+/// function, which redraws the chart in `element` with `plot`, more efficiently
+/// than [`new_plot`].
+pub async fn react(element: impl Into<PlotElement>, plot: &Plot) -> Result<(), JsValue> {
+    check_plotly()?;
+    react_(&element.into().to_js_value(), &plot.to_js_object()).await?;
+    Ok(())
+}
+
+/// A wrapper around the plotly.js [restyle](https://plotly.com/javascript/plotlyjs-function-reference/#plotlyrestyle)
+/// function.
+///
+/// The `updates`, e.g. `RestyleScatter::ModifyOpacity` values, are merged into
+/// a single update. They are applied to the traces with the given indices, or
+/// to all traces if `traces` is `None`.
+pub async fn restyle<R: Restyle>(
+    element: impl Into<PlotElement>,
+    updates: &[R],
+    traces: Option<&[usize]>,
+) -> Result<(), JsValue> {
+    check_plotly()?;
+    restyle_(
+        &element.into().to_js_value(),
+        &to_js_value(&merge_updates(updates))?,
+        &optional(traces)?,
+    )
+    .await?;
+    Ok(())
+}
+
+/// A wrapper around the plotly.js [relayout](https://plotly.com/javascript/plotlyjs-function-reference/#plotlyrelayout)
+/// function.
+///
+/// The `updates`, e.g. `RelayoutLayout::ModifyTitle` values, are merged into a
+/// single update.
+pub async fn relayout<L: Relayout + Serialize>(
+    element: impl Into<PlotElement>,
+    updates: &[L],
+) -> Result<(), JsValue> {
+    check_plotly()?;
+    relayout_(
+        &element.into().to_js_value(),
+        &to_js_value(&merge_updates(updates))?,
+    )
+    .await?;
+    Ok(())
+}
+
+/// A wrapper around the plotly.js [update](https://plotly.com/javascript/plotlyjs-function-reference/#plotlyupdate)
+/// function, which combines [`restyle`] and [`relayout`] in a single redraw.
+pub async fn update<R: Restyle, L: Relayout + Serialize>(
+    element: impl Into<PlotElement>,
+    data_updates: &[R],
+    layout_updates: &[L],
+    traces: Option<&[usize]>,
+) -> Result<(), JsValue> {
+    check_plotly()?;
+    update_(
+        &element.into().to_js_value(),
+        &to_js_value(&merge_updates(data_updates))?,
+        &to_js_value(&merge_updates(layout_updates))?,
+        &optional(traces)?,
+    )
+    .await?;
+    Ok(())
+}
+
+/// A wrapper around the plotly.js [extendTraces](https://plotly.com/javascript/plotlyjs-function-reference/#plotlyextendtraces)
+/// function.
+///
+/// `update` maps attribute names to one array of new points per trace in
+/// `indices`, e.g. `json!({"x": [[4, 5]], "y": [[1, 2]]})`. If `max_points`
+/// is given, only that many of the most recent points are kept.
+pub async fn extend_traces(
+    element: impl Into<PlotElement>,
+    update: &impl Serialize,
+    indices: &[usize],
+    max_points: Option<usize>,
+) -> Result<(), JsValue> {
+    check_plotly()?;
+    extend_traces_(
+        &element.into().to_js_value(),
+        &to_js_value(update)?,
+        &to_js_value(indices)?,
+        &optional(max_points.as_ref())?,
+    )
+    .await?;
+    Ok(())
+}
+
+/// A wrapper around the plotly.js [prependTraces](https://plotly.com/javascript/plotlyjs-function-reference/#plotlyprependtraces)
+/// function. See [`extend_traces`] for the meaning of the arguments.
+pub async fn prepend_traces(
+    element: impl Into<PlotElement>,
+    update: &impl Serialize,
+    indices: &[usize],
+    max_points: Option<usize>,
+) -> Result<(), JsValue> {
+    check_plotly()?;
+    prepend_traces_(
+        &element.into().to_js_value(),
+        &to_js_value(update)?,
+        &to_js_value(indices)?,
+        &optional(max_points.as_ref())?,
+    )
+    .await?;
+    Ok(())
+}
+
+/// A wrapper around the plotly.js [addTraces](https://plotly.com/javascript/plotlyjs-function-reference/#plotlyaddtraces)
+/// function.
+///
+/// The traces are appended, unless `new_indices` gives their positions.
+/// Negative indices count from the end.
+pub async fn add_traces(
+    element: impl Into<PlotElement>,
+    traces: &[Box<dyn Trace>],
+    new_indices: Option<&[isize]>,
+) -> Result<(), JsValue> {
+    check_plotly()?;
+    add_traces_(
+        &element.into().to_js_value(),
+        &to_js_value(traces)?,
+        &optional(new_indices)?,
+    )
+    .await?;
+    Ok(())
+}
+
+/// A wrapper around the plotly.js [deleteTraces](https://plotly.com/javascript/plotlyjs-function-reference/#plotlydeletetraces)
+/// function. Negative indices count from the end.
+pub async fn delete_traces(
+    element: impl Into<PlotElement>,
+    indices: &[isize],
+) -> Result<(), JsValue> {
+    check_plotly()?;
+    delete_traces_(&element.into().to_js_value(), &to_js_value(indices)?).await?;
+    Ok(())
+}
+
+/// A wrapper around the plotly.js [moveTraces](https://plotly.com/javascript/plotlyjs-function-reference/#plotlymovetraces)
+/// function.
+///
+/// The traces are moved to the end, unless `new_indices` gives their new
+/// positions. Negative indices count from the end.
+pub async fn move_traces(
+    element: impl Into<PlotElement>,
+    current_indices: &[isize],
+    new_indices: Option<&[isize]>,
+) -> Result<(), JsValue> {
+    check_plotly()?;
+    move_traces_(
+        &element.into().to_js_value(),
+        &to_js_value(current_indices)?,
+        &optional(new_indices)?,
+    )
+    .await?;
+    Ok(())
+}
+
+/// A wrapper around the plotly.js [purge](https://plotly.com/javascript/plotlyjs-function-reference/#plotlypurge)
+/// function, which removes the chart and its event listeners from `element`.
+pub fn purge(element: impl Into<PlotElement>) -> Result<(), JsValue> {
+    check_plotly()?;
+    purge_(&element.into().to_js_value())?;
+    Ok(())
+}
+
+/// A wrapper around the plotly.js `Plots.resize` function, which fits the chart
+/// in `element` to the current size of its container.
+pub async fn resize(element: impl Into<PlotElement>) -> Result<(), JsValue> {
+    check_plotly()?;
+    resize_(&element.into().to_js_value()).await?;
+    Ok(())
+}
+
+/// A wrapper around the plotly.js [toImage](https://plotly.com/javascript/plotlyjs-function-reference/#plotlytoimage)
+/// function. Returns the image as a data URL.
+pub async fn to_image(
+    element: impl Into<PlotElement>,
+    options: &ImageOptions,
+) -> Result<String, JsValue> {
+    check_plotly()?;
+    to_image_(&element.into().to_js_value(), &to_js_value(options)?)
+        .await?
+        .as_string()
+        .ok_or_else(|| js_sys::Error::new("Plotly.toImage did not return a data URL").into())
+}
+
+/// A wrapper around the plotly.js [downloadImage](https://plotly.com/javascript/plotlyjs-function-reference/#plotlydownloadimage)
+/// function.
+pub async fn download_image(
+    element: impl Into<PlotElement>,
+    options: &ImageOptions,
+) -> Result<(), JsValue> {
+    check_plotly()?;
+    download_image_(&element.into().to_js_value(), &to_js_value(options)?).await?;
+    Ok(())
+}
+
+/// A wrapper around the plotly.js [animate](https://plotly.com/javascript/plotlyjs-function-reference/#plotlyanimate)
+/// function.
+pub async fn animate(
+    element: impl Into<PlotElement>,
+    frames: &AnimationFrames,
+    options: Option<&AnimationOptions>,
+) -> Result<(), JsValue> {
+    check_plotly()?;
+    animate_(
+        &element.into().to_js_value(),
+        &to_js_value(frames)?,
+        &optional(options)?,
+    )
+    .await?;
+    Ok(())
+}
+
+/// A wrapper around the plotly.js [addFrames](https://plotly.com/javascript/plotlyjs-function-reference/#plotlyaddframes)
+/// function.
+///
+/// The frames are appended, unless `indices` gives their positions.
+pub async fn add_frames(
+    element: impl Into<PlotElement>,
+    frames: &[Frame],
+    indices: Option<&[usize]>,
+) -> Result<(), JsValue> {
+    check_plotly()?;
+    add_frames_(
+        &element.into().to_js_value(),
+        &to_js_value(frames)?,
+        &optional(indices)?,
+    )
+    .await?;
+    Ok(())
+}
+
+/// Fail with a descriptive error if plotly.js is not available, rather than
+/// with the `ReferenceError` raised by the first call into it.
+fn check_plotly() -> Result<(), JsValue> {
+    let plotly = js_sys::Reflect::get(&js_sys::global(), &JsValue::from_str("Plotly"))?;
+    if plotly.is_undefined() {
+        return Err(js_sys::Error::new(PLOTLY_NOT_LOADED).into());
+    }
+    Ok(())
+}
+
+fn to_js_value<T: Serialize + ?Sized>(value: &T) -> Result<JsValue, JsValue> {
+    Ok(serializer::to_js_value(value)?)
+}
+
+fn optional<T: Serialize + ?Sized>(value: Option<&T>) -> Result<JsValue, JsValue> {
+    value.map_or(Ok(JsValue::UNDEFINED), to_js_value)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+    use crate::Scatter;
+
+    #[test]
+    fn test_plot_element_from_id() {
+        assert!(matches!(PlotElement::from("plot-div"), PlotElement::Id(id) if id == "plot-div"));
+        assert!(
+            matches!(PlotElement::from(String::from("plot-div")), PlotElement::Id(id) if id == "plot-div")
+        );
+    }
+
+    #[test]
+    fn test_serialize_image_options() {
+        let options = ImageOptions::new()
+            .format(ImageFormat::FullJson)
+            .width(800)
+            .height(600)
+            .scale(2.0)
+            .filename("plot");
+        let expected = json!({
+            "format": "full-json",
+            "width": 800,
+            "height": 600,
+            "scale": 2.0,
+            "filename": "plot"
+        });
+
+        assert_eq!(to_value(options).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_animation_options() {
+        let options = AnimationOptions::new()
+            .frame(FrameSettings::new().duration(500).redraw(false))
+            .transition(
+                TransitionSettings::new()
+                    .duration(300)
+                    .easing(TransitionEasing::CubicInOut),
+            )
+            .mode(AnimationMode::AfterAll)
+            .direction(AnimationDirection::Reverse)
+            .from_current(true);
+        let expected = json!({
+            "frame": {"duration": 500, "redraw": false},
+            "transition": {"duration": 300, "easing": "cubic-in-out"},
+            "mode": "afterall",
+            "direction": "reverse",
+            "fromcurrent": true
+        });
+
+        assert_eq!(to_value(options).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_frame() {
+        let frame = Frame::new()
+            .name("frame_1")
+            .group("group")
+            .base_frame("frame_0")
+            .traces(vec![0])
+            .data(vec![Scatter::new(vec![1], vec![2])])
+            .layout(Layout::new().height(100));
+        let expected = json!({
+            "name": "frame_1",
+            "group": "group",
+            "baseframe": "frame_0",
+            "traces": [0],
+            "data": [{"type": "scatter", "x": [1], "y": [2]}],
+            "layout": {"height": 100}
+        });
+
+        assert_eq!(to_value(frame).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_animation_frames() {
+        assert_eq!(to_value(AnimationFrames::All).unwrap(), json!(null));
+        assert_eq!(
+            to_value(AnimationFrames::Named(vec!["group".to_string()])).unwrap(),
+            json!(["group"])
+        );
+        assert_eq!(
+            to_value(AnimationFrames::Frames(vec![Frame::new().name("a")])).unwrap(),
+            json!([{"name": "a"}])
+        );
+    }
+}
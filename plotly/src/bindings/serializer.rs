@@ -0,0 +1,709 @@
+//! A serde serializer that builds Javascript values directly, rather than
+//! going through a JSON string.
+//!
+//! The output matches that of `serde_json`, including NaN and infinite numbers
+//! becoming `null`, except that sequences of at least `TYPED_ARRAY_MIN_LEN`
+//! finite `f64` or `f32` values, i.e. trace data, become `Float64Array` or
+//! `Float32Array` typed arrays. These are accepted by plotly.js for all data
+//! arrays and are created with a single copy. Shorter sequences stay plain
+//! arrays, as plotly.js requires an `Array` for attributes such as axis ranges
+//! and domains.
+
+use js_sys::{Array, Float32Array, Float64Array, Object, Reflect};
+use serde::ser::{self, Impossible, Serialize};
+use serde_wasm_bindgen::Error;
+use wasm_bindgen::JsValue;
+
+const TYPED_ARRAY_MIN_LEN: usize = 16;
+
+/// Convert `value` to a Javascript value.
+pub(crate) fn to_js_value<T: Serialize + ?Sized>(value: &T) -> Result<JsValue, Error> {
+    value.serialize(Serializer)
+}
+
+/// A number as `serde_json` serializes it, with `null` for NaN and infinity.
+fn number(v: f64) -> JsValue {
+    if v.is_finite() {
+        JsValue::from_f64(v)
+    } else {
+        JsValue::NULL
+    }
+}
+
+fn object_with(key: &str, value: &JsValue) -> Result<JsValue, Error> {
+    let object = Object::new();
+    Reflect::set(&object, &JsValue::from_str(key), value)?;
+    Ok(object.into())
+}
+
+struct Serializer;
+
+impl ser::Serializer for Serializer {
+    type Ok = JsValue;
+    type Error = Error;
+    type SerializeSeq = SeqSerializer;
+    type SerializeTuple = SeqSerializer;
+    type SerializeTupleStruct = SeqSerializer;
+    type SerializeTupleVariant = VariantSerializer<SeqSerializer>;
+    type SerializeMap = MapSerializer;
+    type SerializeStruct = MapSerializer;
+    type SerializeStructVariant = VariantSerializer<MapSerializer>;
+
+    fn serialize_bool(self, v: bool) -> Result<JsValue, Error> {
+        Ok(JsValue::from_bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<JsValue, Error> {
+        self.serialize_f64(v as f64)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<JsValue, Error> {
+        self.serialize_f64(v as f64)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<JsValue, Error> {
+        self.serialize_f64(v as f64)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<JsValue, Error> {
+        self.serialize_f64(v as f64)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<JsValue, Error> {
+        self.serialize_f64(v as f64)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<JsValue, Error> {
+        self.serialize_f64(v as f64)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<JsValue, Error> {
+        self.serialize_f64(v as f64)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<JsValue, Error> {
+        self.serialize_f64(v as f64)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<JsValue, Error> {
+        self.serialize_f64(v as f64)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<JsValue, Error> {
+        Ok(number(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<JsValue, Error> {
+        Ok(JsValue::from_str(&v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<JsValue, Error> {
+        Ok(JsValue::from_str(v))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<JsValue, Error> {
+        Ok(v.iter()
+            .map(|&b| JsValue::from_f64(b as f64))
+            .collect::<Array>()
+            .into())
+    }
+
+    fn serialize_none(self) -> Result<JsValue, Error> {
+        Ok(JsValue::NULL)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<JsValue, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<JsValue, Error> {
+        Ok(JsValue::NULL)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<JsValue, Error> {
+        Ok(JsValue::NULL)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<JsValue, Error> {
+        Ok(JsValue::from_str(variant))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<JsValue, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<JsValue, Error> {
+        object_with(variant, &value.serialize(self)?)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SeqSerializer, Error> {
+        Ok(SeqSerializer::new(len))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SeqSerializer, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SeqSerializer, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<VariantSerializer<SeqSerializer>, Error> {
+        Ok(VariantSerializer {
+            variant,
+            inner: SeqSerializer::new(Some(len)),
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<MapSerializer, Error> {
+        Ok(MapSerializer::new())
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<MapSerializer, Error> {
+        Ok(MapSerializer::new())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<VariantSerializer<MapSerializer>, Error> {
+        Ok(VariantSerializer {
+            variant,
+            inner: MapSerializer::new(),
+        })
+    }
+}
+
+/// The elements of a sequence serialized so far. Numbers are collected as
+/// long as all elements have the same floating point type.
+enum Elements {
+    Empty,
+    F64(Vec<f64>),
+    F32(Vec<f32>),
+    Values(Array),
+}
+
+struct SeqSerializer {
+    capacity: usize,
+    elements: Elements,
+}
+
+impl SeqSerializer {
+    fn new(len: Option<usize>) -> Self {
+        Self {
+            capacity: len.unwrap_or(0),
+            elements: Elements::Empty,
+        }
+    }
+
+    fn values(&mut self) -> &Array {
+        let values = match std::mem::replace(&mut self.elements, Elements::Empty) {
+            Elements::Empty => Array::new(),
+            Elements::F64(numbers) => numbers.into_iter().map(number).collect(),
+            Elements::F32(numbers) => numbers.into_iter().map(|n| number(n as f64)).collect(),
+            Elements::Values(values) => values,
+        };
+        self.elements = Elements::Values(values);
+        match &self.elements {
+            Elements::Values(values) => values,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl ser::SerializeSeq for SeqSerializer {
+    type Ok = JsValue;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let number = match self.elements {
+            Elements::Values(_) => None,
+            _ => value.serialize(FloatProbe).ok(),
+        };
+        match (&mut self.elements, number) {
+            (Elements::Empty, Some(Float::F64(n))) => {
+                let mut numbers = Vec::with_capacity(self.capacity);
+                numbers.push(n);
+                self.elements = Elements::F64(numbers);
+            }
+            (Elements::Empty, Some(Float::F32(n))) => {
+                let mut numbers = Vec::with_capacity(self.capacity);
+                numbers.push(n);
+                self.elements = Elements::F32(numbers);
+            }
+            (Elements::F64(numbers), Some(Float::F64(n))) => numbers.push(n),
+            (Elements::F32(numbers), Some(Float::F32(n))) => numbers.push(n),
+            _ => {
+                let value = value.serialize(Serializer)?;
+                self.values().push(&value);
+            }
+        }
+        Ok(())
+    }
+
+    fn end(mut self) -> Result<JsValue, Error> {
+        match &self.elements {
+            Elements::F64(numbers)
+                if numbers.len() >= TYPED_ARRAY_MIN_LEN
+                    && numbers.iter().all(|n| n.is_finite()) =>
+            {
+                return Ok(Float64Array::from(numbers.as_slice()).into());
+            }
+            Elements::F32(numbers)
+                if numbers.len() >= TYPED_ARRAY_MIN_LEN
+                    && numbers.iter().all(|n| n.is_finite()) =>
+            {
+                return Ok(Float32Array::from(numbers.as_slice()).into());
+            }
+            _ => {}
+        }
+        Ok(self.values().clone().into())
+    }
+}
+
+impl ser::SerializeTuple for SeqSerializer {
+    type Ok = JsValue;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<JsValue, Error> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for SeqSerializer {
+    type Ok = JsValue;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<JsValue, Error> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+struct MapSerializer {
+    object: Object,
+    key: Option<JsValue>,
+}
+
+impl MapSerializer {
+    fn new() -> Self {
+        Self {
+            object: Object::new(),
+            key: None,
+        }
+    }
+}
+
+impl ser::SerializeMap for MapSerializer {
+    type Ok = JsValue;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+        self.key = Some(key.serialize(Serializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| <Error as ser::Error>::custom("map value without a key"))?;
+        Reflect::set(&self.object, &key, &value.serialize(Serializer)?)?;
+        Ok(())
+    }
+
+    fn end(self) -> Result<JsValue, Error> {
+        Ok(self.object.into())
+    }
+}
+
+impl ser::SerializeStruct for MapSerializer {
+    type Ok = JsValue;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        Reflect::set(
+            &self.object,
+            &JsValue::from_str(key),
+            &value.serialize(Serializer)?,
+        )?;
+        Ok(())
+    }
+
+    fn end(self) -> Result<JsValue, Error> {
+        Ok(self.object.into())
+    }
+}
+
+/// Serializes an enum variant with data as `{variant: data}`.
+struct VariantSerializer<S> {
+    variant: &'static str,
+    inner: S,
+}
+
+impl ser::SerializeTupleVariant for VariantSerializer<SeqSerializer> {
+    type Ok = JsValue;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        ser::SerializeSeq::serialize_element(&mut self.inner, value)
+    }
+
+    fn end(self) -> Result<JsValue, Error> {
+        object_with(self.variant, &ser::SerializeSeq::end(self.inner)?)
+    }
+}
+
+impl ser::SerializeStructVariant for VariantSerializer<MapSerializer> {
+    type Ok = JsValue;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        ser::SerializeStruct::serialize_field(&mut self.inner, key, value)
+    }
+
+    fn end(self) -> Result<JsValue, Error> {
+        object_with(self.variant, &ser::SerializeStruct::end(self.inner)?)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Float {
+    F64(f64),
+    F32(f32),
+}
+
+#[derive(Debug)]
+struct NotAFloat;
+
+impl std::fmt::Display for NotAFloat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "not a floating point number")
+    }
+}
+
+impl std::error::Error for NotAFloat {}
+
+impl ser::Error for NotAFloat {
+    fn custom<T: std::fmt::Display>(_msg: T) -> Self {
+        NotAFloat
+    }
+}
+
+/// Extracts the value of an `f64` or `f32` without creating a Javascript
+/// value, and fails for any other type.
+struct FloatProbe;
+
+macro_rules! not_a_float {
+    ($($method:ident($($arg:ty),*)),* $(,)?) => {
+        $(
+            fn $method(self, $(_: $arg),*) -> Result<Float, NotAFloat> {
+                Err(NotAFloat)
+            }
+        )*
+    };
+}
+
+impl ser::Serializer for FloatProbe {
+    type Ok = Float;
+    type Error = NotAFloat;
+    type SerializeSeq = Impossible<Float, NotAFloat>;
+    type SerializeTuple = Impossible<Float, NotAFloat>;
+    type SerializeTupleStruct = Impossible<Float, NotAFloat>;
+    type SerializeTupleVariant = Impossible<Float, NotAFloat>;
+    type SerializeMap = Impossible<Float, NotAFloat>;
+    type SerializeStruct = Impossible<Float, NotAFloat>;
+    type SerializeStructVariant = Impossible<Float, NotAFloat>;
+
+    fn serialize_f64(self, v: f64) -> Result<Float, NotAFloat> {
+        Ok(Float::F64(v))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Float, NotAFloat> {
+        Ok(Float::F32(v))
+    }
+
+    not_a_float! {
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_char(char),
+        serialize_str(&str),
+        serialize_bytes(&[u8]),
+        serialize_none(),
+        serialize_unit(),
+        serialize_unit_struct(&'static str),
+        serialize_unit_variant(&'static str, u32, &'static str),
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, _value: &T) -> Result<Float, NotAFloat> {
+        Err(NotAFloat)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Float, NotAFloat> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Float, NotAFloat> {
+        Err(NotAFloat)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, NotAFloat> {
+        Err(NotAFloat)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, NotAFloat> {
+        Err(NotAFloat)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, NotAFloat> {
+        Err(NotAFloat)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, NotAFloat> {
+        Err(NotAFloat)
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, NotAFloat> {
+        Err(NotAFloat)
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, NotAFloat> {
+        Err(NotAFloat)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, NotAFloat> {
+        Err(NotAFloat)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::private::NumOrString;
+
+    #[test]
+    fn test_float_probe() {
+        assert_eq!(1.5f64.serialize(FloatProbe).unwrap(), Float::F64(1.5));
+        assert_eq!(2.5f32.serialize(FloatProbe).unwrap(), Float::F32(2.5));
+        assert_eq!(
+            NumOrString::F(3.0).serialize(FloatProbe).unwrap(),
+            Float::F64(3.0)
+        );
+        assert!(1u32.serialize(FloatProbe).is_err());
+        assert!("1.5".serialize(FloatProbe).is_err());
+        assert!(Some(1.5f64).serialize(FloatProbe).is_err());
+        assert!(vec![1.5f64].serialize(FloatProbe).is_err());
+    }
+
+    #[cfg(target_family = "wasm")]
+    mod wasm {
+        use std::collections::BTreeMap;
+
+        use js_sys::{Array, Float32Array, Float64Array, Reflect};
+        use serde::Serialize;
+        use wasm_bindgen::JsCast;
+        use wasm_bindgen_test::wasm_bindgen_test;
+
+        use super::*;
+        use crate::common::Mode;
+
+        fn get(value: &JsValue, key: &str) -> JsValue {
+            Reflect::get(value, &JsValue::from_str(key)).unwrap()
+        }
+
+        fn array(value: JsValue) -> Array {
+            assert!(Array::is_array(&value));
+            value.unchecked_into()
+        }
+
+        #[wasm_bindgen_test]
+        fn test_f64_typed_array() {
+            let numbers: Vec<f64> = (0..TYPED_ARRAY_MIN_LEN).map(|n| n as f64 * 0.5).collect();
+            let value = to_js_value(&numbers).unwrap();
+            assert!(value.is_instance_of::<Float64Array>());
+            assert_eq!(value.unchecked_into::<Float64Array>().to_vec(), numbers);
+
+            let value = array(to_js_value(&numbers[1..]).unwrap());
+            assert_eq!(value.length() as usize, TYPED_ARRAY_MIN_LEN - 1);
+            assert_eq!(value.get(0).as_f64(), Some(0.5));
+        }
+
+        #[wasm_bindgen_test]
+        fn test_f32_typed_array() {
+            let numbers: Vec<f32> = (0..TYPED_ARRAY_MIN_LEN).map(|n| n as f32 * 0.5).collect();
+            let value = to_js_value(&numbers).unwrap();
+            assert!(value.is_instance_of::<Float32Array>());
+            assert_eq!(value.unchecked_into::<Float32Array>().to_vec(), numbers);
+
+            let value = array(to_js_value(&numbers[1..]).unwrap());
+            assert_eq!(value.length() as usize, TYPED_ARRAY_MIN_LEN - 1);
+            assert_eq!(value.get(0).as_f64(), Some(0.5));
+        }
+
+        #[wasm_bindgen_test]
+        fn test_plain_array_fallback() {
+            let mut mixed = vec![NumOrString::F(1.0); TYPED_ARRAY_MIN_LEN];
+            mixed.push(NumOrString::S("a".to_string()));
+            let value = array(to_js_value(&mixed).unwrap());
+            assert_eq!(value.length() as usize, TYPED_ARRAY_MIN_LEN + 1);
+            assert_eq!(value.get(0).as_f64(), Some(1.0));
+            assert_eq!(
+                value.get(TYPED_ARRAY_MIN_LEN as u32).as_string().unwrap(),
+                "a"
+            );
+
+            let mut mixed = vec![1.0f64; TYPED_ARRAY_MIN_LEN];
+            mixed.push(f64::NAN);
+            let value = array(to_js_value(&mixed).unwrap());
+            assert_eq!(value.get(0).as_f64(), Some(1.0));
+            assert!(value.get(TYPED_ARRAY_MIN_LEN as u32).is_null());
+
+            let mut options = vec![Some(1.0f64); TYPED_ARRAY_MIN_LEN];
+            let value = array(to_js_value(&options).unwrap());
+            assert_eq!(value.get(0).as_f64(), Some(1.0));
+            options.push(None);
+            let value = array(to_js_value(&options).unwrap());
+            assert!(value.get(TYPED_ARRAY_MIN_LEN as u32).is_null());
+        }
+
+        #[wasm_bindgen_test]
+        fn test_non_finite_numbers() {
+            assert!(to_js_value(&f64::NAN).unwrap().is_null());
+            assert!(to_js_value(&f64::INFINITY).unwrap().is_null());
+            assert!(to_js_value(&f32::NEG_INFINITY).unwrap().is_null());
+            assert_eq!(to_js_value(&1.5).unwrap().as_f64(), Some(1.5));
+        }
+
+        #[derive(Serialize)]
+        struct Inner {
+            values: Vec<f64>,
+        }
+
+        #[derive(Serialize)]
+        struct Outer {
+            name: &'static str,
+            inner: Inner,
+            map: BTreeMap<&'static str, Inner>,
+            #[serde(flatten)]
+            extra: BTreeMap<String, Inner>,
+        }
+
+        #[wasm_bindgen_test]
+        fn test_nested_maps_and_flatten() {
+            let outer = Outer {
+                name: "outer",
+                inner: Inner {
+                    values: vec![1.0, 2.0],
+                },
+                map: BTreeMap::from([("key", Inner { values: vec![3.0] })]),
+                extra: BTreeMap::from([("xaxis2".to_string(), Inner { values: vec![4.0] })]),
+            };
+            let value = to_js_value(&outer).unwrap();
+            assert_eq!(get(&value, "name").as_string().unwrap(), "outer");
+            let values = array(get(&get(&value, "inner"), "values"));
+            assert_eq!(values.get(1).as_f64(), Some(2.0));
+            let values = array(get(&get(&get(&value, "map"), "key"), "values"));
+            assert_eq!(values.get(0).as_f64(), Some(3.0));
+            let values = array(get(&get(&value, "xaxis2"), "values"));
+            assert_eq!(values.get(0).as_f64(), Some(4.0));
+            assert!(get(&value, "extra").is_undefined());
+        }
+
+        #[wasm_bindgen_test]
+        fn test_unit_variants() {
+            assert_eq!(
+                to_js_value(&Mode::Markers).unwrap().as_string().unwrap(),
+                "markers"
+            );
+            assert_eq!(
+                to_js_value(&Mode::LinesMarkers)
+                    .unwrap()
+                    .as_string()
+                    .unwrap(),
+                "lines+markers"
+            );
+            let modes = array(to_js_value(&vec![Mode::Lines, Mode::Text]).unwrap());
+            assert_eq!(modes.get(1).as_string().unwrap(), "text");
+        }
+    }
+}
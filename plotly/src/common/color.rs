@@ -10,6 +10,10 @@
 //! string representation. Cross-browser compatible [`predefined colors`] are
 //! supported via the `NamedColor` enum.
 //!
+//! Numeric `f64` values are also accepted wherever a color is expected; these
+//! are mapped to colors through the trace's `colorscale` (e.g. `cmin`/`cmax`
+//! on a marker or line).
+//!
 //! The `Color` trait is public, and so can be implemented for custom colour
 //! types. The user can then implement a valid serialization function according
 //! to their own requirements. On the whole, that should be largely unnecessary
@@ -33,6 +37,7 @@ impl Color for &'static str {}
 impl Color for String {}
 impl Color for Rgb {}
 impl Color for Rgba {}
+impl Color for f64 {}
 
 /// ColorArray is only used internally to provide a helper method for converting
 /// Vec<impl Color> to Vec<Box<dyn Color>>, as we would otherwise fall foul of
@@ -290,6 +295,12 @@ mod tests {
         assert_eq!(to_value(color).unwrap(), json!("any_arbitrary_string"));
     }
 
+    #[test]
+    fn test_serialize_numeric_color() {
+        let colors: Vec<Box<dyn Color>> = ColorArray(vec![0.0, 0.5]).into();
+        assert_eq!(to_value(colors).unwrap(), json!([0.0, 0.5]));
+    }
+
     #[test]
     #[rustfmt::skip]
     fn test_serialize_named_color() {
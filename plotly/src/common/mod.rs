@@ -4,7 +4,7 @@ use serde::{Serialize, Serializer};
 
 use crate::{
     color::{Color, ColorArray},
-    private,
+    private::{self, Extra},
 };
 
 #[derive(Serialize, Clone, Debug)]
@@ -186,21 +186,41 @@ pub enum PlotType {
     Scatter,
     ScatterGL,
     Scatter3D,
+    ScatterCarpet,
+    ScatterMap,
     ScatterMapbox,
     ScatterPolar,
     ScatterPolarGL,
+    ScatterSmith,
     Bar,
+    BarPolar,
     Box,
     Candlestick,
+    Carpet,
+    ChoroplethMap,
+    ChoroplethMapbox,
+    Cone,
     Contour,
+    ContourCarpet,
+    DensityMap,
+    DensityMapbox,
     HeatMap,
     Histogram,
+    Histogram2d,
     Histogram2dContour,
     Image,
+    Indicator,
+    Isosurface,
     Mesh3D,
     Ohlc,
+    Parcats,
+    Parcoords,
     Sankey,
+    Splom,
+    Streamtube,
     Surface,
+    Table,
+    Volume,
 }
 
 #[derive(Serialize, Clone, Debug)]
@@ -515,6 +535,8 @@ pub struct Line {
     outlier_color: Option<Box<dyn Color>>,
     #[serde(rename = "outlierwidth")]
     outlier_width: Option<usize>,
+    #[serde(flatten)]
+    extra: Option<Extra>,
 }
 
 impl Line {
@@ -596,6 +618,16 @@ impl Line {
         self.outlier_width = Some(outlier_width);
         self
     }
+
+    /// Sets an attribute that is not (yet) modelled by this struct. The value
+    /// is merged into the serialized object, overriding any typed attribute of
+    /// the same name.
+    pub fn extra(mut self, key: impl AsRef<str>, value: impl Into<serde_json::Value>) -> Self {
+        self.extra
+            .get_or_insert_with(Default::default)
+            .insert(key.as_ref().to_owned(), value.into());
+        self
+    }
 }
 
 #[derive(Serialize, Clone, Debug)]
@@ -1052,6 +1084,8 @@ pub struct Marker {
     color_bar: Option<ColorBar>,
     #[serde(rename = "outliercolor")]
     outlier_color: Option<Box<dyn Color>>,
+    #[serde(flatten)]
+    extra: Option<Extra>,
 }
 
 impl Marker {
@@ -1168,6 +1202,16 @@ impl Marker {
         self.outlier_color = Some(Box::new(outlier_color));
         self
     }
+
+    /// Sets an attribute that is not (yet) modelled by this struct. The value
+    /// is merged into the serialized object, overriding any typed attribute of
+    /// the same name.
+    pub fn extra(mut self, key: impl AsRef<str>, value: impl Into<serde_json::Value>) -> Self {
+        self.extra
+            .get_or_insert_with(Default::default)
+            .insert(key.as_ref().to_owned(), value.into());
+        self
+    }
 }
 
 #[serde_with::skip_serializing_none]
@@ -1584,18 +1628,38 @@ mod tests {
         assert_eq!(to_value(PlotType::Scatter).unwrap(), json!("scatter"));
         assert_eq!(to_value(PlotType::ScatterGL).unwrap(), json!("scattergl"));
         assert_eq!(to_value(PlotType::Scatter3D).unwrap(), json!("scatter3d"));
+        assert_eq!(to_value(PlotType::ScatterCarpet).unwrap(), json!("scattercarpet"));
+        assert_eq!(to_value(PlotType::ScatterMap).unwrap(), json!("scattermap"));
         assert_eq!(to_value(PlotType::ScatterPolar).unwrap(), json!("scatterpolar"));
         assert_eq!(to_value(PlotType::ScatterPolarGL).unwrap(), json!("scatterpolargl"));
+        assert_eq!(to_value(PlotType::ScatterSmith).unwrap(), json!("scattersmith"));
         assert_eq!(to_value(PlotType::Bar).unwrap(), json!("bar"));
+        assert_eq!(to_value(PlotType::BarPolar).unwrap(), json!("barpolar"));
         assert_eq!(to_value(PlotType::Box).unwrap(), json!("box"));
         assert_eq!(to_value(PlotType::Candlestick).unwrap(), json!("candlestick"));
+        assert_eq!(to_value(PlotType::Carpet).unwrap(), json!("carpet"));
+        assert_eq!(to_value(PlotType::ChoroplethMap).unwrap(), json!("choroplethmap"));
+        assert_eq!(to_value(PlotType::ChoroplethMapbox).unwrap(), json!("choroplethmapbox"));
+        assert_eq!(to_value(PlotType::Cone).unwrap(), json!("cone"));
         assert_eq!(to_value(PlotType::Contour).unwrap(), json!("contour"));
+        assert_eq!(to_value(PlotType::ContourCarpet).unwrap(), json!("contourcarpet"));
+        assert_eq!(to_value(PlotType::DensityMap).unwrap(), json!("densitymap"));
+        assert_eq!(to_value(PlotType::DensityMapbox).unwrap(), json!("densitymapbox"));
         assert_eq!(to_value(PlotType::HeatMap).unwrap(), json!("heatmap"));
         assert_eq!(to_value(PlotType::Histogram).unwrap(), json!("histogram"));
+        assert_eq!(to_value(PlotType::Histogram2d).unwrap(), json!("histogram2d"));
         assert_eq!(to_value(PlotType::Histogram2dContour).unwrap(), json!("histogram2dcontour"));
+        assert_eq!(to_value(PlotType::Indicator).unwrap(), json!("indicator"));
+        assert_eq!(to_value(PlotType::Isosurface).unwrap(), json!("isosurface"));
         assert_eq!(to_value(PlotType::Ohlc).unwrap(), json!("ohlc"));
+        assert_eq!(to_value(PlotType::Parcats).unwrap(), json!("parcats"));
+        assert_eq!(to_value(PlotType::Parcoords).unwrap(), json!("parcoords"));
         assert_eq!(to_value(PlotType::Sankey).unwrap(), json!("sankey"));
+        assert_eq!(to_value(PlotType::Splom).unwrap(), json!("splom"));
+        assert_eq!(to_value(PlotType::Streamtube).unwrap(), json!("streamtube"));
         assert_eq!(to_value(PlotType::Surface).unwrap(), json!("surface"));
+        assert_eq!(to_value(PlotType::Table).unwrap(), json!("table"));
+        assert_eq!(to_value(PlotType::Volume).unwrap(), json!("volume"));
     }
 
     #[test]
@@ -2143,6 +2207,22 @@ mod tests {
         assert_eq!(to_value(marker).unwrap(), expected);
     }
 
+    #[test]
+    fn test_serialize_marker_extra() {
+        let marker = Marker::new()
+            .size(5)
+            .extra("angle", 45)
+            .line(Line::new().extra("backoff", json!([1, 2])));
+
+        let expected = json!({
+            "size": 5,
+            "angle": 45,
+            "line": {"backoff": [1, 2]}
+        });
+
+        assert_eq!(to_value(marker).unwrap(), expected);
+    }
+
     #[test]
     fn test_serialize_font() {
         let font = Font::new().family("family").size(100).color("#FFFFFF");
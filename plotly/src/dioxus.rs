@@ -0,0 +1,105 @@
+//! A [Dioxus](https://dioxuslabs.com) component for drawing plots, enabled by
+//! the `dioxus` feature.
+//!
+//! ```no_run
+//! use dioxus::prelude::*;
+//! use plotly::{dioxus::PlotlyChart, Plot, Scatter};
+//!
+//! #[component]
+//! fn App() -> Element {
+//!     let mut y = use_signal(|| vec![2, 1, 0]);
+//!     let mut plot = Plot::new();
+//!     plot.add_trace(Scatter::new(vec![0, 1, 2], y()));
+//!
+//!     rsx! {
+//!         PlotlyChart {
+//!             plot: plot,
+//!             on_click: move |_| y.write().reverse(),
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::{cell::RefCell, rc::Rc};
+
+use dioxus::{prelude::*, web::WebEventExt};
+use wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
+
+use crate::{
+    bindings::{
+        chart::{Chart, EventHandlers},
+        events::{LegendClickEvent, PointsEvent, RelayoutEvent, RestyleEvent, SelectionEvent},
+    },
+    Plot,
+};
+
+fn handler<T: 'static, R: 'static>(
+    callback: Option<Callback<T, R>>,
+) -> Option<Box<dyn FnMut(T) -> R>> {
+    callback.map(|callback| Box::new(move |event| callback.call(event)) as Box<_>)
+}
+
+/// A `div` with a chart of the `plot` property. The chart is redrawn with the
+/// plotly.js `react` function when `plot` changes, follows the size of the
+/// `div` and is purged when the component is unmounted.
+///
+/// An `on_legend_click` callback returning `false` cancels toggling the
+/// visibility of the trace.
+#[component]
+pub fn PlotlyChart(
+    plot: ReadOnlySignal<Plot>,
+    id: Option<String>,
+    class: Option<String>,
+    on_click: Option<EventHandler<PointsEvent>>,
+    on_hover: Option<EventHandler<PointsEvent>>,
+    on_unhover: Option<EventHandler<PointsEvent>>,
+    on_selected: Option<EventHandler<Option<SelectionEvent>>>,
+    on_relayout: Option<EventHandler<RelayoutEvent>>,
+    on_restyle: Option<EventHandler<RestyleEvent>>,
+    on_legend_click: Option<Callback<LegendClickEvent, bool>>,
+    on_double_click: Option<EventHandler<()>>,
+) -> Element {
+    let mut element = use_signal(|| None::<HtmlElement>);
+    let chart = use_hook(|| Rc::new(RefCell::new(None::<Chart>)));
+
+    {
+        let chart = chart.clone();
+        // Dioxus keeps callback properties pointing to the closures of the
+        // latest render, so the handlers only need to be registered when the
+        // plot changes.
+        use_effect(move || {
+            let plot = plot();
+            let Some(element) = element() else {
+                return;
+            };
+            let handlers = EventHandlers {
+                on_click: handler(on_click),
+                on_hover: handler(on_hover),
+                on_unhover: handler(on_unhover),
+                on_selected: handler(on_selected),
+                on_relayout: handler(on_relayout),
+                on_restyle: handler(on_restyle),
+                on_legend_click: handler(on_legend_click),
+                on_double_click: on_double_click
+                    .map(|callback| Box::new(move || callback.call(())) as Box<_>),
+            };
+            chart
+                .borrow_mut()
+                .get_or_insert_with(|| Chart::new(element))
+                .render(plot, handlers);
+        });
+    }
+    use_drop(move || drop(chart.borrow_mut().take()));
+
+    rsx! {
+        div {
+            id: id,
+            class: class,
+            onmounted: move |event| {
+                let mounted = event.try_as_web_event().and_then(|e| e.dyn_into().ok());
+                element.set(mounted);
+            },
+        }
+    }
+}
@@ -0,0 +1,280 @@
+//! Data of the events emitted by plotly.js charts, as received by the handlers
+//! registered through the `wasm` bindings or the `server` feature.
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+/// Index of a point in the data arrays of its trace. Traces with 2D data, such
+/// as `HeatMap`, report a `[row, column]` pair.
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(untagged)]
+pub enum PointIndex {
+    Single(usize),
+    Grid(Vec<usize>),
+}
+
+/// A point of a trace, as reported by click, hover and selection events.
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct Point {
+    /// The index of the trace the point belongs to.
+    #[serde(rename = "curveNumber")]
+    pub curve_number: usize,
+    #[serde(rename = "pointNumber")]
+    pub point_number: Option<PointIndex>,
+    #[serde(rename = "pointIndex")]
+    pub point_index: Option<PointIndex>,
+    pub x: Option<Value>,
+    pub y: Option<Value>,
+    pub z: Option<Value>,
+    #[serde(rename = "customdata")]
+    pub custom_data: Option<Value>,
+}
+
+/// Data of the `plotly_click`, `plotly_hover` and `plotly_unhover` events.
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct PointsEvent {
+    pub points: Vec<Point>,
+}
+
+/// Data of the `plotly_selected` event.
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct SelectionEvent {
+    pub points: Vec<Point>,
+    /// The extent of a box selection, as `[min, max]` per axis, keyed by axis
+    /// id (e.g. "x", "y2").
+    pub range: Option<BTreeMap<String, Vec<Value>>>,
+    /// The vertices of a lasso selection, keyed by axis id.
+    #[serde(rename = "lassoPoints")]
+    pub lasso_points: Option<BTreeMap<String, Vec<Value>>>,
+}
+
+/// The range of an axis after zooming or panning.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AxisRange {
+    pub start: Value,
+    pub end: Value,
+}
+
+/// Data of the `plotly_relayout` event, i.e. the layout attributes that have
+/// changed, keyed by their plotly.js path (e.g. "xaxis.range[0]").
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(transparent)]
+pub struct RelayoutEvent {
+    attributes: Map<String, Value>,
+}
+
+impl RelayoutEvent {
+    pub fn attributes(&self) -> &Map<String, Value> {
+        &self.attributes
+    }
+
+    /// The new range of the axis with the given layout name (e.g. "xaxis",
+    /// "yaxis2"), if it was changed by this event.
+    pub fn axis_range(&self, axis: &str) -> Option<AxisRange> {
+        if let Some(Value::Array(range)) = self.attributes.get(&format!("{}.range", axis)) {
+            if let [start, end] = range.as_slice() {
+                return Some(AxisRange {
+                    start: start.clone(),
+                    end: end.clone(),
+                });
+            }
+        }
+        let start = self.attributes.get(&format!("{}.range[0]", axis))?;
+        let end = self.attributes.get(&format!("{}.range[1]", axis))?;
+        Some(AxisRange {
+            start: start.clone(),
+            end: end.clone(),
+        })
+    }
+
+    /// Whether the axis with the given layout name was reset to autorange by
+    /// this event, e.g. after a double click.
+    pub fn axis_autorange(&self, axis: &str) -> bool {
+        self.attributes.get(&format!("{}.autorange", axis)) == Some(&Value::Bool(true))
+    }
+}
+
+/// Data of the `plotly_restyle` event.
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(from = "(Map<String, Value>, Vec<usize>)")]
+pub struct RestyleEvent {
+    /// The trace attributes that have changed.
+    pub update: Map<String, Value>,
+    /// The indices of the traces the update was applied to.
+    pub traces: Vec<usize>,
+}
+
+impl From<(Map<String, Value>, Vec<usize>)> for RestyleEvent {
+    fn from((update, traces): (Map<String, Value>, Vec<usize>)) -> Self {
+        Self { update, traces }
+    }
+}
+
+/// Data of the `plotly_legendclick` event.
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct LegendClickEvent {
+    /// The index of the trace whose legend item was clicked.
+    #[serde(rename = "curveNumber")]
+    pub curve_number: usize,
+    /// The index of the expanded trace, for traces created by transforms.
+    #[serde(rename = "expandedIndex")]
+    pub expanded_index: Option<usize>,
+}
+
+/// Data of the `plotly_buttonclicked` event, emitted when a button of a
+/// `layout::update_menu::UpdateMenu` is clicked.
+///
+/// plotly.js passes the menu and button objects themselves, which the page
+/// served by the `server` feature reduces to their indices.
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct ButtonClickEvent {
+    /// The index of the menu in `Layout::update_menus`.
+    pub menu: usize,
+    /// The index of the clicked button in the menu.
+    pub button: usize,
+}
+
+/// Data of the `plotly_sliderchange` event, emitted when the active step of a
+/// slider changes. Sliders are not modelled yet, but can be added with
+/// `Layout::extra("sliders", ...)`.
+///
+/// plotly.js passes the slider and step objects themselves, which the page
+/// served by the `server` feature reduces to their indices.
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct SliderChangeEvent {
+    /// The index of the slider in the `sliders` layout attribute.
+    pub slider: usize,
+    /// The index of the new active step.
+    pub step: usize,
+    pub label: Option<String>,
+    pub value: Option<Value>,
+    /// Whether the step was changed by the user, rather than e.g. by an
+    /// animation.
+    pub interaction: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_value, json};
+
+    use super::*;
+
+    #[test]
+    fn test_deserialize_points_event() {
+        let data = json!({
+            "points": [
+                {"curveNumber": 0, "pointNumber": 2, "pointIndex": 2, "x": 3, "y": "b", "customdata": [1, 2]},
+                {"curveNumber": 1, "pointNumber": [1, 4], "x": 4, "y": 1, "z": 0.5}
+            ]
+        });
+        let expected = PointsEvent {
+            points: vec![
+                Point {
+                    curve_number: 0,
+                    point_number: Some(PointIndex::Single(2)),
+                    point_index: Some(PointIndex::Single(2)),
+                    x: Some(json!(3)),
+                    y: Some(json!("b")),
+                    z: None,
+                    custom_data: Some(json!([1, 2])),
+                },
+                Point {
+                    curve_number: 1,
+                    point_number: Some(PointIndex::Grid(vec![1, 4])),
+                    point_index: None,
+                    x: Some(json!(4)),
+                    y: Some(json!(1)),
+                    z: Some(json!(0.5)),
+                    custom_data: None,
+                },
+            ],
+        };
+
+        assert_eq!(from_value::<PointsEvent>(data).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_deserialize_selection_event() {
+        let data = json!({
+            "points": [{"curveNumber": 0, "pointIndex": 1, "x": 1, "y": 2}],
+            "range": {"x": [0.5, 1.5], "y": [1, 3]}
+        });
+        let event: SelectionEvent = from_value(data).unwrap();
+
+        assert_eq!(event.points.len(), 1);
+        assert_eq!(event.range.unwrap()["x"], vec![json!(0.5), json!(1.5)]);
+        assert_eq!(event.lasso_points, None);
+    }
+
+    #[test]
+    fn test_relayout_event_axis_range() {
+        let event: RelayoutEvent = from_value(json!({
+            "xaxis.range[0]": "2023-01-01",
+            "xaxis.range[1]": "2023-02-01",
+            "yaxis2.range": [0, 10],
+            "yaxis.autorange": true
+        }))
+        .unwrap();
+
+        assert_eq!(
+            event.axis_range("xaxis"),
+            Some(AxisRange {
+                start: json!("2023-01-01"),
+                end: json!("2023-02-01")
+            })
+        );
+        assert_eq!(
+            event.axis_range("yaxis2"),
+            Some(AxisRange {
+                start: json!(0),
+                end: json!(10)
+            })
+        );
+        assert_eq!(event.axis_range("yaxis"), None);
+        assert!(event.axis_autorange("yaxis"));
+        assert!(!event.axis_autorange("xaxis"));
+        assert_eq!(event.attributes().len(), 4);
+    }
+
+    #[test]
+    fn test_deserialize_restyle_event() {
+        let event: RestyleEvent = from_value(json!([{"visible": [false]}, [2]])).unwrap();
+
+        assert_eq!(event.update["visible"], json!([false]));
+        assert_eq!(event.traces, vec![2]);
+    }
+
+    #[test]
+    fn test_deserialize_legend_click_event() {
+        let event: LegendClickEvent =
+            from_value(json!({"curveNumber": 3, "expandedIndex": 3})).unwrap();
+
+        assert_eq!(event.curve_number, 3);
+        assert_eq!(event.expanded_index, Some(3));
+    }
+
+    #[test]
+    fn test_deserialize_button_click_event() {
+        let event: ButtonClickEvent = from_value(json!({"menu": 1, "button": 2})).unwrap();
+
+        assert_eq!(event, ButtonClickEvent { menu: 1, button: 2 });
+    }
+
+    #[test]
+    fn test_deserialize_slider_change_event() {
+        let event: SliderChangeEvent = from_value(json!({
+            "slider": 0,
+            "step": 3,
+            "label": "2007",
+            "value": "2007",
+            "interaction": true
+        }))
+        .unwrap();
+
+        assert_eq!(event.step, 3);
+        assert_eq!(event.label.as_deref(), Some("2007"));
+        assert!(event.interaction);
+    }
+}
@@ -1,7 +1,7 @@
 pub mod themes;
 pub mod update_menu;
 
-use std::borrow::Cow;
+use std::{borrow::Cow, collections::BTreeMap};
 
 use plotly_derive::FieldSetter;
 use serde::{Serialize, Serializer};
@@ -10,8 +10,8 @@ use update_menu::UpdateMenu;
 use crate::{
     color::Color,
     common::{
-        Anchor, AxisSide, Calendar, ColorBar, ColorScale, DashType, ExponentFormat, Font, Label,
-        Orientation, TickFormatStop, TickMode, Title,
+        Anchor, AxisSide, Calendar, ColorBar, ColorScale, DashType, Domain, ExponentFormat, Font,
+        Label, Orientation, Position, TickFormatStop, TickMode, Title,
     },
     private::{NumOrString, NumOrStringCollection},
 };
@@ -193,6 +193,9 @@ pub struct Legend {
     group_click: Option<GroupClick>,
     #[serde(rename = "itemwidth")]
     item_width: Option<usize>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl Legend {
@@ -231,6 +234,9 @@ pub struct Margin {
     pad: Option<usize>,
     #[serde(rename = "autoexpand")]
     auto_expand: Option<bool>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl Margin {
@@ -246,6 +252,9 @@ pub struct LayoutColorScale {
     #[serde(rename = "sequentialminus")]
     sequential_minus: Option<ColorScale>,
     diverging: Option<ColorScale>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl LayoutColorScale {
@@ -268,6 +277,9 @@ pub struct RangeSliderYAxis {
     #[serde(rename = "rangemode")]
     range_mode: Option<SliderRangeMode>,
     range: Option<NumOrStringCollection>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl RangeSliderYAxis {
@@ -292,6 +304,9 @@ pub struct RangeSlider {
     visible: Option<bool>,
     #[serde(rename = "yaxis")]
     y_axis: Option<RangeSliderYAxis>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl RangeSlider {
@@ -331,6 +346,9 @@ pub struct SelectorButton {
     name: Option<String>,
     #[serde(rename = "templateitemname")]
     template_item_name: Option<String>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl SelectorButton {
@@ -359,6 +377,9 @@ pub struct RangeSelector {
     border_color: Option<Box<dyn Color>>,
     #[serde(rename = "borderwidth")]
     border_width: Option<usize>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl RangeSelector {
@@ -384,6 +405,9 @@ pub struct ColorAxis {
     show_scale: Option<bool>,
     #[serde(rename = "colorbar")]
     color_bar: Option<ColorBar>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl ColorAxis {
@@ -535,6 +559,9 @@ pub struct Axis {
     #[serde(rename = "rangeselector")]
     range_selector: Option<RangeSelector>,
     calendar: Option<Calendar>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl Axis {
@@ -602,6 +629,9 @@ pub enum GridYSide {
 pub struct GridDomain {
     x: Option<Vec<f64>>,
     y: Option<Vec<f64>>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl GridDomain {
@@ -633,6 +663,9 @@ pub struct LayoutGrid {
     x_side: Option<GridXSide>,
     #[serde(rename = "yside")]
     y_side: Option<GridYSide>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl LayoutGrid {
@@ -667,6 +700,9 @@ pub struct UniformText {
     mode: Option<UniformTextMode>,
     #[serde(rename = "minsize")]
     min_size: Option<usize>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl UniformText {
@@ -710,6 +746,9 @@ pub struct ModeBar {
     color: Option<Box<dyn Color>>,
     #[serde(rename = "activecolor")]
     active_color: Option<Box<dyn Color>>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl ModeBar {
@@ -759,6 +798,9 @@ pub struct ShapeLine {
     /// "dash", "longdash", "dashdot", or "longdashdot") or a dash length
     /// list in px (eg "5px,10px,2px,2px").
     dash: Option<DashType>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl ShapeLine {
@@ -888,6 +930,9 @@ pub struct Shape {
     /// explicitly show it with `visible: true`.
     #[serde(rename = "templateitemname")]
     template_item_name: Option<String>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl Shape {
@@ -945,6 +990,9 @@ pub struct NewShape {
     /// extend.
     #[serde(rename = "drawdirection")]
     draw_direction: Option<DrawDirection>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl NewShape {
@@ -962,6 +1010,9 @@ pub struct ActiveShape {
     /// Sets the opacity of the active shape. Number between or equal to 0 and
     /// 1.
     opacity: Option<f64>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl ActiveShape {
@@ -1227,6 +1278,9 @@ pub struct Annotation {
     /// explicitly show it with `visible: true`.
     #[serde(rename = "templateitemname")]
     template_item_name: Option<String>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl Annotation {
@@ -1355,8 +1409,286 @@ pub enum MapboxStyle {
     Dark,
     Satellite,
     SatelliteStreets,
+    /// A custom style given by URL, e.g. `mapbox://mapbox.mapbox-streets-v8`
+    /// or the address of a style served by a local tile server.
+    #[serde(untagged)]
+    Custom(String),
+    /// A complete Mapbox style JSON object, e.g. one describing a raster tile
+    /// source.
+    #[serde(untagged)]
+    Json(serde_json::Value),
 }
 
+impl From<&str> for MapboxStyle {
+    fn from(url: &str) -> Self {
+        Self::Custom(url.to_owned())
+    }
+}
+
+impl From<String> for MapboxStyle {
+    fn from(url: String) -> Self {
+        Self::Custom(url)
+    }
+}
+
+impl From<serde_json::Value> for MapboxStyle {
+    fn from(style: serde_json::Value) -> Self {
+        Self::Json(style)
+    }
+}
+
+/// Sets the bounds of the map; the map cannot be panned or zoomed outside of
+/// them.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct MapboxBounds {
+    /// Sets the maximum longitude of the map (in degrees East).
+    east: Option<f64>,
+    /// Sets the minimum latitude of the map (in degrees North).
+    south: Option<f64>,
+    /// Sets the minimum longitude of the map (in degrees East).
+    west: Option<f64>,
+    /// Sets the maximum latitude of the map (in degrees North).
+    north: Option<f64>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl MapboxBounds {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum MapboxLayerSourceType {
+    GeoJson,
+    Vector,
+    Raster,
+    Image,
+}
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum MapboxLayerType {
+    Circle,
+    Line,
+    Fill,
+    Symbol,
+    Raster,
+}
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum SymbolPlacement {
+    Point,
+    Line,
+    #[serde(rename = "line-center")]
+    LineCenter,
+}
+
+/// The data source of a `MapboxLayer`: a URL (to a GeoJSON file or vector
+/// tiles), a list of tile URLs for raster sources, or an inline GeoJSON
+/// object.
+#[derive(Serialize, Clone, Debug)]
+#[serde(untagged)]
+pub enum MapboxLayerSource {
+    Url(String),
+    Tiles(Vec<String>),
+    GeoJson(serde_json::Value),
+}
+
+impl From<&str> for MapboxLayerSource {
+    fn from(url: &str) -> Self {
+        Self::Url(url.to_owned())
+    }
+}
+
+impl From<String> for MapboxLayerSource {
+    fn from(url: String) -> Self {
+        Self::Url(url)
+    }
+}
+
+impl<S: AsRef<str>> From<Vec<S>> for MapboxLayerSource {
+    fn from(tiles: Vec<S>) -> Self {
+        Self::Tiles(tiles.iter().map(|t| t.as_ref().to_owned()).collect())
+    }
+}
+
+impl From<serde_json::Value> for MapboxLayerSource {
+    fn from(geojson: serde_json::Value) -> Self {
+        Self::GeoJson(geojson)
+    }
+}
+
+/// Style options for layers of type `MapboxLayerType::Circle`.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct MapboxLayerCircle {
+    /// Sets the circle radius.
+    radius: Option<f64>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl MapboxLayerCircle {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+/// Style options for layers of type `MapboxLayerType::Line`.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct MapboxLayerLine {
+    /// Sets the length of dashes and gaps.
+    dash: Option<Vec<f64>>,
+    /// Sets the line width.
+    width: Option<f64>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl MapboxLayerLine {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+/// Style options for layers of type `MapboxLayerType::Fill`.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct MapboxLayerFill {
+    /// Sets the fill outline color.
+    #[serde(rename = "outlinecolor")]
+    outline_color: Option<Box<dyn Color>>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl MapboxLayerFill {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+/// Style options for layers of type `MapboxLayerType::Symbol`.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct MapboxLayerSymbol {
+    /// Sets the symbol icon image. Full list:
+    /// <https://www.mapbox.com/maki-icons/>
+    icon: Option<String>,
+    /// Sets the symbol icon size.
+    #[serde(rename = "iconsize")]
+    icon_size: Option<f64>,
+    /// Sets the symbol and/or text placement.
+    placement: Option<SymbolPlacement>,
+    /// Sets the symbol text.
+    text: Option<String>,
+    /// Sets the icon text font. Has an effect only when `type` is set to
+    /// "symbol".
+    #[serde(rename = "textfont")]
+    text_font: Option<Font>,
+    /// Sets the positions of the `text` elements with respects to the
+    /// (x,y) coordinates.
+    #[serde(rename = "textposition")]
+    text_position: Option<Position>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl MapboxLayerSymbol {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+/// A layer drawn on a `Mapbox` subplot, e.g. a GeoJSON overlay or a raster
+/// tile layer from a (possibly self-hosted) tile server.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct MapboxLayer {
+    /// Determines whether this layer is displayed.
+    visible: Option<bool>,
+    /// Sets the source type for this layer.
+    #[serde(rename = "sourcetype")]
+    source_type: Option<MapboxLayerSourceType>,
+    /// Sets the source data for this layer.
+    #[field_setter(skip)]
+    source: Option<MapboxLayerSource>,
+    /// Specifies the layer to use from a vector tile source. Required for
+    /// "vector" source type that supports multiple layers.
+    #[serde(rename = "sourcelayer")]
+    source_layer: Option<String>,
+    /// Sets the attribution for this source.
+    #[serde(rename = "sourceattribution")]
+    source_attribution: Option<String>,
+    /// Sets the layer type. Support for "raster" and "background" types is
+    /// coming soon.
+    #[field_setter(skip)]
+    r#type: Option<MapboxLayerType>,
+    /// Sets the coordinates array contains [longitude, latitude] pairs for the
+    /// image corners listed in clockwise order: top left, top right, bottom
+    /// right, bottom left. Only has an effect for "image" `source_type`.
+    coordinates: Option<Vec<[f64; 2]>>,
+    /// Determines if the layer will be inserted before the layer with the
+    /// specified ID. If omitted or set to "", the layer will be inserted above
+    /// every existing layer.
+    below: Option<String>,
+    /// Sets the primary layer color.
+    color: Option<Box<dyn Color>>,
+    /// Sets the opacity of the layer.
+    opacity: Option<f64>,
+    /// Sets the minimum zoom level (mapbox-gl-js `minzoom`). At zoom levels
+    /// less than the `min_zoom`, the layer will be hidden.
+    #[serde(rename = "minzoom")]
+    min_zoom: Option<f64>,
+    /// Sets the maximum zoom level (mapbox-gl-js `maxzoom`). At zoom levels
+    /// equal to or greater than the `max_zoom`, the layer will be hidden.
+    #[serde(rename = "maxzoom")]
+    max_zoom: Option<f64>,
+    circle: Option<MapboxLayerCircle>,
+    line: Option<MapboxLayerLine>,
+    fill: Option<MapboxLayerFill>,
+    symbol: Option<MapboxLayerSymbol>,
+    /// When used in a template, named items are created in the output figure
+    /// in addition to any items the figure already has in this array.
+    name: Option<String>,
+    /// Used to refer to a named item in this array in the template.
+    #[serde(rename = "templateitemname")]
+    template_item_name: Option<String>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl MapboxLayer {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Sets the source data for this layer, either as a URL, a list of tile
+    /// URLs or a GeoJSON object.
+    pub fn source<S: Into<MapboxLayerSource>>(mut self, source: S) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    pub fn type_(mut self, t: MapboxLayerType) -> Self {
+        self.r#type = Some(t);
+        self
+    }
+}
+
+#[serde_with::skip_serializing_none]
 #[derive(Serialize, Clone, Debug, FieldSetter)]
 pub struct Mapbox {
     /// Sets the mapbox access token to be used for this mapbox map. Note that
@@ -1373,16 +1705,150 @@ pub struct Mapbox {
     /// Sets the pitch angle of the map in degrees, where `0` means
     /// perpendicular to the surface of the map.
     pitch: Option<f64>,
-    /// Sets the style of the map.
+    /// Sets the style of the map. Besides the predefined styles, a custom
+    /// style URL or a full style JSON object can be given.
+    #[field_setter(skip)]
     style: Option<MapboxStyle>,
     /// Sets the zoom level of the map.
-    zoom: Option<u8>,
+    zoom: Option<f64>,
+    /// Sets the bounds of the map.
+    bounds: Option<MapboxBounds>,
+    /// Sets the layers drawn on top of (or below) the base map.
+    layers: Option<Vec<MapboxLayer>>,
+    /// Sets the domain of this mapbox subplot.
+    domain: Option<Domain>,
+    /// Controls persistence of user-driven changes in the view: `center`,
+    /// `zoom`, `bearing` and `pitch`. Defaults to `layout.uirevision`.
+    #[serde(rename = "uirevision")]
+    ui_revision: Option<NumOrString>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl Mapbox {
     pub fn new() -> Self {
         Default::default()
     }
+
+    /// Sets the style of the map, either a `MapboxStyle`, a custom style URL or
+    /// a style JSON object.
+    pub fn style<S: Into<MapboxStyle>>(mut self, style: S) -> Self {
+        self.style = Some(style.into());
+        self
+    }
+
+    /// Appends a layer to the layers of the map.
+    pub fn add_layer(mut self, layer: MapboxLayer) -> Self {
+        self.layers.get_or_insert_with(Vec::new).push(layer);
+        self
+    }
+}
+
+/// Base map styles available to `LayoutMap`. None of these require an access
+/// token.
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub enum MapStyle {
+    Basic,
+    #[serde(rename = "carto-darkmatter")]
+    CartoDarkMatter,
+    #[serde(rename = "carto-darkmatter-nolabels")]
+    CartoDarkMatterNoLabels,
+    CartoPositron,
+    #[serde(rename = "carto-positron-nolabels")]
+    CartoPositronNoLabels,
+    CartoVoyager,
+    #[serde(rename = "carto-voyager-nolabels")]
+    CartoVoyagerNoLabels,
+    Dark,
+    Light,
+    OpenStreetMap,
+    Outdoors,
+    Satellite,
+    SatelliteStreets,
+    Streets,
+    WhiteBg,
+    /// A custom style given by URL, e.g. the address of a style served by a
+    /// local tile server.
+    #[serde(untagged)]
+    Custom(String),
+    /// A complete MapLibre style JSON object, e.g. one describing a raster
+    /// tile source.
+    #[serde(untagged)]
+    Json(serde_json::Value),
+}
+
+impl From<&str> for MapStyle {
+    fn from(url: &str) -> Self {
+        Self::Custom(url.to_owned())
+    }
+}
+
+impl From<String> for MapStyle {
+    fn from(url: String) -> Self {
+        Self::Custom(url)
+    }
+}
+
+impl From<serde_json::Value> for MapStyle {
+    fn from(style: serde_json::Value) -> Self {
+        Self::Json(style)
+    }
+}
+
+/// A MapLibre map subplot, used by the `ScatterMap`, `ChoroplethMap` and
+/// `DensityMap` traces. Requires plotly.js 2.35 or later, see
+/// `Plot::set_plotly_js_version`.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct LayoutMap {
+    /// Sets the bearing angle of the map in degrees counter-clockwise from
+    /// North.
+    bearing: Option<f64>,
+    /// Sets the latitude and longitude of the center of the map.
+    center: Option<Center>,
+    /// Sets the pitch angle of the map in degrees, where `0` means
+    /// perpendicular to the surface of the map.
+    pitch: Option<f64>,
+    /// Sets the style of the map. Besides the predefined styles, a custom
+    /// style URL or a full style JSON object can be given.
+    #[field_setter(skip)]
+    style: Option<MapStyle>,
+    /// Sets the zoom level of the map.
+    zoom: Option<f64>,
+    /// Sets the bounds of the map.
+    bounds: Option<MapboxBounds>,
+    /// Sets the layers drawn on top of (or below) the base map.
+    layers: Option<Vec<MapboxLayer>>,
+    /// Sets the domain of this map subplot.
+    domain: Option<Domain>,
+    /// Controls persistence of user-driven changes in the view: `center`,
+    /// `zoom`, `bearing` and `pitch`. Defaults to `layout.uirevision`.
+    #[serde(rename = "uirevision")]
+    ui_revision: Option<NumOrString>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl LayoutMap {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Sets the style of the map, either a `MapStyle`, a custom style URL or a
+    /// style JSON object.
+    pub fn style<S: Into<MapStyle>>(mut self, style: S) -> Self {
+        self.style = Some(style.into());
+        self
+    }
+
+    /// Appends a layer to the layers of the map.
+    pub fn add_layer(mut self, layer: MapboxLayer) -> Self {
+        self.layers.get_or_insert_with(Vec::new).push(layer);
+        self
+    }
 }
 
 #[derive(Serialize, Debug, Clone)]
@@ -1416,6 +1882,9 @@ pub struct Eye {
     x: Option<f64>,
     y: Option<f64>,
     z: Option<f64>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl Eye {
@@ -1424,6 +1893,7 @@ impl Eye {
             x: Some(1.25),
             y: Some(1.25),
             z: Some(1.25),
+            ..Default::default()
         }
     }
 }
@@ -1434,6 +1904,7 @@ impl From<(f64, f64, f64)> for Eye {
             x: Some(x),
             y: Some(y),
             z: Some(z),
+            ..Default::default()
         }
     }
 }
@@ -1447,6 +1918,9 @@ pub struct Up {
     x: Option<f64>,
     y: Option<f64>,
     z: Option<f64>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl Up {
@@ -1455,6 +1929,7 @@ impl Up {
             x: Some(0.0),
             y: Some(0.0),
             z: Some(1.0),
+            ..Default::default()
         }
     }
 }
@@ -1465,6 +1940,7 @@ impl From<(f64, f64, f64)> for Up {
             x: Some(x),
             y: Some(y),
             z: Some(z),
+            ..Default::default()
         }
     }
 }
@@ -1485,6 +1961,7 @@ impl From<ProjectionType> for Projection {
     fn from(projection_type: ProjectionType) -> Self {
         Projection {
             projection_type: Some(projection_type),
+            ..Default::default()
         }
     }
 }
@@ -1495,6 +1972,9 @@ impl From<ProjectionType> for Projection {
 pub struct Projection {
     #[serde(rename = "type")]
     projection_type: Option<ProjectionType>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl Projection {
@@ -1513,6 +1993,9 @@ pub struct CameraCenter {
     x: Option<f64>,
     y: Option<f64>,
     z: Option<f64>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl CameraCenter {
@@ -1521,6 +2004,7 @@ impl CameraCenter {
             x: Some(0.0),
             y: Some(0.0),
             z: Some(0.0),
+            ..Default::default()
         }
     }
 }
@@ -1531,6 +2015,7 @@ impl From<(f64, f64, f64)> for CameraCenter {
             x: Some(x),
             y: Some(y),
             z: Some(z),
+            ..Default::default()
         }
     }
 }
@@ -1544,6 +2029,9 @@ pub struct Camera {
     eye: Option<Eye>,
     up: Option<Up>,
     projection: Option<Projection>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl Camera {
@@ -1561,6 +2049,9 @@ pub struct AspectRatio {
     x: Option<f64>,
     y: Option<f64>,
     z: Option<f64>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl AspectRatio {
@@ -1569,6 +2060,7 @@ impl AspectRatio {
             x: Some(1.0),
             y: Some(1.0),
             z: Some(1.0),
+            ..Default::default()
         }
     }
 }
@@ -1579,6 +2071,7 @@ impl From<(f64, f64, f64)> for AspectRatio {
             x: Some(x),
             y: Some(y),
             z: Some(z),
+            ..Default::default()
         }
     }
 }
@@ -1607,6 +2100,9 @@ pub struct LayoutScene {
     annotations: Option<Vec<Annotation>>,
     // domain: Domain,
     // uirevision: Uirevision,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl LayoutScene {
@@ -1615,10 +2111,174 @@ impl LayoutScene {
     }
 }
 
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum AxisLayer {
+    #[serde(rename = "above traces")]
+    AboveTraces,
+    #[serde(rename = "below traces")]
+    BelowTraces,
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum RealAxisTicks {
+    Top,
+    Bottom,
+    #[serde(rename = "")]
+    None,
+}
+
+/// The real axis of a Smith chart, i.e. the circles of constant resistance.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Debug, Clone, FieldSetter)]
+pub struct RealAxis {
+    visible: Option<bool>,
+    color: Option<Box<dyn Color>>,
+    #[serde(rename = "showline")]
+    show_line: Option<bool>,
+    #[serde(rename = "linecolor")]
+    line_color: Option<Box<dyn Color>>,
+    #[serde(rename = "linewidth")]
+    line_width: Option<usize>,
+    #[serde(rename = "showgrid")]
+    show_grid: Option<bool>,
+    #[serde(rename = "gridcolor")]
+    grid_color: Option<Box<dyn Color>>,
+    #[serde(rename = "griddash")]
+    grid_dash: Option<DashType>,
+    #[serde(rename = "gridwidth")]
+    grid_width: Option<usize>,
+    /// Sets the layer on which this axis is displayed.
+    layer: Option<AxisLayer>,
+    /// Determines on which side of real axis line the tick and tick labels
+    /// appear. Only `AxisSide::Top` and `AxisSide::Bottom` are valid.
+    side: Option<AxisSide>,
+    #[serde(rename = "tickvals")]
+    tick_values: Option<Vec<f64>>,
+    ticks: Option<RealAxisTicks>,
+    #[serde(rename = "ticklen")]
+    tick_length: Option<usize>,
+    #[serde(rename = "tickwidth")]
+    tick_width: Option<usize>,
+    #[serde(rename = "tickcolor")]
+    tick_color: Option<Box<dyn Color>>,
+    #[serde(rename = "showticklabels")]
+    show_tick_labels: Option<bool>,
+    #[serde(rename = "tickfont")]
+    tick_font: Option<Font>,
+    #[serde(rename = "tickangle")]
+    tick_angle: Option<f64>,
+    #[serde(rename = "tickprefix")]
+    tick_prefix: Option<String>,
+    #[serde(rename = "showtickprefix")]
+    show_tick_prefix: Option<ArrayShow>,
+    #[serde(rename = "ticksuffix")]
+    tick_suffix: Option<String>,
+    #[serde(rename = "showticksuffix")]
+    show_tick_suffix: Option<ArrayShow>,
+    #[serde(rename = "tickformat")]
+    tick_format: Option<String>,
+    #[serde(rename = "hoverformat")]
+    hover_format: Option<String>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl RealAxis {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+/// The imaginary axis of a Smith chart, i.e. the arcs of constant reactance.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Debug, Clone, FieldSetter)]
+pub struct ImaginaryAxis {
+    visible: Option<bool>,
+    color: Option<Box<dyn Color>>,
+    #[serde(rename = "showline")]
+    show_line: Option<bool>,
+    #[serde(rename = "linecolor")]
+    line_color: Option<Box<dyn Color>>,
+    #[serde(rename = "linewidth")]
+    line_width: Option<usize>,
+    #[serde(rename = "showgrid")]
+    show_grid: Option<bool>,
+    #[serde(rename = "gridcolor")]
+    grid_color: Option<Box<dyn Color>>,
+    #[serde(rename = "griddash")]
+    grid_dash: Option<DashType>,
+    #[serde(rename = "gridwidth")]
+    grid_width: Option<usize>,
+    /// Sets the layer on which this axis is displayed.
+    layer: Option<AxisLayer>,
+    #[serde(rename = "tickvals")]
+    tick_values: Option<Vec<f64>>,
+    ticks: Option<TicksDirection>,
+    #[serde(rename = "ticklen")]
+    tick_length: Option<usize>,
+    #[serde(rename = "tickwidth")]
+    tick_width: Option<usize>,
+    #[serde(rename = "tickcolor")]
+    tick_color: Option<Box<dyn Color>>,
+    #[serde(rename = "showticklabels")]
+    show_tick_labels: Option<bool>,
+    #[serde(rename = "tickfont")]
+    tick_font: Option<Font>,
+    #[serde(rename = "tickprefix")]
+    tick_prefix: Option<String>,
+    #[serde(rename = "showtickprefix")]
+    show_tick_prefix: Option<ArrayShow>,
+    #[serde(rename = "ticksuffix")]
+    tick_suffix: Option<String>,
+    #[serde(rename = "showticksuffix")]
+    show_tick_suffix: Option<ArrayShow>,
+    #[serde(rename = "tickformat")]
+    tick_format: Option<String>,
+    #[serde(rename = "hoverformat")]
+    hover_format: Option<String>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl ImaginaryAxis {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Debug, Clone, FieldSetter)]
+/// Smith chart layout, used by `ScatterSmith` traces
+pub struct LayoutSmith {
+    #[serde(rename = "bgcolor")]
+    background_color: Option<Box<dyn Color>>,
+    domain: Option<Domain>,
+    #[serde(rename = "realaxis")]
+    real_axis: Option<RealAxis>,
+    #[serde(rename = "imaginaryaxis")]
+    imaginary_axis: Option<ImaginaryAxis>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl LayoutSmith {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
 #[serde_with::skip_serializing_none]
 #[derive(Serialize, Debug, Clone, FieldSetter)]
 pub struct Template {
     layout: Option<LayoutTemplate>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl Template {
@@ -1738,6 +2398,7 @@ pub struct LayoutTemplate {
     // ternary: Option<LayoutTernary>,
     scene: Option<LayoutScene>,
     // polar: Option<LayoutPolar>,
+    smith: Option<LayoutSmith>,
     annotations: Option<Vec<Annotation>>,
     shapes: Option<Vec<Shape>>,
     #[serde(rename = "newshape")]
@@ -1784,6 +2445,9 @@ pub struct LayoutTemplate {
     sunburst_colorway: Option<Vec<Box<dyn Color>>>,
     #[serde(rename = "extendsunburstcolors")]
     extend_sunburst_colors: Option<bool>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl LayoutTemplate {
@@ -1918,10 +2582,22 @@ pub struct Layout {
     y_axis8: Option<Box<Axis>>,
     #[serde(rename = "zaxis8")]
     z_axis8: Option<Box<Axis>>,
+    /// Cartesian axes beyond `xaxis8`/`yaxis8`, keyed by their plotly.js name
+    /// (e.g. `"xaxis9"`). Set through `Layout::x_axis_n` and
+    /// `Layout::y_axis_n`.
+    #[field_setter(skip)]
+    #[serde(flatten)]
+    extra_axes: Option<BTreeMap<String, Box<Axis>>>,
 
     // ternary: Option<LayoutTernary>,
     scene: Option<LayoutScene>,
     // polar: Option<LayoutPolar>,
+    smith: Option<LayoutSmith>,
+    /// Smith subplots beyond `smith`, keyed by their plotly.js name (e.g.
+    /// `"smith2"`). Set through `Layout::smith_n`.
+    #[field_setter(skip)]
+    #[serde(flatten)]
+    extra_smiths: Option<BTreeMap<String, LayoutSmith>>,
     annotations: Option<Vec<Annotation>>,
     shapes: Option<Vec<Shape>>,
     #[serde(rename = "newshape")]
@@ -1970,9 +2646,23 @@ pub struct Layout {
     extend_sunburst_colors: Option<bool>,
 
     mapbox: Option<Mapbox>,
+    /// Mapbox subplots beyond `mapbox`, keyed by their plotly.js name (e.g.
+    /// `"mapbox2"`). Set through `Layout::mapbox_n`.
+    #[field_setter(skip)]
+    #[serde(flatten)]
+    extra_mapboxes: Option<BTreeMap<String, Mapbox>>,
+    map: Option<LayoutMap>,
+    /// Map subplots beyond `map`, keyed by their plotly.js name (e.g.
+    /// `"map2"`). Set through `Layout::map_n`.
+    #[field_setter(skip)]
+    #[serde(flatten)]
+    extra_maps: Option<BTreeMap<String, LayoutMap>>,
 
     #[serde(rename = "updatemenus")]
     update_menus: Option<Vec<UpdateMenu>>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl Layout {
@@ -2005,6 +2695,90 @@ impl Layout {
         self.template = Some(Box::new(template.into()));
         self
     }
+
+    /// Sets the `n`-th x-axis, i.e. `xaxis` for `n <= 1` and `xaxis{n}`
+    /// otherwise. Unlike `x_axis2` through `x_axis8` this is not limited in
+    /// the number of axes, which is useful for traces that generate many
+    /// subplots such as `Splom`.
+    pub fn x_axis_n(self, n: usize, axis: Axis) -> Layout {
+        match n {
+            0 | 1 => self.x_axis(axis),
+            2 => self.x_axis2(axis),
+            3 => self.x_axis3(axis),
+            4 => self.x_axis4(axis),
+            5 => self.x_axis5(axis),
+            6 => self.x_axis6(axis),
+            7 => self.x_axis7(axis),
+            8 => self.x_axis8(axis),
+            _ => self.extra_axis(format!("xaxis{}", n), axis),
+        }
+    }
+
+    /// Sets the `n`-th y-axis, i.e. `yaxis` for `n <= 1` and `yaxis{n}`
+    /// otherwise. See `Layout::x_axis_n`.
+    pub fn y_axis_n(self, n: usize, axis: Axis) -> Layout {
+        match n {
+            0 | 1 => self.y_axis(axis),
+            2 => self.y_axis2(axis),
+            3 => self.y_axis3(axis),
+            4 => self.y_axis4(axis),
+            5 => self.y_axis5(axis),
+            6 => self.y_axis6(axis),
+            7 => self.y_axis7(axis),
+            8 => self.y_axis8(axis),
+            _ => self.extra_axis(format!("yaxis{}", n), axis),
+        }
+    }
+
+    /// Sets the `n`-th mapbox subplot, i.e. `mapbox` for `n <= 1` and
+    /// `mapbox{n}` otherwise. Traces are placed on it by setting their
+    /// `subplot` to the same name.
+    pub fn mapbox_n(mut self, n: usize, mapbox: Mapbox) -> Layout {
+        match n {
+            0 | 1 => self.mapbox(mapbox),
+            _ => {
+                self.extra_mapboxes
+                    .get_or_insert_with(BTreeMap::new)
+                    .insert(format!("mapbox{}", n), mapbox);
+                self
+            }
+        }
+    }
+
+    /// Sets the `n`-th map subplot, i.e. `map` for `n <= 1` and `map{n}`
+    /// otherwise. See `Layout::mapbox_n`.
+    pub fn map_n(mut self, n: usize, map: LayoutMap) -> Layout {
+        match n {
+            0 | 1 => self.map(map),
+            _ => {
+                self.extra_maps
+                    .get_or_insert_with(BTreeMap::new)
+                    .insert(format!("map{}", n), map);
+                self
+            }
+        }
+    }
+
+    /// Sets the `n`-th smith subplot, i.e. `smith` for `n <= 1` and
+    /// `smith{n}` otherwise. See `Layout::mapbox_n`.
+    pub fn smith_n(mut self, n: usize, smith: LayoutSmith) -> Layout {
+        match n {
+            0 | 1 => self.smith(smith),
+            _ => {
+                self.extra_smiths
+                    .get_or_insert_with(BTreeMap::new)
+                    .insert(format!("smith{}", n), smith);
+                self
+            }
+        }
+    }
+
+    fn extra_axis(mut self, name: String, axis: Axis) -> Layout {
+        self.extra_axes
+            .get_or_insert_with(BTreeMap::new)
+            .insert(name, Box::new(axis));
+        self
+    }
 }
 
 #[cfg(test)]
@@ -2945,6 +3719,129 @@ mod tests {
         assert_eq!(to_value(MapboxStyle::Dark).unwrap(), json!("dark"));
         assert_eq!(to_value(MapboxStyle::Satellite).unwrap(), json!("satellite"));
         assert_eq!(to_value(MapboxStyle::SatelliteStreets).unwrap(), json!("satellite-streets"));
+        assert_eq!(to_value(MapboxStyle::from("mapbox://styles/custom")).unwrap(), json!("mapbox://styles/custom"));
+        assert_eq!(to_value(MapboxStyle::from(json!({"version": 8}))).unwrap(), json!({"version": 8}));
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_serialize_mapbox_layer_enums() {
+        assert_eq!(to_value(MapboxLayerSourceType::GeoJson).unwrap(), json!("geojson"));
+        assert_eq!(to_value(MapboxLayerSourceType::Vector).unwrap(), json!("vector"));
+        assert_eq!(to_value(MapboxLayerSourceType::Raster).unwrap(), json!("raster"));
+        assert_eq!(to_value(MapboxLayerSourceType::Image).unwrap(), json!("image"));
+
+        assert_eq!(to_value(MapboxLayerType::Circle).unwrap(), json!("circle"));
+        assert_eq!(to_value(MapboxLayerType::Line).unwrap(), json!("line"));
+        assert_eq!(to_value(MapboxLayerType::Fill).unwrap(), json!("fill"));
+        assert_eq!(to_value(MapboxLayerType::Symbol).unwrap(), json!("symbol"));
+        assert_eq!(to_value(MapboxLayerType::Raster).unwrap(), json!("raster"));
+
+        assert_eq!(to_value(SymbolPlacement::Point).unwrap(), json!("point"));
+        assert_eq!(to_value(SymbolPlacement::Line).unwrap(), json!("line"));
+        assert_eq!(to_value(SymbolPlacement::LineCenter).unwrap(), json!("line-center"));
+    }
+
+    #[test]
+    fn test_serialize_mapbox_layer() {
+        let layer = MapboxLayer::new()
+            .visible(true)
+            .source_type(MapboxLayerSourceType::Raster)
+            .source(vec!["http://localhost:8080/{z}/{x}/{y}.png"])
+            .source_layer("source_layer")
+            .source_attribution("attribution")
+            .type_(MapboxLayerType::Raster)
+            .coordinates(vec![[0.0, 1.0], [1.0, 1.0], [1.0, 0.0], [0.0, 0.0]])
+            .below("traces")
+            .color("#ff0000")
+            .opacity(0.5)
+            .min_zoom(1.0)
+            .max_zoom(20.0)
+            .circle(MapboxLayerCircle::new().radius(5.0))
+            .line(MapboxLayerLine::new().dash(vec![2.0, 1.0]).width(2.0))
+            .fill(MapboxLayerFill::new().outline_color("#000000"))
+            .symbol(
+                MapboxLayerSymbol::new()
+                    .icon("airport")
+                    .icon_size(10.0)
+                    .placement(SymbolPlacement::Point)
+                    .text("text")
+                    .text_font(Font::new())
+                    .text_position(Position::TopCenter),
+            )
+            .name("name")
+            .template_item_name("template_item_name");
+        let expected = json!({
+            "visible": true,
+            "sourcetype": "raster",
+            "source": ["http://localhost:8080/{z}/{x}/{y}.png"],
+            "sourcelayer": "source_layer",
+            "sourceattribution": "attribution",
+            "type": "raster",
+            "coordinates": [[0.0, 1.0], [1.0, 1.0], [1.0, 0.0], [0.0, 0.0]],
+            "below": "traces",
+            "color": "#ff0000",
+            "opacity": 0.5,
+            "minzoom": 1.0,
+            "maxzoom": 20.0,
+            "circle": {"radius": 5.0},
+            "line": {"dash": [2.0, 1.0], "width": 2.0},
+            "fill": {"outlinecolor": "#000000"},
+            "symbol": {
+                "icon": "airport",
+                "iconsize": 10.0,
+                "placement": "point",
+                "text": "text",
+                "textfont": {},
+                "textposition": "top center"
+            },
+            "name": "name",
+            "templateitemname": "template_item_name",
+        });
+
+        assert_eq!(to_value(layer).unwrap(), expected);
+
+        let layer = MapboxLayer::new().source(json!({"type": "FeatureCollection"}));
+        assert_eq!(
+            to_value(layer).unwrap(),
+            json!({"source": {"type": "FeatureCollection"}})
+        );
+    }
+
+    #[test]
+    fn test_serialize_mapbox() {
+        let mapbox = Mapbox::new()
+            .access_token("token")
+            .bearing(90.0)
+            .center(Center::new(45.5, -73.5))
+            .pitch(30.0)
+            .style(MapboxStyle::WhiteBg)
+            .zoom(2.5)
+            .bounds(
+                MapboxBounds::new()
+                    .east(10.0)
+                    .south(-10.0)
+                    .west(-10.0)
+                    .north(10.0),
+            )
+            .add_layer(MapboxLayer::new().type_(MapboxLayerType::Fill))
+            .domain(Domain::new().x(&[0.0, 0.5]))
+            .ui_revision("rev");
+        let expected = json!({
+            "accesstoken": "token",
+            "bearing": 90.0,
+            "center": {"lat": 45.5, "lon": -73.5},
+            "pitch": 30.0,
+            "style": "white-bg",
+            "zoom": 2.5,
+            "bounds": {"east": 10.0, "south": -10.0, "west": -10.0, "north": 10.0},
+            "layers": [{"type": "fill"}],
+            "domain": {"x": [0.0, 0.5]},
+            "uirevision": "rev",
+        });
+
+        assert_eq!(to_value(mapbox).unwrap(), expected);
+        assert_eq!(to_value(Mapbox::new()).unwrap(), json!({}));
     }
 
     #[test]
@@ -3389,6 +4286,277 @@ mod tests {
         assert_eq!(to_value(aspect_mode).unwrap(), json!("cube"));
     }
 
+    #[test]
+    fn test_serialize_layout_axis_n() {
+        let layout = Layout::new()
+            .x_axis_n(1, Axis::new())
+            .y_axis_n(8, Axis::new())
+            .x_axis_n(9, Axis::new().title("x9".into()))
+            .y_axis_n(12, Axis::new());
+        let expected = json!({
+            "xaxis": {},
+            "yaxis8": {},
+            "xaxis9": {"title": {"text": "x9"}},
+            "yaxis12": {},
+        });
+
+        assert_eq!(to_value(layout).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_layout_mapbox_n() {
+        let layout = Layout::new()
+            .mapbox_n(1, Mapbox::new().zoom(1.0))
+            .mapbox_n(2, Mapbox::new().zoom(2.0))
+            .mapbox_n(10, Mapbox::new());
+        let expected = json!({
+            "mapbox": {"zoom": 1.0},
+            "mapbox2": {"zoom": 2.0},
+            "mapbox10": {},
+        });
+
+        assert_eq!(to_value(layout).unwrap(), expected);
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_serialize_map_style() {
+        assert_eq!(to_value(MapStyle::Basic).unwrap(), json!("basic"));
+        assert_eq!(to_value(MapStyle::CartoDarkMatter).unwrap(), json!("carto-darkmatter"));
+        assert_eq!(to_value(MapStyle::CartoDarkMatterNoLabels).unwrap(), json!("carto-darkmatter-nolabels"));
+        assert_eq!(to_value(MapStyle::CartoPositron).unwrap(), json!("carto-positron"));
+        assert_eq!(to_value(MapStyle::CartoPositronNoLabels).unwrap(), json!("carto-positron-nolabels"));
+        assert_eq!(to_value(MapStyle::CartoVoyager).unwrap(), json!("carto-voyager"));
+        assert_eq!(to_value(MapStyle::CartoVoyagerNoLabels).unwrap(), json!("carto-voyager-nolabels"));
+        assert_eq!(to_value(MapStyle::Dark).unwrap(), json!("dark"));
+        assert_eq!(to_value(MapStyle::Light).unwrap(), json!("light"));
+        assert_eq!(to_value(MapStyle::OpenStreetMap).unwrap(), json!("open-street-map"));
+        assert_eq!(to_value(MapStyle::Outdoors).unwrap(), json!("outdoors"));
+        assert_eq!(to_value(MapStyle::Satellite).unwrap(), json!("satellite"));
+        assert_eq!(to_value(MapStyle::SatelliteStreets).unwrap(), json!("satellite-streets"));
+        assert_eq!(to_value(MapStyle::Streets).unwrap(), json!("streets"));
+        assert_eq!(to_value(MapStyle::WhiteBg).unwrap(), json!("white-bg"));
+        assert_eq!(to_value(MapStyle::from("http://localhost/style.json")).unwrap(), json!("http://localhost/style.json"));
+        assert_eq!(to_value(MapStyle::from(json!({"version": 8}))).unwrap(), json!({"version": 8}));
+    }
+
+    #[test]
+    fn test_serialize_layout_map() {
+        let map = LayoutMap::new()
+            .bearing(10.0)
+            .center(Center::new(45.5, -73.5))
+            .pitch(20.0)
+            .style(MapStyle::CartoVoyager)
+            .zoom(3.5)
+            .bounds(MapboxBounds::new().west(-80.0).east(-70.0))
+            .add_layer(MapboxLayer::new().source("http://localhost/data.geojson"))
+            .domain(Domain::new().row(0).column(1))
+            .ui_revision(1);
+        let expected = json!({
+            "bearing": 10.0,
+            "center": {"lat": 45.5, "lon": -73.5},
+            "pitch": 20.0,
+            "style": "carto-voyager",
+            "zoom": 3.5,
+            "bounds": {"west": -80.0, "east": -70.0},
+            "layers": [{"source": "http://localhost/data.geojson"}],
+            "domain": {"row": 0, "column": 1},
+            "uirevision": 1,
+        });
+
+        assert_eq!(to_value(map).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_layout_map_n() {
+        let layout = Layout::new()
+            .map_n(0, LayoutMap::new())
+            .map_n(3, LayoutMap::new().zoom(1.0));
+        let expected = json!({"map": {}, "map3": {"zoom": 1.0}});
+
+        assert_eq!(to_value(layout).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_axis_layer() {
+        assert_eq!(
+            to_value(AxisLayer::AboveTraces).unwrap(),
+            json!("above traces")
+        );
+        assert_eq!(
+            to_value(AxisLayer::BelowTraces).unwrap(),
+            json!("below traces")
+        );
+    }
+
+    #[test]
+    fn test_serialize_real_axis_ticks() {
+        assert_eq!(to_value(RealAxisTicks::Top).unwrap(), json!("top"));
+        assert_eq!(to_value(RealAxisTicks::Bottom).unwrap(), json!("bottom"));
+        assert_eq!(to_value(RealAxisTicks::None).unwrap(), json!(""));
+    }
+
+    #[test]
+    fn test_serialize_real_axis() {
+        let axis = RealAxis::new()
+            .visible(true)
+            .color("#111111")
+            .show_line(true)
+            .line_color("#222222")
+            .line_width(2)
+            .show_grid(true)
+            .grid_color("#333333")
+            .grid_dash(DashType::Dash)
+            .grid_width(1)
+            .layer(AxisLayer::BelowTraces)
+            .side(AxisSide::Top)
+            .tick_values(vec![0.2, 0.5, 1.0, 2.0, 5.0])
+            .ticks(RealAxisTicks::Bottom)
+            .tick_length(5)
+            .tick_width(1)
+            .tick_color("#444444")
+            .show_tick_labels(true)
+            .tick_font(Font::new())
+            .tick_angle(45.0)
+            .tick_prefix("prefix")
+            .show_tick_prefix(ArrayShow::First)
+            .tick_suffix("Ω")
+            .show_tick_suffix(ArrayShow::All)
+            .tick_format(".1f")
+            .hover_format(".2f");
+        let expected = json!({
+            "visible": true,
+            "color": "#111111",
+            "showline": true,
+            "linecolor": "#222222",
+            "linewidth": 2,
+            "showgrid": true,
+            "gridcolor": "#333333",
+            "griddash": "dash",
+            "gridwidth": 1,
+            "layer": "below traces",
+            "side": "top",
+            "tickvals": [0.2, 0.5, 1.0, 2.0, 5.0],
+            "ticks": "bottom",
+            "ticklen": 5,
+            "tickwidth": 1,
+            "tickcolor": "#444444",
+            "showticklabels": true,
+            "tickfont": {},
+            "tickangle": 45.0,
+            "tickprefix": "prefix",
+            "showtickprefix": "first",
+            "ticksuffix": "Ω",
+            "showticksuffix": "all",
+            "tickformat": ".1f",
+            "hoverformat": ".2f",
+        });
+
+        assert_eq!(to_value(axis).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_imaginary_axis() {
+        let axis = ImaginaryAxis::new()
+            .visible(false)
+            .color("#111111")
+            .show_line(false)
+            .line_color("#222222")
+            .line_width(2)
+            .show_grid(false)
+            .grid_color("#333333")
+            .grid_dash(DashType::Dot)
+            .grid_width(1)
+            .layer(AxisLayer::AboveTraces)
+            .tick_values(vec![0.5, 1.0])
+            .ticks(TicksDirection::Outside)
+            .tick_length(5)
+            .tick_width(1)
+            .tick_color("#444444")
+            .show_tick_labels(false)
+            .tick_font(Font::new())
+            .tick_prefix("j")
+            .show_tick_prefix(ArrayShow::Last)
+            .tick_suffix("suffix")
+            .show_tick_suffix(ArrayShow::None)
+            .tick_format(".1f")
+            .hover_format(".2f");
+        let expected = json!({
+            "visible": false,
+            "color": "#111111",
+            "showline": false,
+            "linecolor": "#222222",
+            "linewidth": 2,
+            "showgrid": false,
+            "gridcolor": "#333333",
+            "griddash": "dot",
+            "gridwidth": 1,
+            "layer": "above traces",
+            "tickvals": [0.5, 1.0],
+            "ticks": "outside",
+            "ticklen": 5,
+            "tickwidth": 1,
+            "tickcolor": "#444444",
+            "showticklabels": false,
+            "tickfont": {},
+            "tickprefix": "j",
+            "showtickprefix": "last",
+            "ticksuffix": "suffix",
+            "showticksuffix": "none",
+            "tickformat": ".1f",
+            "hoverformat": ".2f",
+        });
+
+        assert_eq!(to_value(axis).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_layout_smith() {
+        let layout = Layout::new().smith(
+            LayoutSmith::new()
+                .background_color("#ffffff")
+                .domain(Domain::new().x(&[0.0, 0.5]))
+                .real_axis(RealAxis::new())
+                .imaginary_axis(ImaginaryAxis::new()),
+        );
+        let expected = json!({
+            "smith": {
+                "bgcolor": "#ffffff",
+                "domain": {"x": [0.0, 0.5]},
+                "realaxis": {},
+                "imaginaryaxis": {},
+            }
+        });
+
+        assert_eq!(to_value(layout).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_layout_smith_n() {
+        let layout = Layout::new()
+            .smith_n(1, LayoutSmith::new())
+            .smith_n(2, LayoutSmith::new().domain(Domain::new().x(&[0.5, 1.0])));
+        let expected = json!({"smith": {}, "smith2": {"domain": {"x": [0.5, 1.0]}}});
+
+        assert_eq!(to_value(layout).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_layout_extra() {
+        let layout = Layout::new()
+            .title("title".into())
+            .x_axis(Axis::new().extra("ticklabelstandoff", 5))
+            .extra("hoversubplots", "axis");
+
+        let expected = json!({
+            "title": {"text": "title"},
+            "xaxis": {"ticklabelstandoff": 5},
+            "hoversubplots": "axis"
+        });
+
+        assert_eq!(to_value(layout).unwrap(), expected);
+    }
+
     #[test]
     fn test_serialize_up() {
         let up = Up::new();
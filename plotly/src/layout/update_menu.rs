@@ -75,6 +75,9 @@ pub struct Button {
     template_item_name: Option<String>,
     /// Determines whether or not this button is visible.
     visible: Option<bool>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl Button {
@@ -233,6 +236,9 @@ pub struct UpdateMenu {
     /// selector. Default: "top"
     #[serde(rename = "yanchor")]
     y_anchor: Option<Anchor>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl UpdateMenu {
@@ -0,0 +1,89 @@
+//! A [Leptos](https://leptos.dev) component for drawing plots, enabled by the
+//! `leptos` feature.
+//!
+//! ```no_run
+//! use leptos::prelude::*;
+//! use plotly::{leptos::PlotlyChart, Plot, Scatter};
+//!
+//! #[component]
+//! fn App() -> impl IntoView {
+//!     let (y, set_y) = signal(vec![2, 1, 0]);
+//!     let plot = move || {
+//!         let mut plot = Plot::new();
+//!         plot.add_trace(Scatter::new(vec![0, 1, 2], y.get()));
+//!         plot
+//!     };
+//!     let on_click = Callback::new(move |_: plotly::bindings::events::PointsEvent| {
+//!         set_y.update(|y| y.reverse());
+//!     });
+//!
+//!     view! { <PlotlyChart plot=plot on_click=on_click /> }
+//! }
+//! ```
+
+use leptos::{html::Div, prelude::*};
+use web_sys::HtmlElement;
+
+use crate::{
+    bindings::{
+        chart::{Chart, EventHandlers},
+        events::{LegendClickEvent, PointsEvent, RelayoutEvent, RestyleEvent, SelectionEvent},
+    },
+    Plot,
+};
+
+fn handler<T: 'static, R: 'static>(
+    callback: Option<Callback<T, R>>,
+) -> Option<Box<dyn FnMut(T) -> R>> {
+    callback.map(|callback| Box::new(move |event| callback.run(event)) as Box<_>)
+}
+
+/// A `div` with a chart of the `plot` property. `plot` is a closure, so the
+/// chart is redrawn with the plotly.js `react` function whenever a signal it
+/// reads changes. The chart follows the size of the `div` and is purged when
+/// the component is unmounted.
+///
+/// An `on_legend_click` callback returning `false` cancels toggling the
+/// visibility of the trace.
+#[component]
+pub fn PlotlyChart(
+    plot: impl Fn() -> Plot + 'static,
+    #[prop(optional, into)] id: Option<String>,
+    #[prop(optional, into)] class: Option<String>,
+    #[prop(optional)] on_click: Option<Callback<PointsEvent>>,
+    #[prop(optional)] on_hover: Option<Callback<PointsEvent>>,
+    #[prop(optional)] on_unhover: Option<Callback<PointsEvent>>,
+    #[prop(optional)] on_selected: Option<Callback<Option<SelectionEvent>>>,
+    #[prop(optional)] on_relayout: Option<Callback<RelayoutEvent>>,
+    #[prop(optional)] on_restyle: Option<Callback<RestyleEvent>>,
+    #[prop(optional)] on_legend_click: Option<Callback<LegendClickEvent, bool>>,
+    #[prop(optional)] on_double_click: Option<Callback<()>>,
+) -> impl IntoView {
+    let node = NodeRef::<Div>::new();
+    let chart = StoredValue::new_local(None::<Chart>);
+
+    Effect::new(move |_| {
+        let plot = plot();
+        let Some(element) = node.get() else {
+            return;
+        };
+        chart.update_value(|chart| {
+            let chart = chart.get_or_insert_with(|| Chart::new(HtmlElement::from(element)));
+            let handlers = EventHandlers {
+                on_click: handler(on_click),
+                on_hover: handler(on_hover),
+                on_unhover: handler(on_unhover),
+                on_selected: handler(on_selected),
+                on_relayout: handler(on_relayout),
+                on_restyle: handler(on_restyle),
+                on_legend_click: handler(on_legend_click),
+                on_double_click: on_double_click
+                    .map(|callback| Box::new(move || callback.run(())) as Box<_>),
+            };
+            chart.render(plot, handlers);
+        });
+    });
+    on_cleanup(move || chart.update_value(|chart| drop(chart.take())));
+
+    view! { <div node_ref=node id=id class=class></div> }
+}
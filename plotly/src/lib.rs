@@ -16,25 +16,49 @@ pub mod ndarray;
 #[cfg(feature = "plotly_ndarray")]
 pub use crate::ndarray::ArrayTraces;
 
+#[cfg(feature = "actix")]
+pub mod actix;
+#[cfg(feature = "axum")]
+pub mod axum;
 #[cfg(feature = "wasm")]
 pub mod bindings;
+#[cfg(feature = "dioxus")]
+pub mod dioxus;
+#[cfg(feature = "leptos")]
+pub mod leptos;
+#[cfg(feature = "yew")]
+pub mod yew;
 
 pub mod common;
 pub mod configuration;
+pub mod events;
 pub mod layout;
 pub mod plot;
+#[cfg(any(feature = "axum", feature = "actix"))]
+pub mod response;
+#[cfg(feature = "server")]
+pub mod server;
 pub mod traces;
 
 pub use common::color;
 pub use configuration::Configuration;
 pub use layout::Layout;
-pub use plot::{ImageFormat, Plot, Trace};
+pub use plot::{
+    ImageFormat, MathJaxSource, Plot, PlotlyJsSource, Trace, MATHJAX_VERSION, PLOTLY_JS_VERSION,
+};
 // Also provide easy access to modules which contain additional trace-specific types
-pub use traces::{box_plot, contour, histogram, image, mesh3d, sankey, scatter_mapbox, surface};
+pub use traces::{
+    box_plot, carpet, choropleth_mapbox, cone, contour, heat_map, histogram, histogram2d, image,
+    indicator, mesh3d, parcats, parcoords, sankey, scatter_gl, scatter_mapbox, splom, streamtube,
+    surface, table, volume,
+};
 // Bring the different trace types into the top-level scope
 pub use traces::{
-    Bar, BoxPlot, Candlestick, Contour, HeatMap, Histogram, Image, Mesh3D, Ohlc, Sankey, Scatter,
-    Scatter3D, ScatterMapbox, ScatterPolar, Surface,
+    Bar, BarPolar, BoxPlot, Candlestick, Carpet, ChoroplethMap, ChoroplethMapbox, Cone, Contour,
+    ContourCarpet, DensityMap, DensityMapbox, HeatMap, Histogram, Histogram2d, Histogram2dContour,
+    Image, Indicator, Isosurface, Mesh3D, Ohlc, Parcats, Parcoords, RawTrace, Sankey, Scatter,
+    Scatter3D, ScatterCarpet, ScatterGL, ScatterMap, ScatterMapbox, ScatterPolar, ScatterSmith,
+    Splom, Streamtube, Surface, Table, Volume,
 };
 
 pub trait Restyle: serde::Serialize {}
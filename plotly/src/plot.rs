@@ -14,16 +14,23 @@ use crate::{Configuration, Layout};
 #[derive(Template)]
 #[template(path = "plot.html", escape = "none")]
 struct PlotTemplate<'a> {
-    plot: &'a Plot,
-    remote_plotly_js: bool,
+    plot: &'a serde_json::Value,
+    plotly_js_src: Option<&'a str>,
+    inline_plotly_js: bool,
+    mathjax_src: Option<&'a str>,
+    mathjax_script: Option<&'a str>,
 }
 
+#[cfg(not(target_family = "wasm"))]
 #[derive(Template)]
 #[template(path = "static_plot.html", escape = "none")]
 struct StaticPlotTemplate<'a> {
-    plot: &'a Plot,
+    plot: &'a serde_json::Value,
     format: ImageFormat,
-    remote_plotly_js: bool,
+    plotly_js_src: Option<&'a str>,
+    inline_plotly_js: bool,
+    mathjax_src: Option<&'a str>,
+    mathjax_script: Option<&'a str>,
     width: usize,
     height: usize,
 }
@@ -40,6 +47,115 @@ struct InlinePlotTemplate<'a> {
 struct JupyterNotebookPlotTemplate<'a> {
     plot: &'a Plot,
     plot_div_id: &'a str,
+    plotly_js_url: &'a str,
+}
+
+/// The version of plotly.js loaded from the CDN unless overridden with
+/// `Plot::set_plotly_js_version`. This is also the version of the bundled
+/// `plotly.min.js` used by `Plot::use_local_plotly`.
+pub const PLOTLY_JS_VERSION: &str = "2.12.1";
+
+/// The bundled plotly.js library, version `PLOTLY_JS_VERSION`.
+pub(crate) const PLOTLY_JS: &str = include_str!("../templates/plotly.min.js");
+
+/// The version of MathJax loaded from the CDN by default.
+pub const MATHJAX_VERSION: &str = "3.2.2";
+
+/// Where the HTML output of a `Plot` loads MathJax from, for typesetting LaTeX
+/// in text wrapped in `$`, such as `"$\alpha^2$"`. Set with
+/// `Plot::set_mathjax_source`.
+///
+/// MathJax is only included in the output when a string of the plot, e.g. a
+/// title, an annotation or a tick text, contains LaTeX, and the
+/// `Configuration::typeset_math` option has not been set to `false`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MathJaxSource {
+    /// Never include MathJax, so the HTML output makes no requests for it.
+    Off,
+    /// Load the given version of MathJax from the jsDelivr CDN, e.g.
+    /// `"3.2.2"`. This is the default, with version `MATHJAX_VERSION`.
+    Cdn(String),
+    /// Load MathJax from the given URL, e.g. an internal mirror or a path
+    /// relative to the HTML file.
+    Url(String),
+    /// Embed the given MathJax script in the HTML, e.g. the contents of
+    /// `tex-svg.js`, so that LaTeX is typeset without network access.
+    Inline(String),
+}
+
+impl Default for MathJaxSource {
+    fn default() -> Self {
+        MathJaxSource::Cdn(MATHJAX_VERSION.to_string())
+    }
+}
+
+impl MathJaxSource {
+    /// The URL of the `script` element loading MathJax, if any. Plotly.js
+    /// renders LaTeX with the SVG output of MathJax 3.
+    fn script_src(&self) -> Option<String> {
+        match self {
+            MathJaxSource::Cdn(version) => Some(format!(
+                "https://cdn.jsdelivr.net/npm/mathjax@{}/es5/tex-svg.js",
+                version
+            )),
+            MathJaxSource::Url(url) => Some(url.clone()),
+            MathJaxSource::Off | MathJaxSource::Inline(_) => None,
+        }
+    }
+}
+
+/// Whether a string contains LaTeX for plotly.js to typeset, i.e. two `$`
+/// delimiters.
+fn contains_latex(value: &serde_json::Value) -> bool {
+    match value {
+        serde_json::Value::String(s) => s
+            .find('$')
+            .is_some_and(|start| s[start + 1..].contains('$')),
+        serde_json::Value::Array(values) => values.iter().any(contains_latex),
+        serde_json::Value::Object(map) => map.values().any(contains_latex),
+        _ => false,
+    }
+}
+
+/// Where the HTML output of a `Plot` loads plotly.js from. Set with
+/// `Plot::set_plotly_js_source`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PlotlyJsSource {
+    /// Load the given version of plotly.js from the official CDN, e.g.
+    /// `"2.35.2"`. This is the default, with version `PLOTLY_JS_VERSION`.
+    Cdn(String),
+    /// Load plotly.js from the given URL, e.g. an internal mirror.
+    Url(String),
+    /// Load plotly.js from a path relative to the HTML file, so that many
+    /// plots share a single copy written with `Plot::write_plotly_js`.
+    RelativePath(String),
+    /// Embed the bundled plotly.js in the HTML. The plot then displays without
+    /// network access, at the cost of about 3 MB per file.
+    Inline,
+    /// Load nothing, as plotly.js is already loaded by the page the HTML is
+    /// included in.
+    AssumeLoaded,
+}
+
+impl Default for PlotlyJsSource {
+    fn default() -> Self {
+        PlotlyJsSource::Cdn(PLOTLY_JS_VERSION.to_string())
+    }
+}
+
+impl PlotlyJsSource {
+    fn cdn_url(version: &str) -> String {
+        format!("https://cdn.plot.ly/plotly-{}.min.js", version)
+    }
+
+    /// The URL of the `script` element loading plotly.js, if any.
+    fn script_src(&self) -> Option<String> {
+        match self {
+            PlotlyJsSource::Cdn(version) => Some(Self::cdn_url(version)),
+            PlotlyJsSource::Url(url) | PlotlyJsSource::RelativePath(url) => Some(url.clone()),
+            PlotlyJsSource::Inline | PlotlyJsSource::AssumeLoaded => None,
+        }
+    }
 }
 
 #[cfg(not(target_family = "wasm"))]
@@ -181,7 +297,9 @@ pub struct Plot {
     #[serde(rename = "config")]
     configuration: Configuration,
     #[serde(skip)]
-    remote_plotly_js: bool,
+    plotly_js_source: PlotlyJsSource,
+    #[serde(skip)]
+    mathjax_source: MathJaxSource,
 }
 
 impl Plot {
@@ -189,7 +307,6 @@ impl Plot {
     pub fn new() -> Plot {
         Plot {
             traces: Traces::new(),
-            remote_plotly_js: true,
             ..Default::default()
         }
     }
@@ -202,8 +319,55 @@ impl Plot {
     /// Note that when using `Plot::to_inline_html()`, it is assumed that the
     /// `plotly.js` library is already in scope, so setting this attribute
     /// will have no effect.
+    ///
+    /// This is the same as setting `PlotlyJsSource::Inline` with
+    /// `Plot::set_plotly_js_source()`.
     pub fn use_local_plotly(&mut self) {
-        self.remote_plotly_js = false;
+        self.plotly_js_source = PlotlyJsSource::Inline;
+    }
+
+    /// Set the version of plotly.js loaded from the CDN, e.g. `"2.35.2"`. By
+    /// default `PLOTLY_JS_VERSION` is used. Newer versions are required by
+    /// some traces, such as the MapLibre based `ScatterMap`, `ChoroplethMap`
+    /// and `DensityMap` which need plotly.js 2.35 or later.
+    ///
+    /// This is the same as setting `PlotlyJsSource::Cdn` with
+    /// `Plot::set_plotly_js_source()`, so it replaces `Plot::use_local_plotly()`
+    /// as the bundled library is always version `PLOTLY_JS_VERSION`.
+    pub fn set_plotly_js_version(&mut self, version: &str) {
+        self.plotly_js_source = PlotlyJsSource::Cdn(version.to_string());
+    }
+
+    /// Set where the HTML output of `Plot::to_html()`, `Plot::write_html()`
+    /// and `Plot::show()` loads plotly.js from.
+    ///
+    /// ```no_run
+    /// use plotly::{Plot, PlotlyJsSource, Scatter};
+    ///
+    /// // Write plotly.js once, and load it from each of the plots.
+    /// Plot::write_plotly_js("plots/plotly.min.js").unwrap();
+    /// for i in 0..10 {
+    ///     let mut plot = Plot::new();
+    ///     plot.add_trace(Scatter::new(vec![0, 1], vec![0, i]));
+    ///     plot.set_plotly_js_source(PlotlyJsSource::RelativePath("plotly.min.js".into()));
+    ///     plot.write_html(format!("plots/plot_{}.html", i));
+    /// }
+    /// ```
+    pub fn set_plotly_js_source(&mut self, source: PlotlyJsSource) {
+        self.plotly_js_source = source;
+    }
+
+    /// Write the bundled plotly.js, version `PLOTLY_JS_VERSION`, to the given
+    /// location, to be loaded with `PlotlyJsSource::RelativePath`.
+    pub fn write_plotly_js<P: AsRef<Path>>(filename: P) -> std::io::Result<()> {
+        std::fs::write(filename, PLOTLY_JS)
+    }
+
+    /// Set where the HTML output of `Plot::to_html()`, `Plot::write_html()`
+    /// and `Plot::show()` loads MathJax from, when the plot contains LaTeX.
+    /// Use `MathJaxSource::Off` to never load it.
+    pub fn set_mathjax_source(&mut self, source: MathJaxSource) {
+        self.mathjax_source = source;
     }
 
     /// Add a `Trace` to the `Plot`.
@@ -303,6 +467,17 @@ impl Plot {
         Plot::show_with_default_app(temp_path);
     }
 
+    /// Serve the `Plot` on a free port of the loopback interface, and return a
+    /// handle through which the page displaying it is updated live. See the
+    /// `server` module for details.
+    ///
+    /// The plotly.js source of the page is taken from this plot when serving
+    /// starts, so it must be set with `set_plotly_js_source` beforehand.
+    #[cfg(feature = "server")]
+    pub fn serve(&self) -> std::io::Result<crate::server::PlotHandle> {
+        crate::server::PlotHandle::bind("127.0.0.1:0", self)
+    }
+
     /// Save the rendered `Plot` to a file at the given location.
     ///
     /// This method will render the plot to a full, standalone HTML document,
@@ -352,6 +527,7 @@ impl Plot {
         let tmpl = JupyterNotebookPlotTemplate {
             plot: self,
             plot_div_id: &plot_div_id,
+            plotly_js_url: &self.jupyter_plotly_js_url(),
         };
         tmpl.render().unwrap()
     }
@@ -405,25 +581,78 @@ impl Plot {
     }
 
     fn render(&self) -> String {
+        let plotly_js_src = self.plotly_js_source.script_src();
+        let plot = serde_json::to_value(self).unwrap();
+        let (mathjax_src, mathjax_script) = self.mathjax(&plot);
         let tmpl = PlotTemplate {
-            plot: self,
-            remote_plotly_js: self.remote_plotly_js,
+            plot: &plot,
+            plotly_js_src: plotly_js_src.as_deref(),
+            inline_plotly_js: self.plotly_js_source == PlotlyJsSource::Inline,
+            mathjax_src: mathjax_src.as_deref(),
+            mathjax_script,
         };
         tmpl.render().unwrap()
     }
 
     #[cfg(not(target_family = "wasm"))]
     fn render_static(&self, format: ImageFormat, width: usize, height: usize) -> String {
+        let plotly_js_src = self.plotly_js_source.script_src();
+        let plot = serde_json::to_value(self).unwrap();
+        let (mathjax_src, mathjax_script) = self.mathjax(&plot);
         let tmpl = StaticPlotTemplate {
-            plot: self,
+            plot: &plot,
             format,
-            remote_plotly_js: self.remote_plotly_js,
+            plotly_js_src: plotly_js_src.as_deref(),
+            inline_plotly_js: self.plotly_js_source == PlotlyJsSource::Inline,
+            mathjax_src: mathjax_src.as_deref(),
+            mathjax_script,
             width,
             height,
         };
         tmpl.render().unwrap()
     }
 
+    /// The URL or the inline script of MathJax, if the plot contains LaTeX to
+    /// be typeset. `plot` is the serialized plot, which the templates render
+    /// as well, so that the plot is only serialized once.
+    fn mathjax(&self, plot: &serde_json::Value) -> (Option<String>, Option<&str>) {
+        if self.mathjax_source == MathJaxSource::Off {
+            return (None, None);
+        }
+        let typeset_math = plot["config"]["typesetMath"] != false;
+        if !typeset_math || !(contains_latex(&plot["data"]) || contains_latex(&plot["layout"])) {
+            return (None, None);
+        }
+        match &self.mathjax_source {
+            MathJaxSource::Inline(script) => (None, Some(script.as_str())),
+            source => (source.script_src(), None),
+        }
+    }
+
+    /// The URL of plotly.js for the live page of the `server` feature, if it
+    /// is not the bundled library served alongside the page. Relative paths
+    /// are ignored, as the server has no file next to the page.
+    #[cfg(feature = "server")]
+    pub(crate) fn remote_plotly_js_url(&self) -> Option<String> {
+        match &self.plotly_js_source {
+            PlotlyJsSource::Cdn(version) if version != PLOTLY_JS_VERSION => {
+                Some(PlotlyJsSource::cdn_url(version))
+            }
+            PlotlyJsSource::Url(url) => Some(url.clone()),
+            _ => None,
+        }
+    }
+
+    /// Notebooks load plotly.js with RequireJS, so it cannot be embedded or
+    /// loaded from a path relative to the notebook, and the CDN is used then.
+    fn jupyter_plotly_js_url(&self) -> String {
+        match &self.plotly_js_source {
+            PlotlyJsSource::Cdn(version) => PlotlyJsSource::cdn_url(version),
+            PlotlyJsSource::Url(url) => url.clone(),
+            _ => PlotlyJsSource::cdn_url(PLOTLY_JS_VERSION),
+        }
+    }
+
     fn render_inline(&self, plot_div_id: &str) -> String {
         let tmpl = InlinePlotTemplate {
             plot: self,
@@ -438,19 +667,21 @@ impl Plot {
 
     #[cfg(feature = "wasm")]
     /// Convert a `Plot` to a native Javasript `js_sys::Object`.
+    ///
+    /// The object is built directly rather than by parsing `to_json()`, and
+    /// long numeric trace data is passed as `Float64Array`/`Float32Array`
+    /// typed arrays.
     pub fn to_js_object(&self) -> js_sys::Object {
         use wasm_bindgen::JsCast;
-        // The only reason this could fail is if to_json() produces structurally
-        // incorrect JSON. That would be a bug, and would require fixing in the
-        // to_json()/serialization methods, rather than here
-        js_sys::JSON::parse(&self.to_json())
-            .expect("Invalid JSON")
-            .dyn_into::<js_sys::Object>()
-            .expect("Invalid JSON structure - expected a top-level Object")
+        // The only reason this could fail is a bug in the serialization of
+        // the plot, which would require fixing there rather than here.
+        crate::bindings::serializer::to_js_value(self)
+            .expect("Error converting the plot to a Javascript object")
+            .unchecked_into::<js_sys::Object>()
     }
 
     #[cfg(target_os = "linux")]
-    fn show_with_default_app(temp_path: &str) {
+    pub(crate) fn show_with_default_app(temp_path: &str) {
         use std::process::Command;
         Command::new("xdg-open")
             .args([temp_path])
@@ -459,7 +690,7 @@ impl Plot {
     }
 
     #[cfg(target_os = "macos")]
-    fn show_with_default_app(temp_path: &str) {
+    pub(crate) fn show_with_default_app(temp_path: &str) {
         use std::process::Command;
         Command::new("open")
             .args(&[temp_path])
@@ -468,7 +699,7 @@ impl Plot {
     }
 
     #[cfg(target_os = "windows")]
-    fn show_with_default_app(temp_path: &str) {
+    pub(crate) fn show_with_default_app(temp_path: &str) {
         use std::process::Command;
         Command::new("cmd")
             .args(&["/C", "start", &format!(r#"{}"#, temp_path)])
@@ -490,7 +721,7 @@ mod tests {
     use serde_json::{json, to_value};
 
     use super::*;
-    use crate::Scatter;
+    use crate::{common::Title, Scatter};
 
     fn create_test_plot() -> Plot {
         let trace1 = Scatter::new(vec![0, 1, 2], vec![6, 10, 2]).name("trace1");
@@ -513,6 +744,98 @@ mod tests {
         plot.to_jupyter_notebook_html();
     }
 
+    #[test]
+    fn test_plotly_js_version() {
+        let mut plot = create_test_plot();
+        let default_url = format!("https://cdn.plot.ly/plotly-{}.min.js", PLOTLY_JS_VERSION);
+        assert!(plot.to_html().contains(&default_url));
+        assert!(plot.to_jupyter_notebook_html().contains(&default_url));
+
+        plot.set_plotly_js_version("2.35.2");
+        assert!(plot
+            .to_html()
+            .contains("https://cdn.plot.ly/plotly-2.35.2.min.js"));
+        assert!(plot
+            .to_jupyter_notebook_html()
+            .contains("https://cdn.plot.ly/plotly-2.35.2.min.js"));
+    }
+
+    #[test]
+    fn test_plotly_js_source() {
+        let mut plot = create_test_plot();
+        let bundled = "<script type=\"text/javascript\">/**";
+
+        plot.set_plotly_js_source(PlotlyJsSource::Url(
+            "https://mirror.example.com/plotly.js".into(),
+        ));
+        let html = plot.to_html();
+        assert!(html.contains(r#"<script src="https://mirror.example.com/plotly.js"></script>"#));
+        assert!(!html.contains("cdn.plot.ly"));
+        assert!(plot
+            .to_jupyter_notebook_html()
+            .contains("https://mirror.example.com/plotly.js"));
+
+        plot.set_plotly_js_source(PlotlyJsSource::RelativePath("js/plotly.min.js".into()));
+        assert!(plot
+            .to_html()
+            .contains(r#"<script src="js/plotly.min.js"></script>"#));
+
+        plot.use_local_plotly();
+        let html = plot.to_html();
+        assert!(html.contains(bundled));
+        assert!(!html.contains("<script src=\"https://cdn.plot.ly"));
+
+        plot.set_plotly_js_source(PlotlyJsSource::AssumeLoaded);
+        let html = plot.to_html();
+        assert!(!html.contains(bundled));
+        assert!(!html.contains("cdn.plot.ly"));
+        assert!(html.contains("Plotly.newPlot"));
+    }
+
+    #[test]
+    fn test_mathjax_source() {
+        let cdn_url = format!(
+            "https://cdn.jsdelivr.net/npm/mathjax@{}/es5/tex-svg.js",
+            MATHJAX_VERSION
+        );
+        let mut plot = create_test_plot();
+        assert!(!plot.to_html().contains("mathjax"));
+
+        plot.set_layout(Layout::new().title(Title::new(r"$\alpha^2$")));
+        assert!(plot.to_html().contains(&cdn_url));
+        #[cfg(not(target_family = "wasm"))]
+        assert!(plot
+            .render_static(ImageFormat::SVG, 800, 600)
+            .contains(&cdn_url));
+
+        plot.set_mathjax_source(MathJaxSource::Url("js/tex-svg.js".into()));
+        assert!(plot
+            .to_html()
+            .contains(r#"<script src="js/tex-svg.js"></script>"#));
+
+        plot.set_mathjax_source(MathJaxSource::Inline("window.MathJax = {};".into()));
+        assert!(plot
+            .to_html()
+            .contains(r#"<script type="text/javascript">window.MathJax = {};</script>"#));
+
+        plot.set_mathjax_source(MathJaxSource::Off);
+        assert!(!plot.to_html().contains("MathJax"));
+
+        plot.set_mathjax_source(MathJaxSource::default());
+        plot.set_configuration(Configuration::new().typeset_math(false));
+        assert!(!plot.to_html().contains("mathjax"));
+    }
+
+    #[test]
+    fn test_contains_latex() {
+        assert!(contains_latex(&json!({"text": "$x^2$"})));
+        assert!(contains_latex(
+            &json!({"ticktext": ["a", "costs $5 or $10"]})
+        ));
+        assert!(!contains_latex(&json!({"text": "costs $5"})));
+        assert!(!contains_latex(&json!({"x": [1, 2], "name": "trace"})));
+    }
+
     #[test]
     fn test_notebook_display() {
         let plot = create_test_plot();
@@ -11,6 +11,10 @@ pub fn owned_string_vector<S: AsRef<str>>(s: Vec<S>) -> Vec<String> {
         .collect::<Vec<String>>()
 }
 
+/// Attributes set through the generated `extra` setters, which are flattened
+/// into the serialized object.
+pub type Extra = std::collections::BTreeMap<String, serde_json::Value>;
+
 #[derive(Serialize, Clone, Debug, PartialEq)]
 #[serde(untagged)]
 pub enum NumOrString {
@@ -125,6 +129,21 @@ where
     traces
 }
 
+/// Merge `Restyle*` or `Relayout*` values, each of which serializes to an
+/// object with a single attribute, into one update object.
+#[cfg(any(feature = "wasm", feature = "server"))]
+pub(crate) fn merge_updates<T: Serialize>(
+    updates: &[T],
+) -> serde_json::Map<String, serde_json::Value> {
+    let mut merged = serde_json::Map::new();
+    for update in updates {
+        if let serde_json::Value::Object(attributes) = serde_json::to_value(update).unwrap() {
+            merged.extend(attributes);
+        }
+    }
+    merged
+}
+
 #[cfg(test)]
 mod tests {
     use serde_json::{json, to_value};
@@ -203,4 +222,36 @@ mod tests {
         assert_eq!(to_value(NumOrStringCollection(vec![NumOrString::I(-50)])).unwrap(), json!([-50]));
         assert_eq!(to_value(NumOrStringCollection(vec![NumOrString::U(50)])).unwrap(), json!([50]));
     }
+
+    #[test]
+    #[cfg(any(feature = "wasm", feature = "server"))]
+    fn test_merge_updates() {
+        use crate::{
+            common::Dim,
+            layout::{Axis, RelayoutLayout},
+            traces::RestyleScatter,
+        };
+
+        let restyles: [RestyleScatter<f64, f64>; 2] = [
+            RestyleScatter::ModifyOpacity {
+                opacity: Some(Dim::Scalar(0.5)),
+            },
+            RestyleScatter::ModifyName {
+                name: Some(Dim::Vector(vec!["a".to_string(), "b".to_string()])),
+            },
+        ];
+        let expected = json!({"opacity": 0.5, "name": ["a", "b"]});
+        assert_eq!(to_value(merge_updates(&restyles)).unwrap(), expected);
+
+        let relayouts = [
+            RelayoutLayout::ModifyXAxis {
+                x_axis: Some(Box::new(Axis::new().visible(false))),
+            },
+            RelayoutLayout::ModifyShowLegend {
+                show_legend: Some(true),
+            },
+        ];
+        let expected = json!({"xaxis": {"visible": false}, "showlegend": true});
+        assert_eq!(to_value(merge_updates(&relayouts)).unwrap(), expected);
+    }
 }
@@ -0,0 +1,330 @@
+//! Representations of a `Plot` in HTTP responses, shared by the `axum` and
+//! `actix` features.
+
+use std::{error::Error, fmt};
+
+use crate::Plot;
+
+/// The width of images returned for `image/png` and `image/svg+xml` requests.
+#[cfg(feature = "kaleido")]
+const IMAGE_WIDTH: usize = 800;
+/// The height of images returned for `image/png` and `image/svg+xml` requests.
+#[cfg(feature = "kaleido")]
+const IMAGE_HEIGHT: usize = 600;
+
+pub(crate) const PLOTLY_JS_CONTENT_TYPE: &str = "text/javascript; charset=utf-8";
+/// The bundled plotly.js only changes with the version of this crate, so it
+/// can be cached for a day.
+pub(crate) const PLOTLY_JS_CACHE_CONTROL: &str = "public, max-age=86400";
+
+/// The representation of a `Plot` in an HTTP response.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PlotFormat {
+    /// A standalone HTML document, as returned by `Plot::to_html`.
+    Html,
+    /// An HTML fragment to embed in a page which loads plotly.js, as returned
+    /// by `Plot::to_inline_html`. Requested with `text/html; fragment=true`.
+    InlineHtml,
+    /// The JSON of the plot, as returned by `Plot::to_json`.
+    Json,
+    #[cfg(feature = "kaleido")]
+    Png,
+    #[cfg(feature = "kaleido")]
+    Svg,
+}
+
+impl PlotFormat {
+    /// Choose the format preferred by the value of an `Accept` header. The
+    /// `Html` format is used if the header is missing or none of the accepted
+    /// media types is supported.
+    pub fn from_accept(accept: Option<&str>) -> Self {
+        let mut best: Option<(f32, bool, PlotFormat)> = None;
+        for media_range in accept.unwrap_or_default().split(',') {
+            let mut parts = media_range.split(';').map(str::trim);
+            let media_type = parts.next().unwrap_or_default().to_ascii_lowercase();
+            let mut quality = 1.0;
+            let mut fragment = false;
+            for parameter in parts {
+                if let Some((name, value)) = parameter.split_once('=') {
+                    match name.trim().to_ascii_lowercase().as_str() {
+                        "q" => quality = value.trim().parse().unwrap_or(0.0),
+                        "fragment" => fragment = matches!(value.trim(), "true" | "1"),
+                        _ => {}
+                    }
+                }
+            }
+
+            let format = match media_type.as_str() {
+                "text/html" if fragment => PlotFormat::InlineHtml,
+                "text/html" | "text/*" | "*/*" => PlotFormat::Html,
+                "application/vnd.plotly.v1+json" | "application/json" => PlotFormat::Json,
+                #[cfg(feature = "kaleido")]
+                "image/png" | "image/*" => PlotFormat::Png,
+                #[cfg(feature = "kaleido")]
+                "image/svg+xml" => PlotFormat::Svg,
+                _ => continue,
+            };
+            // Between media ranges of the same quality, the first one which
+            // names a media type wins over wildcards.
+            let specific = !media_type.ends_with("/*");
+            let better = match best {
+                Some((best_quality, best_specific, _)) => {
+                    quality > best_quality
+                        || (quality == best_quality && specific && !best_specific)
+                }
+                None => true,
+            };
+            if quality > 0.0 && better {
+                best = Some((quality, specific, format));
+            }
+        }
+        best.map_or(PlotFormat::Html, |(_, _, format)| format)
+    }
+
+    /// The value of the `Content-Type` header of the response.
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            PlotFormat::Html | PlotFormat::InlineHtml => "text/html; charset=utf-8",
+            PlotFormat::Json => "application/vnd.plotly.v1+json",
+            #[cfg(feature = "kaleido")]
+            PlotFormat::Png => "image/png",
+            #[cfg(feature = "kaleido")]
+            PlotFormat::Svg => "image/svg+xml",
+        }
+    }
+
+    /// Render `plot` in this format. Images are rendered with Kaleido, which
+    /// blocks the current thread, so handlers returning images should render
+    /// them with e.g. `tokio::task::spawn_blocking` or `actix_web::web::block`
+    /// and return the `RenderedPlot`.
+    pub fn render(&self, plot: &Plot) -> Result<RenderedPlot, RenderError> {
+        self.render_body(plot)
+            .map(|body| RenderedPlot {
+                format: *self,
+                body,
+                vary_accept: false,
+            })
+            .map_err(|e| RenderError(e.to_string()))
+    }
+
+    fn render_body(&self, plot: &Plot) -> Result<Vec<u8>, Box<dyn Error>> {
+        let body = match self {
+            PlotFormat::Html => plot.to_html().into_bytes(),
+            PlotFormat::InlineHtml => plot.to_inline_html(None).into_bytes(),
+            PlotFormat::Json => plot.to_json().into_bytes(),
+            #[cfg(feature = "kaleido")]
+            PlotFormat::Png | PlotFormat::Svg => {
+                let format = if *self == PlotFormat::Png {
+                    "png"
+                } else {
+                    "svg"
+                };
+                plotly_kaleido::Kaleido::try_new()?.image_data(
+                    &serde_json::to_value(plot)?,
+                    format,
+                    IMAGE_WIDTH,
+                    IMAGE_HEIGHT,
+                    1.0,
+                )?
+            }
+        };
+        Ok(body)
+    }
+}
+
+/// The `PlotFormat` preferred by the `Accept` header of a request, which
+/// handlers get by taking it as an argument. Responses in this format are
+/// sent with `Vary: accept`, as they depend on the header.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AcceptedFormat(PlotFormat);
+
+impl AcceptedFormat {
+    /// Choose the format preferred by the value of an `Accept` header, see
+    /// `PlotFormat::from_accept`.
+    pub fn from_accept(accept: Option<&str>) -> Self {
+        Self(PlotFormat::from_accept(accept))
+    }
+
+    pub fn format(&self) -> PlotFormat {
+        self.0
+    }
+
+    /// Render `plot` in this format, see `PlotFormat::render`.
+    pub fn render(&self, plot: &Plot) -> Result<RenderedPlot, RenderError> {
+        self.0.render(plot).map(|rendered| RenderedPlot {
+            vary_accept: true,
+            ..rendered
+        })
+    }
+}
+
+/// A `Plot` returned in a given format.
+///
+/// The plot is rendered when the response is built, on the async worker of
+/// the server, which is only suitable for formats other than images. See
+/// `PlotFormat::render`.
+pub struct PlotResponse {
+    plot: Plot,
+    format: PlotFormat,
+    vary_accept: bool,
+}
+
+impl PlotResponse {
+    /// A response in a format chosen by the handler.
+    pub fn new(plot: Plot, format: PlotFormat) -> Self {
+        Self {
+            plot,
+            format,
+            vary_accept: false,
+        }
+    }
+
+    /// A response in the format preferred by the `Accept` header.
+    pub fn accepted(plot: Plot, format: AcceptedFormat) -> Self {
+        Self {
+            plot,
+            format: format.format(),
+            vary_accept: true,
+        }
+    }
+
+    /// Render the plot, see `PlotFormat::render`.
+    pub fn render(&self) -> Result<RenderedPlot, RenderError> {
+        self.format.render(&self.plot).map(|rendered| RenderedPlot {
+            vary_accept: self.vary_accept,
+            ..rendered
+        })
+    }
+}
+
+/// A plot rendered by `PlotFormat::render` or `AcceptedFormat::render`, which
+/// is returned from handlers like a `PlotResponse`. Unlike a `Plot`, it can be
+/// sent between threads.
+#[derive(Clone, Debug)]
+pub struct RenderedPlot {
+    format: PlotFormat,
+    body: Vec<u8>,
+    vary_accept: bool,
+}
+
+impl RenderedPlot {
+    pub fn format(&self) -> PlotFormat {
+        self.format
+    }
+
+    /// The body of the response.
+    pub fn body(&self) -> &[u8] {
+        &self.body
+    }
+
+    /// Whether the format was negotiated from the `Accept` header, so that the
+    /// response must be sent with `Vary: accept`.
+    pub(crate) fn vary_accept(&self) -> bool {
+        self.vary_accept
+    }
+
+    pub(crate) fn into_body(self) -> Vec<u8> {
+        self.body
+    }
+}
+
+/// An error rendering a plot, such as a failure of Kaleido to export an image.
+/// Returned from a handler it results in `500 Internal Server Error`.
+#[derive(Clone, Debug)]
+pub struct RenderError(String);
+
+impl fmt::Display for RenderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to render the plot: {}", self.0)
+    }
+}
+
+impl Error for RenderError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Scatter;
+
+    #[test]
+    fn test_format_from_accept() {
+        assert_eq!(PlotFormat::from_accept(None), PlotFormat::Html);
+        assert_eq!(
+            PlotFormat::from_accept(Some(
+                "text/html,application/xhtml+xml,application/xml;q=0.9,*/*;q=0.8"
+            )),
+            PlotFormat::Html
+        );
+        assert_eq!(
+            PlotFormat::from_accept(Some("text/html; fragment=true")),
+            PlotFormat::InlineHtml
+        );
+        assert_eq!(
+            PlotFormat::from_accept(Some("application/vnd.plotly.v1+json")),
+            PlotFormat::Json
+        );
+        assert_eq!(
+            PlotFormat::from_accept(Some("text/html;q=0.5, application/json")),
+            PlotFormat::Json
+        );
+        assert_eq!(
+            PlotFormat::from_accept(Some("*/*, application/json")),
+            PlotFormat::Json
+        );
+        assert_eq!(
+            PlotFormat::from_accept(Some("application/json;q=0, text/plain")),
+            PlotFormat::Html
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "kaleido")]
+    fn test_image_format_from_accept() {
+        assert_eq!(PlotFormat::from_accept(Some("image/png")), PlotFormat::Png);
+        assert_eq!(
+            PlotFormat::from_accept(Some("image/svg+xml, image/*;q=0.5")),
+            PlotFormat::Svg
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "kaleido")]
+    fn test_render_image() {
+        let mut plot = Plot::new();
+        plot.add_trace(Scatter::new(vec![1, 2], vec![3, 4]));
+
+        // A missing Kaleido binary is an error rather than a panic.
+        match PlotFormat::Png.render(&plot) {
+            Ok(png) => assert!(png.body().starts_with(b"\x89PNG")),
+            Err(e) => assert!(e.to_string().contains("kaleido")),
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let mut plot = Plot::new();
+        plot.add_trace(Scatter::new(vec![1, 2], vec![3, 4]));
+
+        let json = PlotFormat::Json.render(&plot).unwrap();
+        assert_eq!(json.format(), PlotFormat::Json);
+        assert_eq!(json.body(), plot.to_json().as_bytes());
+
+        let fragment = PlotFormat::InlineHtml.render(&plot).unwrap().into_body();
+        let fragment = String::from_utf8(fragment).unwrap();
+        assert!(fragment.starts_with("<div id="));
+        assert!(!fragment.contains("<html"));
+
+        let document = PlotResponse::new(plot.clone(), PlotFormat::Html)
+            .render()
+            .unwrap();
+        assert!(document.body().starts_with(b"<!doctype html>"));
+        assert!(!document.vary_accept());
+
+        let accepted = AcceptedFormat::from_accept(Some("application/json"));
+        assert_eq!(accepted.format(), PlotFormat::Json);
+        assert!(accepted.render(&plot).unwrap().vary_accept());
+        let response = PlotResponse::accepted(plot, accepted).render().unwrap();
+        assert_eq!(response.format(), PlotFormat::Json);
+        assert!(response.vary_accept());
+    }
+}
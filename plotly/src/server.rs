@@ -0,0 +1,968 @@
+//! A local HTTP server which displays a plot in the browser and pushes updates
+//! to it as they are made, e.g. while a long-running simulation produces new
+//! data. Enabled by the `server` feature.
+//!
+//! The page served at the root of the server loads the bundled plotly.js from
+//! the server itself, unless the plot was given another source when serving
+//! started, and receives updates over a [server-sent events](https://developer.mozilla.org/en-US/docs/Web/API/Server-sent_events)
+//! stream, so no outside network access is needed.
+//!
+//! Click, selection, relayout, update menu and slider events of the page are
+//! posted back to the server, where they are passed to the handlers registered
+//! with e.g. [`PlotHandle::on_click`]. A handler may return a [`PlotUpdate`],
+//! which is then applied to all pages displaying the plot. This makes small
+//! interactive applications possible without writing any Javascript.
+//!
+//! ```no_run
+//! use plotly::{Plot, Scatter};
+//! use serde_json::json;
+//!
+//! let mut plot = Plot::new();
+//! plot.add_trace(Scatter::new(vec![0], vec![0]));
+//!
+//! let handle = plot.serve().unwrap();
+//! handle.show();
+//! for i in 1..100 {
+//!     handle.extend_traces(&json!({"x": [[i]], "y": [[i * i]]}), &[0], None);
+//!     std::thread::sleep(std::time::Duration::from_millis(100));
+//! }
+//! ```
+//!
+//! ```no_run
+//! use plotly::{layout::RelayoutLayout, server::PlotUpdate, Plot, Scatter};
+//!
+//! let mut plot = Plot::new();
+//! plot.add_trace(Scatter::new(vec![0, 1, 2], vec![2, 1, 0]));
+//!
+//! let handle = plot.serve().unwrap();
+//! // Show the coordinates of the clicked point in the title.
+//! handle.on_click(|event| {
+//!     let point = event.points.first()?;
+//!     let title = format!("Clicked ({}, {})", point.x.clone()?, point.y.clone()?);
+//!     let relayout = RelayoutLayout::ModifyTitle {
+//!         title: Some(title.as_str().into()),
+//!     };
+//!     Some(PlotUpdate::relayout(&[relayout]))
+//! });
+//! handle.show();
+//! # std::thread::park();
+//! ```
+
+use std::{
+    collections::HashMap,
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{Shutdown, SocketAddr, TcpListener, TcpStream, ToSocketAddrs},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Sender},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+use askama::Template;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    events::{ButtonClickEvent, PointsEvent, RelayoutEvent, SelectionEvent, SliderChangeEvent},
+    plot::PLOTLY_JS,
+    private::merge_updates,
+    Plot, Relayout, Restyle,
+};
+
+/// How long a page may take to accept an update before it is disconnected.
+const WRITE_TIMEOUT: Duration = Duration::from_secs(5);
+/// The largest event data accepted from a page, which leaves room for large
+/// selections.
+const MAX_EVENT_SIZE: usize = 16 * 1024 * 1024;
+/// The most updates kept since the last `react` for replaying to pages which
+/// connect later on.
+const MAX_HISTORY: usize = 1000;
+
+#[derive(Template)]
+#[template(path = "live_plot.html", escape = "none")]
+struct LivePlotTemplate<'a> {
+    plotly_js_src: &'a str,
+}
+
+/// A change to a served plot, made through a [`PlotHandle`] or returned by an
+/// event handler. Each change corresponds to a call of the plotly.js function
+/// of the same name in the pages displaying the plot.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlotUpdate {
+    method: &'static str,
+    args: Vec<Value>,
+}
+
+impl PlotUpdate {
+    /// Redraw the plot with the plotly.js [react](https://plotly.com/javascript/plotlyjs-function-reference/#react)
+    /// function. The plotly.js source of `plot` is ignored, as the pages have
+    /// already loaded plotly.js, see [`PlotHandle::bind`].
+    pub fn react(plot: &Plot) -> Self {
+        Self::new("react", vec![json!(plot)])
+    }
+
+    /// Update the traces with the given indices, or all traces if `traces` is
+    /// `None`, with the plotly.js [restyle](https://plotly.com/javascript/plotlyjs-function-reference/#plotlyrestyle)
+    /// function. The `updates`, e.g. `RestyleScatter::ModifyOpacity` values,
+    /// are merged into a single update.
+    pub fn restyle<R: Restyle>(updates: &[R], traces: Option<&[usize]>) -> Self {
+        let mut args = vec![json!(merge_updates(updates))];
+        args.extend(traces.map(|traces| json!(traces)));
+        Self::new("restyle", args)
+    }
+
+    /// Update the layout with the plotly.js [relayout](https://plotly.com/javascript/plotlyjs-function-reference/#plotlyrelayout)
+    /// function. The `updates`, e.g. `RelayoutLayout::ModifyTitle` values, are
+    /// merged into a single update.
+    pub fn relayout<L: Relayout + Serialize>(updates: &[L]) -> Self {
+        Self::new("relayout", vec![json!(merge_updates(updates))])
+    }
+
+    /// Combine [`restyle`](Self::restyle) and [`relayout`](Self::relayout)
+    /// with the plotly.js [update](https://plotly.com/javascript/plotlyjs-function-reference/#plotlyupdate)
+    /// function.
+    pub fn update<R: Restyle, L: Relayout + Serialize>(
+        data_updates: &[R],
+        layout_updates: &[L],
+        traces: Option<&[usize]>,
+    ) -> Self {
+        let mut args = vec![
+            json!(merge_updates(data_updates)),
+            json!(merge_updates(layout_updates)),
+        ];
+        args.extend(traces.map(|traces| json!(traces)));
+        Self::new("update", args)
+    }
+
+    /// Append points to the traces with the given indices with the plotly.js
+    /// [extendTraces](https://plotly.com/javascript/plotlyjs-function-reference/#plotlyextendtraces)
+    /// function.
+    ///
+    /// `update` maps attribute names to one array of new points per trace in
+    /// `indices`, e.g. `json!({"x": [[4, 5]], "y": [[1, 2]]})`. If `max_points`
+    /// is given, only that many of the most recent points are kept.
+    pub fn extend_traces(
+        update: &impl Serialize,
+        indices: &[usize],
+        max_points: Option<usize>,
+    ) -> Self {
+        let mut args = vec![json!(update), json!(indices)];
+        args.extend(max_points.map(|max_points| json!(max_points)));
+        Self::new("extendTraces", args)
+    }
+
+    /// Insert points at the start of the traces with the given indices with the
+    /// plotly.js [prependTraces](https://plotly.com/javascript/plotlyjs-function-reference/#plotlyprependtraces)
+    /// function. See [`extend_traces`](Self::extend_traces) for the meaning of
+    /// the arguments.
+    pub fn prepend_traces(
+        update: &impl Serialize,
+        indices: &[usize],
+        max_points: Option<usize>,
+    ) -> Self {
+        let mut args = vec![json!(update), json!(indices)];
+        args.extend(max_points.map(|max_points| json!(max_points)));
+        Self::new("prependTraces", args)
+    }
+
+    fn new(method: &'static str, args: Vec<Value>) -> Self {
+        Self { method, args }
+    }
+
+    /// Merge `next` into this update if both add points to the same traces
+    /// with the same `max_points`, keeping only the points that `max_points`
+    /// retains. Returns whether `next` was merged.
+    fn merge_points(&mut self, next: &PlotUpdate) -> bool {
+        let extend = match (self.method, next.method) {
+            ("extendTraces", "extendTraces") => true,
+            ("prependTraces", "prependTraces") => false,
+            _ => return false,
+        };
+        if self.args[1..] != next.args[1..] {
+            return false;
+        }
+        let max_points = self.args.get(2).and_then(Value::as_u64);
+        let (Some(points), Some(next_points)) =
+            (self.args[0].as_object_mut(), next.args[0].as_object())
+        else {
+            return false;
+        };
+        // Both updates need one array of points per trace for the same
+        // attributes.
+        let same_shape = points.len() == next_points.len()
+            && next_points.iter().all(|(key, next_values)| {
+                match (
+                    points.get(key).and_then(Value::as_array),
+                    next_values.as_array(),
+                ) {
+                    (Some(values), Some(next_values)) => {
+                        values.len() == next_values.len()
+                            && values.iter().chain(next_values).all(Value::is_array)
+                    }
+                    _ => false,
+                }
+            });
+        if !same_shape {
+            return false;
+        }
+
+        for (key, next_values) in next_points {
+            let values = points[key].as_array_mut().unwrap();
+            for (trace, next_trace) in values.iter_mut().zip(next_values.as_array().unwrap()) {
+                let trace = trace.as_array_mut().unwrap();
+                let next_trace = next_trace.as_array().unwrap();
+                if extend {
+                    trace.extend(next_trace.iter().cloned());
+                    if let Some(max_points) = max_points {
+                        let excess = trace.len().saturating_sub(max_points as usize);
+                        trace.drain(..excess);
+                    }
+                } else {
+                    trace.splice(0..0, next_trace.iter().cloned());
+                    if let Some(max_points) = max_points {
+                        trace.truncate(max_points as usize);
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    /// An event of the event stream, calling `Plotly[method](graph_div,
+    /// ...args)` in the page.
+    fn message(&self) -> String {
+        format!(
+            "data: {}\n\n",
+            json!({"method": self.method, "args": self.args})
+        )
+    }
+}
+
+impl From<&Plot> for PlotUpdate {
+    fn from(plot: &Plot) -> Self {
+        Self::react(plot)
+    }
+}
+
+type Handler = Arc<Mutex<dyn FnMut(Value) -> Option<PlotUpdate> + Send>>;
+
+/// The state shared between a [`PlotHandle`] and the connections of the
+/// server.
+#[derive(Default)]
+struct State {
+    /// The updates which bring a newly connected page up to date: the last
+    /// `react` call and at most `MAX_HISTORY` updates made since.
+    history: Vec<PlotUpdate>,
+    /// The message queues of the connected pages. Each page is written to by
+    /// the thread of its connection, so that a page which stalls does not
+    /// hold up updates while the state is locked.
+    clients: Vec<Sender<Arc<str>>>,
+}
+
+impl State {
+    fn apply(&mut self, update: PlotUpdate) {
+        let message: Arc<str> = update.message().into();
+        self.clients
+            .retain(|client| client.send(message.clone()).is_ok());
+        self.record(update);
+    }
+
+    /// Add `update` to the history. Points added to the same traces in a row
+    /// are merged into a single update, so that the history of a plot which
+    /// is extended continuously stays short, and only holds the points that
+    /// `max_points` keeps.
+    fn record(&mut self, update: PlotUpdate) {
+        if update.method == "react" {
+            self.history.clear();
+        } else if let Some(last) = self.history.last_mut() {
+            if last.merge_points(&update) {
+                return;
+            }
+        }
+        // The first entry is the last `react`, which is always kept.
+        if self.history.len() > MAX_HISTORY {
+            self.history.remove(1);
+        }
+        self.history.push(update);
+    }
+}
+
+#[derive(Default)]
+struct Shared {
+    state: Mutex<State>,
+    /// The handlers of the events posted by the pages, by event name.
+    handlers: Mutex<HashMap<&'static str, Handler>>,
+    stopped: AtomicBool,
+}
+
+/// A handle to a plot served by a local HTTP server, through which the plot is
+/// updated in all pages displaying it, and through which handlers for the
+/// events of the pages are registered. The update methods mirror the plotly.js
+/// functions of the same name, and the `wasm` bindings.
+///
+/// The server stops when the handle is dropped.
+pub struct PlotHandle {
+    address: SocketAddr,
+    shared: Arc<Shared>,
+}
+
+impl PlotHandle {
+    /// Serve `plot` on the given address. Use [`Plot::serve`] to serve it on a
+    /// free port of the loopback interface; binding to any other interface
+    /// makes the plot reachable from other machines.
+    ///
+    /// Requests must name the bound address, or `localhost` for the loopback
+    /// interface, in their `Host` header, which protects the server against
+    /// DNS rebinding. This check is skipped when binding to an unspecified
+    /// address such as `0.0.0.0`, as the server is then reached through names
+    /// it does not know. Events are only accepted from the page of the server,
+    /// so that other websites cannot call the event handlers.
+    ///
+    /// The page loads plotly.js from the source set on `plot` with
+    /// `Plot::set_plotly_js_source`, if that is a URL or a CDN version other
+    /// than `PLOTLY_JS_VERSION`, and from the server otherwise. The source is
+    /// fixed when serving starts: changing it on a plot passed to `react`
+    /// later on has no effect on the page.
+    pub fn bind(address: impl ToSocketAddrs, plot: &Plot) -> io::Result<Self> {
+        let listener = TcpListener::bind(address)?;
+        let address = listener.local_addr()?;
+
+        let plotly_js_src = plot
+            .remote_plotly_js_url()
+            .unwrap_or_else(|| "/plotly.min.js".to_string());
+        let page: Arc<str> = LivePlotTemplate {
+            plotly_js_src: &plotly_js_src,
+        }
+        .render()
+        .unwrap()
+        .into();
+
+        let handle = Self {
+            address,
+            shared: Arc::default(),
+        };
+        handle.react(plot);
+
+        let shared = handle.shared.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                if shared.stopped.load(Ordering::SeqCst) {
+                    break;
+                }
+                if let Ok(stream) = stream {
+                    let page = page.clone();
+                    let shared = shared.clone();
+                    thread::spawn(move || {
+                        // The connection is dropped if the page goes away
+                        // while it is served.
+                        let _ = handle_connection(stream, address, &page, &shared);
+                    });
+                }
+            }
+        });
+
+        Ok(handle)
+    }
+
+    /// The address the server is listening on.
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// The URL of the page displaying the plot.
+    pub fn url(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    /// Open the page displaying the plot in the default system browser.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn show(&self) {
+        Plot::show_with_default_app(&self.url());
+    }
+
+    /// Apply `update` to all pages displaying the plot.
+    ///
+    /// The updates made since the last `react` are replayed to pages which
+    /// connect later on. Consecutive `extend_traces` or `prepend_traces` calls
+    /// on the same traces are replayed as one, but beyond that only the last
+    /// 1000 updates are kept, so a plot which changes in other ways for a long
+    /// time should be redrawn with `react` from time to time.
+    pub fn apply(&self, update: PlotUpdate) {
+        self.shared.state.lock().unwrap().apply(update);
+    }
+
+    /// Redraw the plot, see [`PlotUpdate::react`].
+    pub fn react(&self, plot: &Plot) {
+        self.apply(PlotUpdate::react(plot));
+    }
+
+    /// Update traces of the plot, see [`PlotUpdate::restyle`].
+    pub fn restyle<R: Restyle>(&self, updates: &[R], traces: Option<&[usize]>) {
+        self.apply(PlotUpdate::restyle(updates, traces));
+    }
+
+    /// Update the layout of the plot, see [`PlotUpdate::relayout`].
+    pub fn relayout<L: Relayout + Serialize>(&self, updates: &[L]) {
+        self.apply(PlotUpdate::relayout(updates));
+    }
+
+    /// Update traces and the layout of the plot, see [`PlotUpdate::update`].
+    pub fn update<R: Restyle, L: Relayout + Serialize>(
+        &self,
+        data_updates: &[R],
+        layout_updates: &[L],
+        traces: Option<&[usize]>,
+    ) {
+        self.apply(PlotUpdate::update(data_updates, layout_updates, traces));
+    }
+
+    /// Append points to traces of the plot, see [`PlotUpdate::extend_traces`].
+    pub fn extend_traces(
+        &self,
+        update: &impl Serialize,
+        indices: &[usize],
+        max_points: Option<usize>,
+    ) {
+        self.apply(PlotUpdate::extend_traces(update, indices, max_points));
+    }
+
+    /// Insert points at the start of traces of the plot, see
+    /// [`PlotUpdate::prepend_traces`].
+    pub fn prepend_traces(
+        &self,
+        update: &impl Serialize,
+        indices: &[usize],
+        max_points: Option<usize>,
+    ) {
+        self.apply(PlotUpdate::prepend_traces(update, indices, max_points));
+    }
+
+    /// Handle clicks on data points. The handler replaces any handler
+    /// registered before, as for the other `on_*` methods, and the update it
+    /// returns is applied to all pages displaying the plot.
+    pub fn on_click<F>(&self, handler: F)
+    where
+        F: FnMut(PointsEvent) -> Option<PlotUpdate> + Send + 'static,
+    {
+        self.on("plotly_click", handler);
+    }
+
+    /// Handle box and lasso selections. The handler receives `None` when the
+    /// selection is cleared.
+    pub fn on_selected<F>(&self, handler: F)
+    where
+        F: FnMut(Option<SelectionEvent>) -> Option<PlotUpdate> + Send + 'static,
+    {
+        self.on("plotly_selected", handler);
+    }
+
+    /// Handle layout changes, including zooming and panning. Note that the
+    /// handler is also called for the `relayout` updates made through the
+    /// server, so it should not unconditionally return another one.
+    pub fn on_relayout<F>(&self, handler: F)
+    where
+        F: FnMut(RelayoutEvent) -> Option<PlotUpdate> + Send + 'static,
+    {
+        self.on("plotly_relayout", handler);
+    }
+
+    /// Handle clicks on the buttons of `UpdateMenu`s. The method of the button
+    /// is applied by plotly.js before the handler is called.
+    pub fn on_button_clicked<F>(&self, handler: F)
+    where
+        F: FnMut(ButtonClickEvent) -> Option<PlotUpdate> + Send + 'static,
+    {
+        self.on("plotly_buttonclicked", handler);
+    }
+
+    /// Handle changes of the active step of sliders.
+    pub fn on_slider_change<F>(&self, handler: F)
+    where
+        F: FnMut(SliderChangeEvent) -> Option<PlotUpdate> + Send + 'static,
+    {
+        self.on("plotly_sliderchange", handler);
+    }
+
+    fn on<T, F>(&self, event: &'static str, mut handler: F)
+    where
+        T: DeserializeOwned,
+        F: FnMut(T) -> Option<PlotUpdate> + Send + 'static,
+    {
+        // Events whose data does not have the documented structure are
+        // ignored, as in the `wasm` bindings.
+        let handler: Handler = Arc::new(Mutex::new(move |data| {
+            serde_json::from_value(data).ok().and_then(&mut handler)
+        }));
+        self.shared.handlers.lock().unwrap().insert(event, handler);
+    }
+}
+
+impl Drop for PlotHandle {
+    fn drop(&mut self) {
+        self.shared.stopped.store(true, Ordering::SeqCst);
+        // Wake up the accept loop, so that it sees the server has stopped.
+        let _ = TcpStream::connect(self.address);
+        // Closing the message queues ends the event streams.
+        self.shared.state.lock().unwrap().clients.clear();
+    }
+}
+
+/// Whether `host`, the value of a `Host` header, names the server.
+fn is_own_host(host: &str, address: SocketAddr) -> bool {
+    if address.ip().is_unspecified() {
+        return true;
+    }
+    host.eq_ignore_ascii_case(&address.to_string())
+        || (address.ip().is_loopback()
+            && host.eq_ignore_ascii_case(&format!("localhost:{}", address.port())))
+}
+
+fn handle_connection(
+    mut stream: TcpStream,
+    address: SocketAddr,
+    page: &str,
+    shared: &Shared,
+) -> io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut content_length = 0;
+    let mut host = None;
+    let mut origin = None;
+    let mut header = String::new();
+    while reader.read_line(&mut header)? > 0 && header.trim_end() != "" {
+        if let Some((name, value)) = header.split_once(':') {
+            let value = value.trim();
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.parse().unwrap_or(0);
+            } else if name.eq_ignore_ascii_case("host") {
+                host = Some(value.to_string());
+            } else if name.eq_ignore_ascii_case("origin") {
+                origin = Some(value.to_string());
+            }
+        }
+        header.clear();
+    }
+
+    let host = match host {
+        Some(host) if is_own_host(&host, address) => host,
+        _ => return respond(&mut stream, "403 Forbidden", "text/plain", "Forbidden"),
+    };
+
+    let mut parts = request_line.split_whitespace();
+    let (method, target) = (parts.next(), parts.next().unwrap_or("/"));
+    let path = target.split('?').next().unwrap_or(target);
+    match (method, path) {
+        (Some("GET"), "/") => respond(&mut stream, "200 OK", "text/html; charset=utf-8", page),
+        (Some("GET"), "/plotly.min.js") => respond(
+            &mut stream,
+            "200 OK",
+            "text/javascript; charset=utf-8",
+            PLOTLY_JS,
+        ),
+        (Some("GET"), "/events") => {
+            stream.set_write_timeout(Some(WRITE_TIMEOUT))?;
+            stream.write_all(
+                b"HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nCache-Control: no-cache\r\n\r\n",
+            )?;
+            // Queue the history while holding the lock, so that no update is
+            // missed or sent twice.
+            let (sender, receiver) = mpsc::channel();
+            {
+                let mut state = shared.state.lock().unwrap();
+                for update in &state.history {
+                    let _ = sender.send(update.message().into());
+                }
+                state.clients.push(sender);
+            }
+            // A write error or timeout drops the receiver, which removes the
+            // page from the clients on the next update.
+            for message in receiver {
+                stream.write_all(message.as_bytes())?;
+            }
+            stream.shutdown(Shutdown::Both)
+        }
+        (Some("GET"), _) => respond(&mut stream, "404 Not Found", "text/plain", "Not Found"),
+        (Some("POST"), path) if path.starts_with("/events/") => {
+            if content_length > MAX_EVENT_SIZE {
+                return respond(
+                    &mut stream,
+                    "413 Payload Too Large",
+                    "text/plain",
+                    "Payload Too Large",
+                );
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body)?;
+            // Pages of other origins can post to the server without a
+            // preflight request, but browsers always send their origin.
+            if origin.is_some_and(|origin| origin != format!("http://{}", host)) {
+                return respond(&mut stream, "403 Forbidden", "text/plain", "Forbidden");
+            }
+            let data = serde_json::from_slice(&body).unwrap_or(Value::Null);
+            let event = &path["/events/".len()..];
+            // Release the map before running the handler, so that handlers of
+            // other events are not blocked by it.
+            let handler = shared.handlers.lock().unwrap().get(event).cloned();
+            if let Some(handler) = handler {
+                let update = (handler.lock().unwrap())(data);
+                if let Some(update) = update {
+                    shared.state.lock().unwrap().apply(update);
+                }
+            }
+            respond(&mut stream, "204 No Content", "text/plain", "")
+        }
+        _ => respond(
+            &mut stream,
+            "405 Method Not Allowed",
+            "text/plain",
+            "Method Not Allowed",
+        ),
+    }
+}
+
+fn respond(stream: &mut TcpStream, status: &str, content_type: &str, body: &str) -> io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        status,
+        content_type,
+        body.len()
+    )?;
+    stream.write_all(body.as_bytes())?;
+    stream.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::from_str;
+
+    use super::*;
+    use crate::{common::Dim, layout::RelayoutLayout, traces::RestyleScatter, Scatter};
+
+    fn plot() -> Plot {
+        let mut plot = Plot::new();
+        plot.add_trace(Scatter::new(vec![0, 1], vec![2, 3]));
+        plot
+    }
+
+    fn get(handle: &PlotHandle, path: &str) -> String {
+        let mut stream = TcpStream::connect(handle.address()).unwrap();
+        write!(
+            stream,
+            "GET {} HTTP/1.1\r\nHost: {}\r\n\r\n",
+            path,
+            handle.address()
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    fn post(handle: &PlotHandle, event: &str, data: Value) -> String {
+        post_with_headers(
+            handle,
+            event,
+            data,
+            &format!("Origin: {}\r\n", handle.url()),
+        )
+    }
+
+    fn post_with_headers(handle: &PlotHandle, event: &str, data: Value, headers: &str) -> String {
+        let body = data.to_string();
+        let mut stream = TcpStream::connect(handle.address()).unwrap();
+        write!(
+            stream,
+            "POST /events/{} HTTP/1.1\r\nHost: {}\r\n{}Content-Length: {}\r\n\r\n{}",
+            event,
+            handle.address(),
+            headers,
+            body.len(),
+            body
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    /// Connect to the event stream, returning a reader positioned after the
+    /// response headers.
+    fn connect_events(handle: &PlotHandle) -> BufReader<TcpStream> {
+        let mut stream = TcpStream::connect(handle.address()).unwrap();
+        stream
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+        write!(
+            stream,
+            "GET /events HTTP/1.1\r\nHost: {}\r\n\r\n",
+            handle.address()
+        )
+        .unwrap();
+        let mut reader = BufReader::new(stream);
+        let mut line = String::new();
+        while reader.read_line(&mut line).unwrap() > 0 && line.trim_end() != "" {
+            line.clear();
+        }
+        reader
+    }
+
+    fn next_message(events: &mut BufReader<TcpStream>) -> Value {
+        let mut line = String::new();
+        events.read_line(&mut line).unwrap();
+        let mut blank = String::new();
+        events.read_line(&mut blank).unwrap();
+        from_str(line.strip_prefix("data: ").unwrap().trim_end()).unwrap()
+    }
+
+    #[test]
+    fn test_serve_page() {
+        let handle = plot().serve().unwrap();
+        assert!(handle.address().ip().is_loopback());
+
+        let page = get(&handle, "/");
+        assert!(page.starts_with("HTTP/1.1 200 OK"));
+        assert!(page.contains(r#"<script src="/plotly.min.js"></script>"#));
+        assert!(page.contains(r#"new EventSource("/events")"#));
+
+        let script = get(&handle, "/plotly.min.js");
+        assert!(script.starts_with("HTTP/1.1 200 OK"));
+        assert!(script.ends_with(PLOTLY_JS));
+
+        assert!(get(&handle, "/favicon.ico").starts_with("HTTP/1.1 404 Not Found"));
+    }
+
+    #[test]
+    fn test_serve_page_with_plotly_js_version() {
+        let mut plot = plot();
+        plot.set_plotly_js_version("2.35.2");
+        let handle = plot.serve().unwrap();
+
+        let page = get(&handle, "/");
+        assert!(
+            page.contains(r#"<script src="https://cdn.plot.ly/plotly-2.35.2.min.js"></script>"#)
+        );
+    }
+
+    #[test]
+    fn test_push_updates() {
+        let plot = plot();
+        let handle = plot.serve().unwrap();
+        let mut events = connect_events(&handle);
+        assert_eq!(
+            next_message(&mut events),
+            json!({"method": "react", "args": [plot]})
+        );
+
+        handle.extend_traces(&json!({"x": [[2]], "y": [[4]]}), &[0], Some(10));
+        assert_eq!(
+            next_message(&mut events),
+            json!({"method": "extendTraces", "args": [{"x": [[2]], "y": [[4]]}, [0], 10]})
+        );
+
+        let restyle: RestyleScatter<f64, f64> = RestyleScatter::ModifyOpacity {
+            opacity: Some(Dim::Scalar(0.5)),
+        };
+        handle.restyle(&[restyle], None);
+        assert_eq!(
+            next_message(&mut events),
+            json!({"method": "restyle", "args": [{"opacity": 0.5}]})
+        );
+    }
+
+    #[test]
+    fn test_replay_history() {
+        let handle = plot().serve().unwrap();
+        handle.prepend_traces(&json!({"y": [[1]]}), &[0], None);
+
+        let mut events = connect_events(&handle);
+        assert_eq!(next_message(&mut events)["method"], "react");
+        assert_eq!(
+            next_message(&mut events),
+            json!({"method": "prependTraces", "args": [{"y": [[1]]}, [0]]})
+        );
+
+        let mut new_plot = plot();
+        new_plot.add_trace(Scatter::new(vec![1], vec![1]));
+        handle.react(&new_plot);
+        assert_eq!(
+            next_message(&mut events),
+            json!({"method": "react", "args": [new_plot]})
+        );
+
+        // Updates made before the last `react` are not replayed.
+        let mut events = connect_events(&handle);
+        assert_eq!(
+            next_message(&mut events),
+            json!({"method": "react", "args": [new_plot]})
+        );
+    }
+
+    #[test]
+    fn test_replay_merged_points() {
+        let handle = plot().serve().unwrap();
+        for i in 0..5 {
+            handle.extend_traces(&json!({"x": [[i]], "y": [[i * i]]}), &[0], Some(3));
+        }
+        handle.extend_traces(&json!({"y": [[5]]}), &[0], Some(3));
+
+        let mut events = connect_events(&handle);
+        assert_eq!(next_message(&mut events)["method"], "react");
+        assert_eq!(
+            next_message(&mut events),
+            json!({"method": "extendTraces", "args": [{"x": [[2, 3, 4]], "y": [[4, 9, 16]]}, [0], 3]})
+        );
+        assert_eq!(
+            next_message(&mut events),
+            json!({"method": "extendTraces", "args": [{"y": [[5]]}, [0], 3]})
+        );
+    }
+
+    #[test]
+    fn test_merge_prepended_points() {
+        let mut update = PlotUpdate::prepend_traces(&json!({"y": [[2], [3]]}), &[0, 1], Some(2));
+        assert!(update.merge_points(&PlotUpdate::prepend_traces(
+            &json!({"y": [[0, 1], [1]]}),
+            &[0, 1],
+            Some(2)
+        )));
+        assert_eq!(update.args[0], json!({"y": [[0, 1], [1, 3]]}));
+
+        // Updates of other traces, or with another `max_points`, are kept.
+        let other_traces = PlotUpdate::prepend_traces(&json!({"y": [[0]]}), &[1], Some(2));
+        assert!(!update.merge_points(&other_traces));
+        let unbounded = PlotUpdate::prepend_traces(&json!({"y": [[0], [0]]}), &[0, 1], None);
+        assert!(!update.merge_points(&unbounded));
+    }
+
+    #[test]
+    fn test_bounded_history() {
+        let mut state = State::default();
+        state.apply(PlotUpdate::react(&plot()));
+        for i in 0..MAX_HISTORY + 10 {
+            let relayout = RelayoutLayout::ModifyShowLegend {
+                show_legend: Some(i % 2 == 0),
+            };
+            state.apply(PlotUpdate::relayout(&[relayout]));
+        }
+        assert_eq!(state.history.len(), MAX_HISTORY + 1);
+        assert_eq!(state.history[0].method, "react");
+    }
+
+    #[test]
+    fn test_reject_foreign_requests() {
+        let handle = plot().serve().unwrap();
+        let clicked = Arc::new(AtomicBool::new(false));
+        {
+            let clicked = clicked.clone();
+            handle.on_click(move |_| {
+                clicked.store(true, Ordering::SeqCst);
+                None
+            });
+        }
+        let click = json!({"points": []});
+
+        // Another website, or a DNS rebinding attack.
+        let foreign_origin = "Origin: http://example.com\r\n";
+        let response = post_with_headers(&handle, "plotly_click", click.clone(), foreign_origin);
+        assert!(response.starts_with("HTTP/1.1 403 Forbidden"));
+
+        let mut stream = TcpStream::connect(handle.address()).unwrap();
+        write!(stream, "GET / HTTP/1.1\r\nHost: example.com\r\n\r\n").unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 403 Forbidden"));
+
+        let too_large = format!("Content-Length: {}\r\n", MAX_EVENT_SIZE + 1);
+        let mut stream = TcpStream::connect(handle.address()).unwrap();
+        write!(
+            stream,
+            "POST /events/plotly_click HTTP/1.1\r\nHost: {}\r\n{}\r\n",
+            handle.address(),
+            too_large
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 413 Payload Too Large"));
+        assert!(!clicked.load(Ordering::SeqCst));
+
+        // Requests without an origin, e.g. from other programs, are accepted.
+        assert!(post_with_headers(&handle, "plotly_click", click, "")
+            .starts_with("HTTP/1.1 204 No Content"));
+        assert!(clicked.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn test_stalled_page() {
+        let handle = plot().serve().unwrap();
+        // A page which never reads its event stream.
+        let _stalled = connect_events(&handle);
+        let mut events = connect_events(&handle);
+        assert_eq!(next_message(&mut events)["method"], "react");
+
+        // Enough data to fill the socket buffers of the stalled page.
+        let points: Vec<usize> = (0..100_000).collect();
+        let start = std::time::Instant::now();
+        for _ in 0..20 {
+            handle.extend_traces(&json!({"y": [points]}), &[0], Some(10));
+        }
+        assert!(start.elapsed() < WRITE_TIMEOUT);
+        assert_eq!(next_message(&mut events)["method"], "extendTraces");
+    }
+
+    #[test]
+    fn test_event_handlers() {
+        let handle = plot().serve().unwrap();
+        handle.on_click(|event| {
+            let point = event.points.first()?;
+            let relayout = RelayoutLayout::ModifyTitle {
+                title: Some(format!("Clicked {}", point.x.clone()?).as_str().into()),
+            };
+            Some(PlotUpdate::relayout(&[relayout]))
+        });
+        handle.on_selected(|event| {
+            let relayout = RelayoutLayout::ModifyShowLegend {
+                show_legend: Some(event.is_some()),
+            };
+            Some(PlotUpdate::relayout(&[relayout]))
+        });
+        let mut events = connect_events(&handle);
+        assert_eq!(next_message(&mut events)["method"], "react");
+
+        let click = json!({"points": [{"curveNumber": 0, "pointIndex": 1, "x": 1, "y": 3}]});
+        assert!(post(&handle, "plotly_click", click).starts_with("HTTP/1.1 204 No Content"));
+        assert_eq!(
+            next_message(&mut events),
+            json!({"method": "relayout", "args": [{"title": {"text": "Clicked 1"}}]})
+        );
+
+        // A cleared selection is posted as `null`.
+        post(&handle, "plotly_selected", Value::Null);
+        assert_eq!(
+            next_message(&mut events),
+            json!({"method": "relayout", "args": [{"showlegend": false}]})
+        );
+
+        // Neither invalid data nor events without a handler cause an update.
+        assert!(post(&handle, "plotly_click", json!({})).starts_with("HTTP/1.1 204"));
+        assert!(post(&handle, "plotly_relayout", json!({})).starts_with("HTTP/1.1 204"));
+        handle.on_button_clicked(|event| {
+            let mut plot = plot();
+            plot.add_trace(Scatter::new(vec![event.menu], vec![event.button]));
+            Some(PlotUpdate::react(&plot))
+        });
+        post(
+            &handle,
+            "plotly_buttonclicked",
+            json!({"menu": 0, "button": 2}),
+        );
+        let message = next_message(&mut events);
+        assert_eq!(message["method"], "react");
+        assert_eq!(message["args"][0]["data"][1]["y"], json!([2]));
+    }
+}
@@ -100,6 +100,9 @@ where
     x_calendar: Option<Calendar>,
     #[serde(rename = "ycalendar")]
     y_calendar: Option<Calendar>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl<X, Y> Bar<X, Y>
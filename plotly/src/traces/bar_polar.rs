@@ -0,0 +1,236 @@
+//! Polar bar trace
+
+use plotly_derive::FieldSetter;
+use serde::Serialize;
+
+use crate::{
+    common::{Dim, HoverInfo, Label, LegendGroupTitle, Marker, PlotType, Visible},
+    private::{NumOrString, NumOrStringCollection},
+    Trace,
+};
+
+/// Construct a polar bar trace, e.g. for wind roses.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::BarPolar;
+///
+/// let trace = BarPolar::new(vec!["N", "E", "S", "W"], vec![4.0, 2.5, 1.0, 3.5])
+///     .subplot("polar2");
+///
+/// let expected = serde_json::json!({
+///     "type": "barpolar",
+///     "theta": ["N", "E", "S", "W"],
+///     "r": [4.0, 2.5, 1.0, 3.5],
+///     "subplot": "polar2"
+/// });
+///
+/// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+#[field_setter(box_self, kind = "trace")]
+pub struct BarPolar<Theta, R>
+where
+    Theta: Serialize + Clone + 'static,
+    R: Serialize + Clone + 'static,
+{
+    #[field_setter(default = "PlotType::BarPolar")]
+    r#type: PlotType,
+    /// Sets the trace name. The trace name appear as the legend item and on
+    /// hover.
+    name: Option<String>,
+    /// Determines whether or not this trace is visible. If
+    /// `Visible::LegendOnly`, the trace is not drawn, but can appear as a
+    /// legend item (provided that the legend itself is visible).
+    visible: Option<Visible>,
+    /// Determines whether or not an item corresponding to this trace is shown
+    /// in the legend.
+    #[serde(rename = "showlegend")]
+    show_legend: Option<bool>,
+    /// Sets the legend group for this trace. Traces part of the same legend
+    /// group hide/show at the same time when toggling legend items.
+    #[serde(rename = "legendgroup")]
+    legend_group: Option<String>,
+    /// Set and style the title to appear for the legend group
+    #[serde(rename = "legendgrouptitle")]
+    legend_group_title: Option<LegendGroupTitle>,
+    /// Sets the opacity of the trace.
+    opacity: Option<f64>,
+    /// Assigns id labels to each datum. These ids for object constancy of data
+    /// points during animation. Should be an array of strings, not numbers
+    /// or any other type.
+    ids: Option<Vec<String>>,
+    /// Sets the angular coordinates.
+    theta: Option<Vec<Theta>>,
+    /// Alternate to `theta`. Builds a linear space of theta coordinates. Use
+    /// with `dtheta` where `theta0` is the starting coordinate and `dtheta`
+    /// the step.
+    theta0: Option<NumOrString>,
+    /// Sets the theta coordinate step. See `theta0` for more info.
+    dtheta: Option<f64>,
+    /// Sets the radial coordinates.
+    r: Option<Vec<R>>,
+    /// Alternate to `r`. Builds a linear space of r coordinates. Use with `dr`
+    /// where `r0` is the starting coordinate and `dr` the step.
+    r0: Option<NumOrString>,
+    /// Sets the r coordinate step. See `r0` for more info.
+    dr: Option<f64>,
+    /// Sets a reference between this trace's data coordinates and a polar
+    /// subplot. If "polar" (the default value), the data refer to
+    /// `layout.polar`. If "polar2", the data refer to `layout.polar2`, and
+    /// so on.
+    subplot: Option<String>,
+    /// Sets the bar angular width (in "thetaunit" units).
+    width: Option<Dim<f64>>,
+    /// Sets where the bar base is drawn (in radial axis units). In "stack"
+    /// barmode, traces that set "base" will be excluded and drawn in "overlay"
+    /// mode instead.
+    base: Option<Dim<f64>>,
+    /// Shifts the angular position where the bar is drawn (in "thetatunit"
+    /// units).
+    offset: Option<Dim<f64>>,
+    marker: Option<Marker>,
+    /// Sets hover text elements associated with each bar. If a single string,
+    /// the same string appears over all bars. If an array of string, the
+    /// items are mapped in order to the this trace's coordinates.
+    text: Option<Dim<String>>,
+    /// Same as `text`.
+    #[serde(rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    /// Determines which trace information appear on hover. If `HoverInfo::None`
+    /// or `HoverInfo::Skip` are set, no information is displayed upon
+    /// hovering. But, if `HoverInfo::None` is set, click and hover events
+    /// are still fired.
+    #[serde(rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    /// Template string used for rendering the information that appear on hover
+    /// box. Note that this will override `HoverInfo`.
+    #[serde(rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    /// Properties of label displayed on mouse hover.
+    #[serde(rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    /// Vector containing integer indices of selected points. Has an effect only
+    /// for traces that support selections.
+    #[serde(rename = "selectedpoints")]
+    selected_points: Option<Vec<usize>>,
+    /// Assigns extra meta information associated with this trace that can be
+    /// used in various text attributes. To access the trace `meta` values in
+    /// an attribute in the same trace, simply use `%{meta[i]}` where `i` is
+    /// the index or key of the `meta` item in question.
+    meta: Option<NumOrString>,
+    /// Assigns extra data each datum. This may be useful when listening to
+    /// hover, click and selection events.
+    #[serde(rename = "customdata")]
+    custom_data: Option<NumOrStringCollection>,
+    /// Controls persistence of some user-driven changes to the trace. Defaults
+    /// to `layout.uirevision`.
+    #[serde(rename = "uirevision")]
+    ui_revision: Option<NumOrString>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl<Theta, R> BarPolar<Theta, R>
+where
+    Theta: Serialize + Clone + 'static,
+    R: Serialize + Clone + 'static,
+{
+    pub fn new(theta: Vec<Theta>, r: Vec<R>) -> Box<Self> {
+        Box::new(Self {
+            theta: Some(theta),
+            r: Some(r),
+            ..Default::default()
+        })
+    }
+}
+
+impl<Theta, R> Trace for BarPolar<Theta, R>
+where
+    Theta: Serialize + Clone + 'static,
+    R: Serialize + Clone + 'static,
+{
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+
+    #[test]
+    fn test_serialize_default_bar_polar() {
+        let trace = BarPolar::<f64, f64>::default();
+        let expected = json!({"type": "barpolar"});
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_bar_polar() {
+        let trace = BarPolar::new(vec![0.0, 90.0], vec![1, 2])
+            .name("bar_polar_trace")
+            .visible(Visible::True)
+            .show_legend(true)
+            .legend_group("legend_group")
+            .legend_group_title(LegendGroupTitle::new("Legend Group Title"))
+            .opacity(0.8)
+            .ids(vec!["one", "two"])
+            .theta0(0)
+            .dtheta(90.0)
+            .r0(1)
+            .dr(1.0)
+            .subplot("polar3")
+            .width(45.0)
+            .base_array(vec![0.5, 1.0])
+            .offset(-22.5)
+            .marker(Marker::new())
+            .text("text")
+            .hover_text_array(vec!["a", "b"])
+            .hover_info(HoverInfo::All)
+            .hover_template("hover_template")
+            .hover_label(Label::new())
+            .selected_points(vec![0])
+            .meta("meta")
+            .custom_data(vec!["custom_data"])
+            .ui_revision(5);
+        let expected = json!({
+            "type": "barpolar",
+            "name": "bar_polar_trace",
+            "visible": true,
+            "showlegend": true,
+            "legendgroup": "legend_group",
+            "legendgrouptitle": {"text": "Legend Group Title"},
+            "opacity": 0.8,
+            "ids": ["one", "two"],
+            "theta": [0.0, 90.0],
+            "theta0": 0,
+            "dtheta": 90.0,
+            "r": [1, 2],
+            "r0": 1,
+            "dr": 1.0,
+            "subplot": "polar3",
+            "width": 45.0,
+            "base": [0.5, 1.0],
+            "offset": -22.5,
+            "marker": {},
+            "text": "text",
+            "hovertext": ["a", "b"],
+            "hoverinfo": "all",
+            "hovertemplate": "hover_template",
+            "hoverlabel": {},
+            "selectedpoints": [0],
+            "meta": "meta",
+            "customdata": ["custom_data"],
+            "uirevision": 5,
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+}
@@ -84,6 +84,9 @@ where
     hover_label: Option<Label>,
     #[serde(rename = "xcalendar")]
     x_calendar: Option<Calendar>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl<T, O> Candlestick<T, O>
@@ -0,0 +1,490 @@
+//! Carpet trace
+
+use plotly_derive::FieldSetter;
+use serde::Serialize;
+
+use crate::{
+    color::Color,
+    common::{ExponentFormat, Font, PlotType, Show, TickMode, Title, Visible},
+    layout::AxisType,
+    private::{NumOrString, NumOrStringCollection},
+    Trace,
+};
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum CheaterType {
+    Index,
+    Value,
+}
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum ShowTickLabels {
+    Start,
+    End,
+    Both,
+    None,
+}
+
+/// The `aaxis` or `baxis` of a carpet.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct CarpetAxis {
+    /// Sets the axis type. By default, plotly attempts to determined the axis
+    /// type by looking into the data of the traces that referenced the axis
+    /// in question.
+    #[field_setter(skip)]
+    r#type: Option<AxisType>,
+    /// Determines whether or not the range of this axis is computed in
+    /// relation to the input data.
+    #[serde(rename = "autorange")]
+    auto_range: Option<bool>,
+    /// Sets the range of this axis.
+    range: Option<Vec<f64>>,
+    title: Option<Title>,
+    /// Sets default for all colors associated with this axis all at once:
+    /// line, font, tick, and grid colors.
+    color: Option<Box<dyn Color>>,
+    /// Controls the smoothing of the carpet grid lines, between 0 and 1.3.
+    smoothing: Option<f64>,
+    #[serde(rename = "cheatertype")]
+    cheater_type: Option<CheaterType>,
+    #[serde(rename = "tickmode")]
+    tick_mode: Option<TickMode>,
+    #[serde(rename = "nticks")]
+    n_ticks: Option<usize>,
+    tick0: Option<f64>,
+    /// Sets the step in-between ticks on this axis.
+    dtick: Option<f64>,
+    /// The starting index of grid lines along the axis.
+    #[serde(rename = "arraytick0")]
+    array_tick0: Option<usize>,
+    /// The stride between grid lines along the axis.
+    #[serde(rename = "arraydtick")]
+    array_dtick: Option<usize>,
+    #[serde(rename = "tickvals")]
+    tick_values: Option<Vec<f64>>,
+    #[serde(rename = "ticktext")]
+    tick_text: Option<Vec<String>>,
+    /// Determines whether axis labels are drawn on the low side, the high
+    /// side, both, or neither side of the axis.
+    #[serde(rename = "showticklabels")]
+    show_tick_labels: Option<ShowTickLabels>,
+    #[serde(rename = "tickfont")]
+    tick_font: Option<Font>,
+    #[serde(rename = "tickformat")]
+    tick_format: Option<String>,
+    #[serde(rename = "showtickprefix")]
+    show_tick_prefix: Option<Show>,
+    #[serde(rename = "tickprefix")]
+    tick_prefix: Option<String>,
+    #[serde(rename = "showticksuffix")]
+    show_tick_suffix: Option<Show>,
+    #[serde(rename = "ticksuffix")]
+    tick_suffix: Option<String>,
+    #[serde(rename = "showexponent")]
+    show_exponent: Option<Show>,
+    #[serde(rename = "exponentformat")]
+    exponent_format: Option<ExponentFormat>,
+    /// Extra padding between label and the axis.
+    #[serde(rename = "labelpadding")]
+    label_padding: Option<usize>,
+    /// Sets a axis label prefix.
+    #[serde(rename = "labelprefix")]
+    label_prefix: Option<String>,
+    /// Sets a axis label suffix.
+    #[serde(rename = "labelsuffix")]
+    label_suffix: Option<String>,
+    #[serde(rename = "showline")]
+    show_line: Option<bool>,
+    #[serde(rename = "linecolor")]
+    line_color: Option<Box<dyn Color>>,
+    #[serde(rename = "linewidth")]
+    line_width: Option<f64>,
+    #[serde(rename = "showgrid")]
+    show_grid: Option<bool>,
+    #[serde(rename = "gridcolor")]
+    grid_color: Option<Box<dyn Color>>,
+    #[serde(rename = "gridwidth")]
+    grid_width: Option<f64>,
+    /// Sets the number of minor grid ticks per major grid tick.
+    #[serde(rename = "minorgridcount")]
+    minor_grid_count: Option<usize>,
+    #[serde(rename = "minorgridcolor")]
+    minor_grid_color: Option<Box<dyn Color>>,
+    #[serde(rename = "minorgridwidth")]
+    minor_grid_width: Option<f64>,
+    /// Determines whether or not a line is drawn at along the starting value
+    /// of this axis.
+    #[serde(rename = "startline")]
+    start_line: Option<bool>,
+    #[serde(rename = "startlinecolor")]
+    start_line_color: Option<Box<dyn Color>>,
+    #[serde(rename = "startlinewidth")]
+    start_line_width: Option<f64>,
+    /// Determines whether or not a line is drawn at along the final value of
+    /// this axis.
+    #[serde(rename = "endline")]
+    end_line: Option<bool>,
+    #[serde(rename = "endlinecolor")]
+    end_line_color: Option<Box<dyn Color>>,
+    #[serde(rename = "endlinewidth")]
+    end_line_width: Option<f64>,
+    /// Determines whether or not this axis is zoom-able.
+    #[serde(rename = "fixedrange")]
+    fixed_range: Option<bool>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl CarpetAxis {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn type_(mut self, t: AxisType) -> Self {
+        self.r#type = Some(t);
+        self
+    }
+}
+
+/// Construct a carpet trace. Carpets define a curvilinear coordinate system
+/// `(a, b)` which `ScatterCarpet` and `ContourCarpet` traces can be plotted
+/// onto by referencing the carpet's `carpet` id.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::Carpet;
+///
+/// let trace = Carpet::new(
+///     vec![4.0, 4.0, 5.0, 5.0],
+///     vec![1.0, 2.0, 1.0, 2.0],
+///     vec![2.0, 3.5, 3.0, 4.5],
+/// )
+/// .carpet("c1");
+///
+/// let expected = serde_json::json!({
+///     "type": "carpet",
+///     "a": [4.0, 4.0, 5.0, 5.0],
+///     "b": [1.0, 2.0, 1.0, 2.0],
+///     "y": [2.0, 3.5, 3.0, 4.5],
+///     "carpet": "c1"
+/// });
+///
+/// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+#[field_setter(box_self, kind = "trace")]
+pub struct Carpet<X = f64, Y = f64>
+where
+    X: Serialize + Clone,
+    Y: Serialize + Clone,
+{
+    #[field_setter(default = "PlotType::Carpet")]
+    r#type: PlotType,
+    /// Sets the trace name. The trace name appear as the legend item and on
+    /// hover.
+    name: Option<String>,
+    /// Determines whether or not this trace is visible. If
+    /// `Visible::LegendOnly`, the trace is not drawn, but can appear as a
+    /// legend item (provided that the legend itself is visible).
+    visible: Option<Visible>,
+    /// Sets the opacity of the trace.
+    opacity: Option<f64>,
+    /// An identifier for this carpet, so that `ScatterCarpet` and
+    /// `ContourCarpet` traces can specify a carpet plot on which they lie.
+    carpet: Option<String>,
+    /// An array containing values of the first parameter value.
+    a: Option<Vec<f64>>,
+    /// Alternate to `a`. Builds a linear space of a coordinates. Use with `da`
+    /// where `a0` is the starting coordinate and `da` the step.
+    a0: Option<f64>,
+    /// Sets the a coordinate step.
+    da: Option<f64>,
+    /// An array containing values of the second parameter value.
+    b: Option<Vec<f64>>,
+    /// Alternate to `b`. Builds a linear space of b coordinates. Use with `db`
+    /// where `b0` is the starting coordinate and `db` the step.
+    b0: Option<f64>,
+    /// Sets the b coordinate step.
+    db: Option<f64>,
+    /// A two dimensional array of x coordinates at each carpet point. If
+    /// omitted, the plot is a cheater plot and the xaxis is hidden by
+    /// default.
+    x: Option<Vec<X>>,
+    /// A two dimensional array of y coordinates at each carpet point.
+    y: Option<Vec<Y>>,
+    /// The shift applied to each successive row of data in creating a cheater
+    /// plot. Only used if `x` is been omitted.
+    #[serde(rename = "cheaterslope")]
+    cheater_slope: Option<f64>,
+    #[serde(rename = "aaxis")]
+    a_axis: Option<CarpetAxis>,
+    #[serde(rename = "baxis")]
+    b_axis: Option<CarpetAxis>,
+    /// Sets default for all colors associated with this axis all at once:
+    /// line, font, tick, and grid colors.
+    color: Option<Box<dyn Color>>,
+    /// The default font used for axis & tick labels on this carpet.
+    font: Option<Font>,
+    /// Sets a reference between this trace's x coordinates and a 2D cartesian
+    /// x axis. If "x" (the default value), the x coordinates refer to
+    /// `Layout::x_axis`. If "x2", the x coordinates refer to
+    /// `Layout::x_axis2`, and so on.
+    #[serde(rename = "xaxis")]
+    x_axis: Option<String>,
+    /// Sets a reference between this trace's y coordinates and a 2D cartesian
+    /// y axis. If "y" (the default value), the y coordinates refer to
+    /// `Layout::y_axis`. If "y2", the y coordinates refer to
+    /// `Layout::y_axis2`, and so on.
+    #[serde(rename = "yaxis")]
+    y_axis: Option<String>,
+    /// Assigns id labels to each datum. These ids for object constancy of data
+    /// points during animation. Should be an array of strings, not numbers
+    /// or any other type.
+    ids: Option<Vec<String>>,
+    /// Assigns extra meta information associated with this trace that can be
+    /// used in various text attributes. To access the trace `meta` values in
+    /// an attribute in the same trace, simply use `%{meta[i]}` where `i` is
+    /// the index or key of the `meta` item in question.
+    meta: Option<NumOrString>,
+    /// Assigns extra data each datum. This may be useful when listening to
+    /// hover, click and selection events.
+    #[serde(rename = "customdata")]
+    custom_data: Option<NumOrStringCollection>,
+    /// Controls persistence of some user-driven changes to the trace. Defaults
+    /// to `layout.uirevision`.
+    #[serde(rename = "uirevision")]
+    ui_revision: Option<NumOrString>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl<Y> Carpet<f64, Y>
+where
+    Y: Serialize + Clone + 'static,
+{
+    /// Creates a carpet from its `a` and `b` parameter values and the
+    /// corresponding `y` coordinates. The resulting carpet is a cheater plot
+    /// unless `x` coordinates are provided as well.
+    pub fn new(a: Vec<f64>, b: Vec<f64>, y: Vec<Y>) -> Box<Self> {
+        Box::new(Self {
+            a: Some(a),
+            b: Some(b),
+            y: Some(y),
+            ..Default::default()
+        })
+    }
+}
+
+impl<X, Y> Carpet<X, Y>
+where
+    X: Serialize + Clone + 'static,
+    Y: Serialize + Clone + 'static,
+{
+    pub fn new_xy(a: Vec<f64>, b: Vec<f64>, x: Vec<X>, y: Vec<Y>) -> Box<Self> {
+        Box::new(Self {
+            a: Some(a),
+            b: Some(b),
+            x: Some(x),
+            y: Some(y),
+            ..Default::default()
+        })
+    }
+}
+
+impl<X, Y> Trace for Carpet<X, Y>
+where
+    X: Serialize + Clone,
+    Y: Serialize + Clone,
+{
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+
+    #[test]
+    fn test_serialize_cheater_type() {
+        assert_eq!(to_value(CheaterType::Index).unwrap(), json!("index"));
+        assert_eq!(to_value(CheaterType::Value).unwrap(), json!("value"));
+    }
+
+    #[test]
+    fn test_serialize_show_tick_labels() {
+        assert_eq!(to_value(ShowTickLabels::Start).unwrap(), json!("start"));
+        assert_eq!(to_value(ShowTickLabels::End).unwrap(), json!("end"));
+        assert_eq!(to_value(ShowTickLabels::Both).unwrap(), json!("both"));
+        assert_eq!(to_value(ShowTickLabels::None).unwrap(), json!("none"));
+    }
+
+    #[test]
+    fn test_serialize_carpet_axis() {
+        let axis = CarpetAxis::new()
+            .type_(AxisType::Linear)
+            .auto_range(false)
+            .range(vec![0.0, 1.0])
+            .title("a".into())
+            .color("#111111")
+            .smoothing(1.0)
+            .cheater_type(CheaterType::Value)
+            .tick_mode(TickMode::Array)
+            .n_ticks(5)
+            .tick0(0.0)
+            .dtick(0.5)
+            .array_tick0(1)
+            .array_dtick(2)
+            .tick_values(vec![0.0, 0.5])
+            .tick_text(vec!["zero", "half"])
+            .show_tick_labels(ShowTickLabels::Both)
+            .tick_font(Font::new())
+            .tick_format(".1f")
+            .show_tick_prefix(Show::First)
+            .tick_prefix("<")
+            .show_tick_suffix(Show::Last)
+            .tick_suffix(">")
+            .show_exponent(Show::None)
+            .exponent_format(ExponentFormat::Power)
+            .label_padding(10)
+            .label_prefix("a = ")
+            .label_suffix("m")
+            .show_line(true)
+            .line_color("white")
+            .line_width(1.0)
+            .show_grid(true)
+            .grid_color("white")
+            .grid_width(0.5)
+            .minor_grid_count(4)
+            .minor_grid_color("#eeeeee")
+            .minor_grid_width(0.2)
+            .start_line(true)
+            .start_line_color("#2a3f5f")
+            .start_line_width(2.0)
+            .end_line(false)
+            .end_line_color("#2a3f5f")
+            .end_line_width(2.0)
+            .fixed_range(true);
+        let expected = json!({
+            "type": "linear",
+            "autorange": false,
+            "range": [0.0, 1.0],
+            "title": {"text": "a"},
+            "color": "#111111",
+            "smoothing": 1.0,
+            "cheatertype": "value",
+            "tickmode": "array",
+            "nticks": 5,
+            "tick0": 0.0,
+            "dtick": 0.5,
+            "arraytick0": 1,
+            "arraydtick": 2,
+            "tickvals": [0.0, 0.5],
+            "ticktext": ["zero", "half"],
+            "showticklabels": "both",
+            "tickfont": {},
+            "tickformat": ".1f",
+            "showtickprefix": "first",
+            "tickprefix": "<",
+            "showticksuffix": "last",
+            "ticksuffix": ">",
+            "showexponent": "none",
+            "exponentformat": "power",
+            "labelpadding": 10,
+            "labelprefix": "a = ",
+            "labelsuffix": "m",
+            "showline": true,
+            "linecolor": "white",
+            "linewidth": 1.0,
+            "showgrid": true,
+            "gridcolor": "white",
+            "gridwidth": 0.5,
+            "minorgridcount": 4,
+            "minorgridcolor": "#eeeeee",
+            "minorgridwidth": 0.2,
+            "startline": true,
+            "startlinecolor": "#2a3f5f",
+            "startlinewidth": 2.0,
+            "endline": false,
+            "endlinecolor": "#2a3f5f",
+            "endlinewidth": 2.0,
+            "fixedrange": true,
+        });
+
+        assert_eq!(to_value(axis).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_default_carpet() {
+        let trace = Carpet::<f64, f64>::default();
+        let expected = json!({"type": "carpet"});
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_carpet() {
+        let trace = Carpet::new_xy(
+            vec![1.0, 2.0],
+            vec![3.0, 4.0],
+            vec![vec![0.0, 1.0], vec![2.0, 3.0]],
+            vec![vec![4.0, 5.0], vec![6.0, 7.0]],
+        )
+        .name("carpet_trace")
+        .visible(Visible::True)
+        .opacity(0.5)
+        .carpet("c1")
+        .a0(0.0)
+        .da(1.0)
+        .b0(2.0)
+        .db(3.0)
+        .cheater_slope(1.5)
+        .a_axis(CarpetAxis::new())
+        .b_axis(CarpetAxis::new())
+        .color("#444444")
+        .font(Font::new())
+        .x_axis("x2")
+        .y_axis("y2")
+        .ids(vec!["one"])
+        .meta("meta")
+        .custom_data(vec!["custom_data"])
+        .ui_revision(1);
+        let expected = json!({
+            "type": "carpet",
+            "name": "carpet_trace",
+            "visible": true,
+            "opacity": 0.5,
+            "carpet": "c1",
+            "a": [1.0, 2.0],
+            "a0": 0.0,
+            "da": 1.0,
+            "b": [3.0, 4.0],
+            "b0": 2.0,
+            "db": 3.0,
+            "x": [[0.0, 1.0], [2.0, 3.0]],
+            "y": [[4.0, 5.0], [6.0, 7.0]],
+            "cheaterslope": 1.5,
+            "aaxis": {},
+            "baxis": {},
+            "color": "#444444",
+            "font": {},
+            "xaxis": "x2",
+            "yaxis": "y2",
+            "ids": ["one"],
+            "meta": "meta",
+            "customdata": ["custom_data"],
+            "uirevision": 1,
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+}
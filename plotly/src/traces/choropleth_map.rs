@@ -0,0 +1,265 @@
+//! MapLibre choropleth trace
+
+use plotly_derive::FieldSetter;
+use serde::Serialize;
+
+use crate::{
+    common::{ColorBar, ColorScale, Dim, HoverInfo, Label, LegendGroupTitle, PlotType, Visible},
+    private::{NumOrString, NumOrStringCollection},
+    traces::choropleth_mapbox::{GeoJson, Marker},
+    Trace,
+};
+
+/// Construct a choropleth trace on a MapLibre map, shading the GeoJSON features
+/// matching `locations` according to `z`.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::ChoroplethMap;
+///
+/// let trace = ChoroplethMap::new(vec!["NY", "CA"], vec![1.0, 2.0])
+///     .geojson("https://example.com/states.geojson")
+///     .feature_id_key("properties.abbr");
+///
+/// let expected = serde_json::json!({
+///     "type": "choroplethmap",
+///     "locations": ["NY", "CA"],
+///     "z": [1.0, 2.0],
+///     "geojson": "https://example.com/states.geojson",
+///     "featureidkey": "properties.abbr"
+/// });
+///
+/// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+#[field_setter(box_self, kind = "trace")]
+pub struct ChoroplethMap<Z>
+where
+    Z: Serialize + Clone,
+{
+    #[field_setter(default = "PlotType::ChoroplethMap")]
+    r#type: PlotType,
+    /// Sets the trace name. The trace name appear as the legend item and on
+    /// hover.
+    name: Option<String>,
+    /// Determines whether or not this trace is visible. If
+    /// `Visible::LegendOnly`, the trace is not drawn, but can appear as a
+    /// legend item (provided that the legend itself is visible).
+    visible: Option<Visible>,
+    /// Determines whether or not an item corresponding to this trace is shown
+    /// in the legend.
+    #[serde(rename = "showlegend")]
+    show_legend: Option<bool>,
+    /// Sets the legend group for this trace. Traces part of the same legend
+    /// group hide/show at the same time when toggling legend items.
+    #[serde(rename = "legendgroup")]
+    legend_group: Option<String>,
+    /// Set and style the title to appear for the legend group
+    #[serde(rename = "legendgrouptitle")]
+    legend_group_title: Option<LegendGroupTitle>,
+    /// Sets optional GeoJSON data associated with this trace. It must be of
+    /// type "FeatureCollection" or "Feature" with geometries of type "Polygon"
+    /// or "MultiPolygon".
+    #[field_setter(skip)]
+    geojson: Option<GeoJson>,
+    /// Sets which features found in `geojson` to plot using their feature
+    /// `id` field.
+    locations: Option<Vec<String>>,
+    /// Sets the key in GeoJSON features which is used as id to match the items
+    /// included in the `locations` array. Support nested property, for
+    /// example "properties.name".
+    #[serde(rename = "featureidkey")]
+    feature_id_key: Option<String>,
+    /// Sets the color values.
+    z: Option<Vec<Z>>,
+    marker: Option<Marker>,
+    /// Determines if the choropleth polygons will be inserted before the layer
+    /// with the specified ID. By default, choroplethmap traces are placed
+    /// above the water layers. If set to "", the layer will be inserted above
+    /// every existing layer.
+    below: Option<String>,
+    /// Sets a reference between this trace's data coordinates and a map
+    /// subplot. If "map" (the default value), the data refer to
+    /// `layout.map`. If "map2", the data refer to `layout.map2`, and
+    /// so on.
+    subplot: Option<String>,
+    /// Sets the text elements associated with each location.
+    text: Option<Dim<String>>,
+    /// Same as `text`.
+    #[serde(rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    /// Determines which trace information appear on hover. If `HoverInfo::None`
+    /// or `HoverInfo::Skip` are set, no information is displayed upon
+    /// hovering. But, if `HoverInfo::None` is set, click and hover events
+    /// are still fired.
+    #[serde(rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    /// Template string used for rendering the information that appear on hover
+    /// box. Note that this will override `HoverInfo`.
+    #[serde(rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    /// Properties of label displayed on mouse hover.
+    #[serde(rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(rename = "autocolorscale")]
+    auto_color_scale: Option<bool>,
+    #[serde(rename = "colorscale")]
+    color_scale: Option<ColorScale>,
+    #[serde(rename = "reversescale")]
+    reverse_scale: Option<bool>,
+    #[serde(rename = "showscale")]
+    show_scale: Option<bool>,
+    #[serde(rename = "colorbar")]
+    color_bar: Option<ColorBar>,
+    zauto: Option<bool>,
+    zmin: Option<f64>,
+    zmax: Option<f64>,
+    zmid: Option<f64>,
+    /// Sets a reference to a shared color axis. References to these shared
+    /// color axes are "coloraxis", "coloraxis2", "coloraxis3", etc.
+    #[serde(rename = "coloraxis")]
+    color_axis: Option<String>,
+    /// Vector containing integer indices of selected points. Has an effect only
+    /// for traces that support selections.
+    #[serde(rename = "selectedpoints")]
+    selected_points: Option<Vec<usize>>,
+    /// Assigns id labels to each datum. These ids for object constancy of data
+    /// points during animation. Should be an array of strings, not numbers
+    /// or any other type.
+    ids: Option<Vec<String>>,
+    /// Assigns extra meta information associated with this trace that can be
+    /// used in various text attributes. To access the trace `meta` values in
+    /// an attribute in the same trace, simply use `%{meta[i]}` where `i` is
+    /// the index or key of the `meta` item in question.
+    meta: Option<NumOrString>,
+    /// Assigns extra data each datum. This may be useful when listening to
+    /// hover, click and selection events.
+    #[serde(rename = "customdata")]
+    custom_data: Option<NumOrStringCollection>,
+    /// Controls persistence of some user-driven changes to the trace. Defaults
+    /// to `layout.uirevision`.
+    #[serde(rename = "uirevision")]
+    ui_revision: Option<NumOrString>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl<Z> ChoroplethMap<Z>
+where
+    Z: Serialize + Clone + 'static,
+{
+    pub fn new<S: AsRef<str>>(locations: Vec<S>, z: Vec<Z>) -> Box<Self> {
+        Box::new(Self {
+            locations: Some(locations.iter().map(|l| l.as_ref().to_owned()).collect()),
+            z: Some(z),
+            ..Default::default()
+        })
+    }
+
+    /// Sets the GeoJSON data associated with this trace, either as a GeoJSON
+    /// object or as a URL to fetch it from.
+    pub fn geojson<G: Into<GeoJson>>(mut self, geojson: G) -> Box<Self> {
+        self.geojson = Some(geojson.into());
+        Box::new(self)
+    }
+}
+
+impl<Z> Trace for ChoroplethMap<Z>
+where
+    Z: Serialize + Clone,
+{
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+    use crate::common::ColorScalePalette;
+
+    #[test]
+    fn test_serialize_default_choropleth_map() {
+        let trace = ChoroplethMap::<f64>::default();
+        let expected = json!({"type": "choroplethmap"});
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_choropleth_map() {
+        let trace = ChoroplethMap::new(vec!["a", "b"], vec![1, 2])
+            .name("choropleth_map_trace")
+            .visible(Visible::True)
+            .show_legend(false)
+            .legend_group("legend_group")
+            .legend_group_title(LegendGroupTitle::new("Legend Group Title"))
+            .geojson(json!({"type": "FeatureCollection", "features": []}))
+            .feature_id_key("properties.id")
+            .marker(Marker::new())
+            .below("water")
+            .subplot("map2")
+            .text("text")
+            .hover_text_array(vec!["a", "b"])
+            .hover_info(HoverInfo::Z)
+            .hover_template("hover_template")
+            .hover_label(Label::new())
+            .auto_color_scale(false)
+            .color_scale(ColorScale::Palette(ColorScalePalette::Reds))
+            .reverse_scale(true)
+            .show_scale(true)
+            .color_bar(ColorBar::new())
+            .zauto(false)
+            .zmin(0.0)
+            .zmax(2.0)
+            .zmid(1.0)
+            .color_axis("coloraxis")
+            .selected_points(vec![1])
+            .ids(vec!["one", "two"])
+            .meta("meta")
+            .custom_data(vec!["custom_data"])
+            .ui_revision(1);
+        let expected = json!({
+            "type": "choroplethmap",
+            "name": "choropleth_map_trace",
+            "visible": true,
+            "showlegend": false,
+            "legendgroup": "legend_group",
+            "legendgrouptitle": {"text": "Legend Group Title"},
+            "geojson": {"type": "FeatureCollection", "features": []},
+            "locations": ["a", "b"],
+            "featureidkey": "properties.id",
+            "z": [1, 2],
+            "marker": {},
+            "below": "water",
+            "subplot": "map2",
+            "text": "text",
+            "hovertext": ["a", "b"],
+            "hoverinfo": "z",
+            "hovertemplate": "hover_template",
+            "hoverlabel": {},
+            "autocolorscale": false,
+            "colorscale": "Reds",
+            "reversescale": true,
+            "showscale": true,
+            "colorbar": {},
+            "zauto": false,
+            "zmin": 0.0,
+            "zmax": 2.0,
+            "zmid": 1.0,
+            "coloraxis": "coloraxis",
+            "selectedpoints": [1],
+            "ids": ["one", "two"],
+            "meta": "meta",
+            "customdata": ["custom_data"],
+            "uirevision": 1,
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+}
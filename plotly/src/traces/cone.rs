@@ -0,0 +1,319 @@
+//! Cone trace
+
+use plotly_derive::FieldSetter;
+use serde::Serialize;
+
+use crate::{
+    common::{ColorBar, ColorScale, Dim, HoverInfo, Label, LegendGroupTitle, PlotType, Visible},
+    private::{NumOrString, NumOrStringCollection},
+    traces::mesh3d::{LightPosition, Lighting},
+    Trace,
+};
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum SizeMode {
+    Scaled,
+    Absolute,
+    Raw,
+}
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum Anchor {
+    Tip,
+    Tail,
+    #[serde(rename = "cm")]
+    CenterOfMass,
+    Center,
+}
+
+/// Construct a cone trace, drawing a cone at each `(x, y, z)` position
+/// pointing in the direction of the vector field `(u, v, w)`.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::{cone::Anchor, Cone};
+///
+/// let trace = Cone::new(
+///     vec![1.0],
+///     vec![1.0],
+///     vec![1.0],
+///     vec![0.5],
+///     vec![0.0],
+///     vec![-0.5],
+/// )
+/// .anchor(Anchor::Tip);
+///
+/// let expected = serde_json::json!({
+///     "type": "cone",
+///     "x": [1.0],
+///     "y": [1.0],
+///     "z": [1.0],
+///     "u": [0.5],
+///     "v": [0.0],
+///     "w": [-0.5],
+///     "anchor": "tip"
+/// });
+///
+/// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+#[field_setter(box_self, kind = "trace")]
+pub struct Cone {
+    #[field_setter(default = "PlotType::Cone")]
+    r#type: PlotType,
+    /// Sets the trace name. The trace name appear as the legend item and on
+    /// hover.
+    name: Option<String>,
+    /// Determines whether or not this trace is visible. If
+    /// `Visible::LegendOnly`, the trace is not drawn, but can appear as a
+    /// legend item (provided that the legend itself is visible).
+    visible: Option<Visible>,
+    /// Determines whether or not an item corresponding to this trace is shown
+    /// in the legend.
+    #[serde(rename = "showlegend")]
+    show_legend: Option<bool>,
+    /// Sets the legend group for this trace. Traces part of the same legend
+    /// group hide/show at the same time when toggling legend items.
+    #[serde(rename = "legendgroup")]
+    legend_group: Option<String>,
+    /// Set and style the title to appear for the legend group
+    #[serde(rename = "legendgrouptitle")]
+    legend_group_title: Option<LegendGroupTitle>,
+    /// Sets the opacity of the surface.
+    opacity: Option<f64>,
+    /// Sets the x coordinates of the vector field and of the displayed cones.
+    x: Option<Vec<f64>>,
+    /// Sets the y coordinates of the vector field and of the displayed cones.
+    y: Option<Vec<f64>>,
+    /// Sets the z coordinates of the vector field and of the displayed cones.
+    z: Option<Vec<f64>>,
+    /// Sets the x components of the vector field.
+    u: Option<Vec<f64>>,
+    /// Sets the y components of the vector field.
+    v: Option<Vec<f64>>,
+    /// Sets the z components of the vector field.
+    w: Option<Vec<f64>>,
+    /// Determines whether `size_ref` is set as a "scaled" (i.e unitless)
+    /// scalar (normalized by the max u/v/w norm in the vector field) or as
+    /// "absolute" value (in the same units as the vector field). To display
+    /// sizes in actual vector length use "raw".
+    #[serde(rename = "sizemode")]
+    size_mode: Option<SizeMode>,
+    /// Adjusts the cone size scaling. The size of the cones is determined by
+    /// their u/v/w norm multiplied by a factor and `size_ref`.
+    #[serde(rename = "sizeref")]
+    size_ref: Option<f64>,
+    /// Sets the cones' anchor with respect to their x/y/z positions.
+    anchor: Option<Anchor>,
+    lighting: Option<Lighting>,
+    #[serde(rename = "lightposition")]
+    light_position: Option<LightPosition>,
+    /// Sets the text elements associated with the cones.
+    text: Option<Dim<String>>,
+    /// Same as `text`.
+    #[serde(rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    /// Determines which trace information appear on hover. If `HoverInfo::None`
+    /// or `HoverInfo::Skip` are set, no information is displayed upon
+    /// hovering. But, if `HoverInfo::None` is set, click and hover events
+    /// are still fired.
+    #[serde(rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    /// Template string used for rendering the information that appear on hover
+    /// box. Note that this will override `HoverInfo`.
+    #[serde(rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    /// Properties of label displayed on mouse hover.
+    #[serde(rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(rename = "autocolorscale")]
+    auto_color_scale: Option<bool>,
+    #[serde(rename = "colorscale")]
+    color_scale: Option<ColorScale>,
+    #[serde(rename = "reversescale")]
+    reverse_scale: Option<bool>,
+    #[serde(rename = "showscale")]
+    show_scale: Option<bool>,
+    #[serde(rename = "colorbar")]
+    color_bar: Option<ColorBar>,
+    cauto: Option<bool>,
+    cmin: Option<f64>,
+    cmax: Option<f64>,
+    cmid: Option<f64>,
+    /// Sets a reference to a shared color axis. References to these shared
+    /// color axes are "coloraxis", "coloraxis2", "coloraxis3", etc.
+    #[serde(rename = "coloraxis")]
+    color_axis: Option<String>,
+    /// Sets a reference between this trace's 3D coordinate system and a 3D
+    /// scene. If "scene" (the default value), the (x,y,z) coordinates refer
+    /// to `layout.scene`. If "scene2", the (x, y, z) coordinates refer to
+    /// `layout.scene2`, and so on.
+    scene: Option<String>,
+    /// Assigns id labels to each datum. These ids for object constancy of data
+    /// points during animation. Should be an array of strings, not numbers
+    /// or any other type.
+    ids: Option<Vec<String>>,
+    /// Assigns extra meta information associated with this trace that can be
+    /// used in various text attributes. To access the trace `meta` values in
+    /// an attribute in the same trace, simply use `%{meta[i]}` where `i` is
+    /// the index or key of the `meta` item in question.
+    meta: Option<NumOrString>,
+    /// Assigns extra data each datum. This may be useful when listening to
+    /// hover, click and selection events.
+    #[serde(rename = "customdata")]
+    custom_data: Option<NumOrStringCollection>,
+    /// Controls persistence of some user-driven changes to the trace. Defaults
+    /// to `layout.uirevision`.
+    #[serde(rename = "uirevision")]
+    ui_revision: Option<NumOrString>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl Cone {
+    pub fn new(
+        x: Vec<f64>,
+        y: Vec<f64>,
+        z: Vec<f64>,
+        u: Vec<f64>,
+        v: Vec<f64>,
+        w: Vec<f64>,
+    ) -> Box<Self> {
+        Box::new(Self {
+            x: Some(x),
+            y: Some(y),
+            z: Some(z),
+            u: Some(u),
+            v: Some(v),
+            w: Some(w),
+            ..Default::default()
+        })
+    }
+}
+
+impl Trace for Cone {
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+    use crate::common::ColorScalePalette;
+
+    #[test]
+    fn test_serialize_size_mode() {
+        assert_eq!(to_value(SizeMode::Scaled).unwrap(), json!("scaled"));
+        assert_eq!(to_value(SizeMode::Absolute).unwrap(), json!("absolute"));
+        assert_eq!(to_value(SizeMode::Raw).unwrap(), json!("raw"));
+    }
+
+    #[test]
+    fn test_serialize_anchor() {
+        assert_eq!(to_value(Anchor::Tip).unwrap(), json!("tip"));
+        assert_eq!(to_value(Anchor::Tail).unwrap(), json!("tail"));
+        assert_eq!(to_value(Anchor::CenterOfMass).unwrap(), json!("cm"));
+        assert_eq!(to_value(Anchor::Center).unwrap(), json!("center"));
+    }
+
+    #[test]
+    fn test_serialize_default_cone() {
+        let trace = Cone::default();
+        let expected = json!({"type": "cone"});
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_cone() {
+        let trace = Cone::new(
+            vec![0.0],
+            vec![1.0],
+            vec![2.0],
+            vec![3.0],
+            vec![4.0],
+            vec![5.0],
+        )
+        .name("cone_trace")
+        .visible(Visible::True)
+        .show_legend(true)
+        .legend_group("legend_group")
+        .legend_group_title(LegendGroupTitle::new("Legend Group Title"))
+        .opacity(0.5)
+        .size_mode(SizeMode::Absolute)
+        .size_ref(2.0)
+        .anchor(Anchor::Center)
+        .lighting(Lighting::new())
+        .light_position(LightPosition::new())
+        .text_array(vec!["text"])
+        .hover_text("hover_text")
+        .hover_info(HoverInfo::Text)
+        .hover_template("hover_template")
+        .hover_label(Label::new())
+        .auto_color_scale(false)
+        .color_scale(ColorScale::Palette(ColorScalePalette::Blues))
+        .reverse_scale(false)
+        .show_scale(true)
+        .color_bar(ColorBar::new())
+        .cauto(true)
+        .cmin(0.0)
+        .cmax(1.0)
+        .cmid(0.5)
+        .color_axis("coloraxis2")
+        .scene("scene")
+        .ids(vec!["one"])
+        .meta("meta")
+        .custom_data(vec!["custom_data"])
+        .ui_revision("rev");
+        let expected = json!({
+            "type": "cone",
+            "name": "cone_trace",
+            "visible": true,
+            "showlegend": true,
+            "legendgroup": "legend_group",
+            "legendgrouptitle": {"text": "Legend Group Title"},
+            "opacity": 0.5,
+            "x": [0.0],
+            "y": [1.0],
+            "z": [2.0],
+            "u": [3.0],
+            "v": [4.0],
+            "w": [5.0],
+            "sizemode": "absolute",
+            "sizeref": 2.0,
+            "anchor": "center",
+            "lighting": {},
+            "lightposition": {},
+            "text": ["text"],
+            "hovertext": "hover_text",
+            "hoverinfo": "text",
+            "hovertemplate": "hover_template",
+            "hoverlabel": {},
+            "autocolorscale": false,
+            "colorscale": "Blues",
+            "reversescale": false,
+            "showscale": true,
+            "colorbar": {},
+            "cauto": true,
+            "cmin": 0.0,
+            "cmax": 1.0,
+            "cmid": 0.5,
+            "coloraxis": "coloraxis2",
+            "scene": "scene",
+            "ids": ["one"],
+            "meta": "meta",
+            "customdata": ["custom_data"],
+            "uirevision": "rev",
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+}
@@ -65,6 +65,9 @@ pub struct Contours {
     label_format: Option<String>,
     operation: Option<Operation>,
     value: Option<f64>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl Contours {
@@ -0,0 +1,284 @@
+//! Contour carpet trace
+
+use plotly_derive::FieldSetter;
+use serde::Serialize;
+
+use crate::{
+    color::Color,
+    common::{ColorBar, ColorScale, LegendGroupTitle, Line, PlotType, Visible},
+    private::{NumOrString, NumOrStringCollection},
+    traces::contour::Contours,
+    Trace,
+};
+
+/// Construct a contour trace plotted on a `Carpet`, with `z` values given at
+/// the carpet's `(a, b)` coordinates.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::{contour::Contours, ContourCarpet};
+///
+/// let trace = ContourCarpet::new(
+///     vec![0.0, 1.0, 0.0, 1.0],
+///     vec![0.0, 0.0, 1.0, 1.0],
+///     vec![1.0, 2.0, 3.0, 4.0],
+/// )
+/// .carpet("c1")
+/// .contours(Contours::new().show_labels(true));
+///
+/// let expected = serde_json::json!({
+///     "type": "contourcarpet",
+///     "a": [0.0, 1.0, 0.0, 1.0],
+///     "b": [0.0, 0.0, 1.0, 1.0],
+///     "z": [1.0, 2.0, 3.0, 4.0],
+///     "carpet": "c1",
+///     "contours": {"showlabels": true}
+/// });
+///
+/// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+#[field_setter(box_self, kind = "trace")]
+pub struct ContourCarpet<Z>
+where
+    Z: Serialize + Clone,
+{
+    #[field_setter(default = "PlotType::ContourCarpet")]
+    r#type: PlotType,
+    /// Sets the trace name. The trace name appear as the legend item and on
+    /// hover.
+    name: Option<String>,
+    /// Determines whether or not this trace is visible. If
+    /// `Visible::LegendOnly`, the trace is not drawn, but can appear as a
+    /// legend item (provided that the legend itself is visible).
+    visible: Option<Visible>,
+    /// Determines whether or not an item corresponding to this trace is shown
+    /// in the legend.
+    #[serde(rename = "showlegend")]
+    show_legend: Option<bool>,
+    /// Sets the legend group for this trace. Traces part of the same legend
+    /// group hide/show at the same time when toggling legend items.
+    #[serde(rename = "legendgroup")]
+    legend_group: Option<String>,
+    /// Set and style the title to appear for the legend group
+    #[serde(rename = "legendgrouptitle")]
+    legend_group_title: Option<LegendGroupTitle>,
+    /// Sets the opacity of the trace.
+    opacity: Option<f64>,
+    /// The `carpet` of the carpet axes on which this contour trace lies.
+    carpet: Option<String>,
+    /// Sets the a-axis coordinates.
+    a: Option<Vec<f64>>,
+    /// Alternate to `a`. Builds a linear space of a coordinates. Use with `da`
+    /// where `a0` is the starting coordinate and `da` the step.
+    a0: Option<f64>,
+    /// Sets the a coordinate step.
+    da: Option<f64>,
+    /// Sets the b-axis coordinates.
+    b: Option<Vec<f64>>,
+    /// Alternate to `b`. Builds a linear space of b coordinates. Use with `db`
+    /// where `b0` is the starting coordinate and `db` the step.
+    b0: Option<f64>,
+    /// Sets the b coordinate step.
+    db: Option<f64>,
+    /// Sets the z data.
+    z: Option<Vec<Z>>,
+    /// Transposes the z data.
+    transpose: Option<bool>,
+    /// Determines whether or not the contour level attributes are picked by an
+    /// algorithm. If `false`, the `contours` start, end and size must be set.
+    #[serde(rename = "autocontour")]
+    auto_contour: Option<bool>,
+    /// Sets the maximum number of contour levels. The actual number of
+    /// contours will be chosen automatically to be less than or equal to the
+    /// value of `n_contours`. Has an effect only if `auto_contour` is `true`.
+    #[serde(rename = "ncontours")]
+    n_contours: Option<usize>,
+    contours: Option<Contours>,
+    line: Option<Line>,
+    /// Sets the fill color if `contours.type` is "constraint". Defaults to a
+    /// half-transparent variant of the line color, marker color, or marker
+    /// line color, whichever is available.
+    #[serde(rename = "fillcolor")]
+    fill_color: Option<Box<dyn Color>>,
+    /// Sets the text elements associated with each z value.
+    text: Option<Vec<String>>,
+    /// Same as `text`.
+    #[serde(rename = "hovertext")]
+    hover_text: Option<Vec<String>>,
+    #[serde(rename = "autocolorscale")]
+    auto_color_scale: Option<bool>,
+    #[serde(rename = "colorscale")]
+    color_scale: Option<ColorScale>,
+    #[serde(rename = "reversescale")]
+    reverse_scale: Option<bool>,
+    #[serde(rename = "showscale")]
+    show_scale: Option<bool>,
+    #[serde(rename = "colorbar")]
+    color_bar: Option<ColorBar>,
+    zauto: Option<bool>,
+    zmin: Option<f64>,
+    zmax: Option<f64>,
+    zmid: Option<f64>,
+    /// Sets a reference to a shared color axis. References to these shared
+    /// color axes are "coloraxis", "coloraxis2", "coloraxis3", etc.
+    #[serde(rename = "coloraxis")]
+    color_axis: Option<String>,
+    /// Sets a reference between this trace's x coordinates and a 2D cartesian
+    /// x axis. If "x" (the default value), the x coordinates refer to
+    /// `Layout::x_axis`. If "x2", the x coordinates refer to
+    /// `Layout::x_axis2`, and so on.
+    #[serde(rename = "xaxis")]
+    x_axis: Option<String>,
+    /// Sets a reference between this trace's y coordinates and a 2D cartesian
+    /// y axis. If "y" (the default value), the y coordinates refer to
+    /// `Layout::y_axis`. If "y2", the y coordinates refer to
+    /// `Layout::y_axis2`, and so on.
+    #[serde(rename = "yaxis")]
+    y_axis: Option<String>,
+    /// Assigns id labels to each datum. These ids for object constancy of data
+    /// points during animation. Should be an array of strings, not numbers
+    /// or any other type.
+    ids: Option<Vec<String>>,
+    /// Assigns extra meta information associated with this trace that can be
+    /// used in various text attributes. To access the trace `meta` values in
+    /// an attribute in the same trace, simply use `%{meta[i]}` where `i` is
+    /// the index or key of the `meta` item in question.
+    meta: Option<NumOrString>,
+    /// Assigns extra data each datum. This may be useful when listening to
+    /// hover, click and selection events.
+    #[serde(rename = "customdata")]
+    custom_data: Option<NumOrStringCollection>,
+    /// Controls persistence of some user-driven changes to the trace. Defaults
+    /// to `layout.uirevision`.
+    #[serde(rename = "uirevision")]
+    ui_revision: Option<NumOrString>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl<Z> ContourCarpet<Z>
+where
+    Z: Serialize + Clone + 'static,
+{
+    pub fn new(a: Vec<f64>, b: Vec<f64>, z: Vec<Z>) -> Box<Self> {
+        Box::new(Self {
+            a: Some(a),
+            b: Some(b),
+            z: Some(z),
+            ..Default::default()
+        })
+    }
+}
+
+impl<Z> Trace for ContourCarpet<Z>
+where
+    Z: Serialize + Clone,
+{
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+    use crate::common::ColorScalePalette;
+
+    #[test]
+    fn test_serialize_default_contour_carpet() {
+        let trace = ContourCarpet::<f64>::default();
+        let expected = json!({"type": "contourcarpet"});
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_contour_carpet() {
+        let trace =
+            ContourCarpet::new(vec![0.0, 1.0], vec![2.0, 3.0], vec![vec![1, 2], vec![3, 4]])
+                .name("contour_carpet_trace")
+                .visible(Visible::True)
+                .show_legend(false)
+                .legend_group("legend_group")
+                .legend_group_title(LegendGroupTitle::new("Legend Group Title"))
+                .opacity(0.7)
+                .carpet("c1")
+                .a0(0.0)
+                .da(1.0)
+                .b0(2.0)
+                .db(1.0)
+                .transpose(true)
+                .auto_contour(false)
+                .n_contours(6)
+                .contours(Contours::new())
+                .line(Line::new())
+                .fill_color("#abcdef")
+                .text(vec!["text"])
+                .hover_text(vec!["hover_text"])
+                .auto_color_scale(false)
+                .color_scale(ColorScale::Palette(ColorScalePalette::Cividis))
+                .reverse_scale(false)
+                .show_scale(true)
+                .color_bar(ColorBar::new())
+                .zauto(false)
+                .zmin(1.0)
+                .zmax(4.0)
+                .zmid(2.5)
+                .color_axis("coloraxis")
+                .x_axis("x")
+                .y_axis("y")
+                .ids(vec!["one"])
+                .meta("meta")
+                .custom_data(vec!["custom_data"])
+                .ui_revision(4);
+        let expected = json!({
+            "type": "contourcarpet",
+            "name": "contour_carpet_trace",
+            "visible": true,
+            "showlegend": false,
+            "legendgroup": "legend_group",
+            "legendgrouptitle": {"text": "Legend Group Title"},
+            "opacity": 0.7,
+            "carpet": "c1",
+            "a": [0.0, 1.0],
+            "a0": 0.0,
+            "da": 1.0,
+            "b": [2.0, 3.0],
+            "b0": 2.0,
+            "db": 1.0,
+            "z": [[1, 2], [3, 4]],
+            "transpose": true,
+            "autocontour": false,
+            "ncontours": 6,
+            "contours": {},
+            "line": {},
+            "fillcolor": "#abcdef",
+            "text": ["text"],
+            "hovertext": ["hover_text"],
+            "autocolorscale": false,
+            "colorscale": "Cividis",
+            "reversescale": false,
+            "showscale": true,
+            "colorbar": {},
+            "zauto": false,
+            "zmin": 1.0,
+            "zmax": 4.0,
+            "zmid": 2.5,
+            "coloraxis": "coloraxis",
+            "xaxis": "x",
+            "yaxis": "y",
+            "ids": ["one"],
+            "meta": "meta",
+            "customdata": ["custom_data"],
+            "uirevision": 4,
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+}
@@ -0,0 +1,252 @@
+//! Mapbox density trace
+
+use plotly_derive::FieldSetter;
+use serde::Serialize;
+
+use crate::{
+    common::{ColorBar, ColorScale, Dim, HoverInfo, Label, LegendGroupTitle, PlotType, Visible},
+    private::{NumOrString, NumOrStringCollection},
+    Trace,
+};
+
+/// Construct a density (heat map) trace on a Mapbox map.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::DensityMapbox;
+///
+/// let trace = DensityMapbox::new(vec![45.5, 46.0], vec![-73.6, -73.5], vec![1.0, 3.0])
+///     .radius(20.0);
+///
+/// let expected = serde_json::json!({
+///     "type": "densitymapbox",
+///     "lat": [45.5, 46.0],
+///     "lon": [-73.6, -73.5],
+///     "z": [1.0, 3.0],
+///     "radius": 20.0
+/// });
+///
+/// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+#[field_setter(box_self, kind = "trace")]
+pub struct DensityMapbox<Lat, Lon, Z>
+where
+    Lat: Serialize + Clone,
+    Lon: Serialize + Clone,
+    Z: Serialize + Clone,
+{
+    #[field_setter(default = "PlotType::DensityMapbox")]
+    r#type: PlotType,
+    /// Sets the trace name. The trace name appear as the legend item and on
+    /// hover.
+    name: Option<String>,
+    /// Determines whether or not this trace is visible. If
+    /// `Visible::LegendOnly`, the trace is not drawn, but can appear as a
+    /// legend item (provided that the legend itself is visible).
+    visible: Option<Visible>,
+    /// Determines whether or not an item corresponding to this trace is shown
+    /// in the legend.
+    #[serde(rename = "showlegend")]
+    show_legend: Option<bool>,
+    /// Sets the legend group for this trace. Traces part of the same legend
+    /// group hide/show at the same time when toggling legend items.
+    #[serde(rename = "legendgroup")]
+    legend_group: Option<String>,
+    /// Set and style the title to appear for the legend group
+    #[serde(rename = "legendgrouptitle")]
+    legend_group_title: Option<LegendGroupTitle>,
+    /// Sets the opacity of the trace.
+    opacity: Option<f64>,
+    /// Sets the latitude coordinates (in degrees North).
+    lat: Option<Vec<Lat>>,
+    /// Sets the longitude coordinates (in degrees East).
+    lon: Option<Vec<Lon>>,
+    /// Sets the points' weight. For example, a value of 10 would be equivalent
+    /// to having 10 points of weight 1 in the same spot.
+    z: Option<Vec<Z>>,
+    /// Sets the radius of influence of one `lon` / `lat` point in pixels.
+    /// Increasing the value makes the density trace smoother, but less
+    /// detailed.
+    radius: Option<Dim<f64>>,
+    /// Determines if the densitymapbox trace will be inserted before the layer
+    /// with the specified ID. By default, densitymapbox traces are placed
+    /// below the first layer of type symbol. If set to "", the layer will be
+    /// inserted above every existing layer.
+    below: Option<String>,
+    /// Sets a reference between this trace's data coordinates and a mapbox
+    /// subplot. If "mapbox" (the default value), the data refer to
+    /// `layout.mapbox`. If "mapbox2", the data refer to `layout.mapbox2`, and
+    /// so on.
+    subplot: Option<String>,
+    /// Sets text elements associated with each (lon,lat) pair.
+    text: Option<Dim<String>>,
+    /// Same as `text`.
+    #[serde(rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    /// Determines which trace information appear on hover. If `HoverInfo::None`
+    /// or `HoverInfo::Skip` are set, no information is displayed upon
+    /// hovering. But, if `HoverInfo::None` is set, click and hover events
+    /// are still fired.
+    #[serde(rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    /// Template string used for rendering the information that appear on hover
+    /// box. Note that this will override `HoverInfo`.
+    #[serde(rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    /// Properties of label displayed on mouse hover.
+    #[serde(rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(rename = "autocolorscale")]
+    auto_color_scale: Option<bool>,
+    #[serde(rename = "colorscale")]
+    color_scale: Option<ColorScale>,
+    #[serde(rename = "reversescale")]
+    reverse_scale: Option<bool>,
+    #[serde(rename = "showscale")]
+    show_scale: Option<bool>,
+    #[serde(rename = "colorbar")]
+    color_bar: Option<ColorBar>,
+    zauto: Option<bool>,
+    zmin: Option<f64>,
+    zmax: Option<f64>,
+    zmid: Option<f64>,
+    /// Sets a reference to a shared color axis. References to these shared
+    /// color axes are "coloraxis", "coloraxis2", "coloraxis3", etc.
+    #[serde(rename = "coloraxis")]
+    color_axis: Option<String>,
+    /// Assigns id labels to each datum. These ids for object constancy of data
+    /// points during animation. Should be an array of strings, not numbers
+    /// or any other type.
+    ids: Option<Vec<String>>,
+    /// Assigns extra meta information associated with this trace that can be
+    /// used in various text attributes. To access the trace `meta` values in
+    /// an attribute in the same trace, simply use `%{meta[i]}` where `i` is
+    /// the index or key of the `meta` item in question.
+    meta: Option<NumOrString>,
+    /// Assigns extra data each datum. This may be useful when listening to
+    /// hover, click and selection events.
+    #[serde(rename = "customdata")]
+    custom_data: Option<NumOrStringCollection>,
+    /// Controls persistence of some user-driven changes to the trace. Defaults
+    /// to `layout.uirevision`.
+    #[serde(rename = "uirevision")]
+    ui_revision: Option<NumOrString>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl<Lat, Lon, Z> DensityMapbox<Lat, Lon, Z>
+where
+    Lat: Serialize + Clone + 'static,
+    Lon: Serialize + Clone + 'static,
+    Z: Serialize + Clone + 'static,
+{
+    pub fn new(lat: Vec<Lat>, lon: Vec<Lon>, z: Vec<Z>) -> Box<Self> {
+        Box::new(Self {
+            lat: Some(lat),
+            lon: Some(lon),
+            z: Some(z),
+            ..Default::default()
+        })
+    }
+}
+
+impl<Lat, Lon, Z> Trace for DensityMapbox<Lat, Lon, Z>
+where
+    Lat: Serialize + Clone,
+    Lon: Serialize + Clone,
+    Z: Serialize + Clone,
+{
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+    use crate::common::ColorScalePalette;
+
+    #[test]
+    fn test_serialize_default_density_mapbox() {
+        let trace = DensityMapbox::<f64, f64, f64>::default();
+        let expected = json!({"type": "densitymapbox"});
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_density_mapbox() {
+        let trace = DensityMapbox::new(vec![45.5], vec![-73.5], vec![1])
+            .name("density_mapbox_trace")
+            .visible(Visible::True)
+            .show_legend(true)
+            .legend_group("legend_group")
+            .legend_group_title(LegendGroupTitle::new("Legend Group Title"))
+            .opacity(0.5)
+            .radius_array(vec![10.0])
+            .below("")
+            .subplot("mapbox")
+            .text("text")
+            .hover_text("hover_text")
+            .hover_info(HoverInfo::Text)
+            .hover_template("hover_template")
+            .hover_label(Label::new())
+            .auto_color_scale(true)
+            .color_scale(ColorScale::Palette(ColorScalePalette::Hot))
+            .reverse_scale(false)
+            .show_scale(false)
+            .color_bar(ColorBar::new())
+            .zauto(true)
+            .zmin(0.0)
+            .zmax(1.0)
+            .zmid(0.5)
+            .color_axis("coloraxis2")
+            .ids(vec!["one"])
+            .meta("meta")
+            .custom_data(vec!["custom_data"])
+            .ui_revision("rev");
+        let expected = json!({
+            "type": "densitymapbox",
+            "name": "density_mapbox_trace",
+            "visible": true,
+            "showlegend": true,
+            "legendgroup": "legend_group",
+            "legendgrouptitle": {"text": "Legend Group Title"},
+            "opacity": 0.5,
+            "lat": [45.5],
+            "lon": [-73.5],
+            "z": [1],
+            "radius": [10.0],
+            "below": "",
+            "subplot": "mapbox",
+            "text": "text",
+            "hovertext": "hover_text",
+            "hoverinfo": "text",
+            "hovertemplate": "hover_template",
+            "hoverlabel": {},
+            "autocolorscale": true,
+            "colorscale": "Hot",
+            "reversescale": false,
+            "showscale": false,
+            "colorbar": {},
+            "zauto": true,
+            "zmin": 0.0,
+            "zmax": 1.0,
+            "zmid": 0.5,
+            "coloraxis": "coloraxis2",
+            "ids": ["one"],
+            "meta": "meta",
+            "customdata": ["custom_data"],
+            "uirevision": "rev",
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+}
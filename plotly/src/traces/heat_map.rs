@@ -114,6 +114,9 @@ where
     zmid: Option<Z>,
     zmin: Option<Z>,
     zsmooth: Option<Smoothing>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl<Z> HeatMap<f64, f64, Z>
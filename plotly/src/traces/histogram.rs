@@ -35,6 +35,9 @@ pub struct Cumulative {
     direction: Option<HistDirection>,
     #[serde(rename = "currentbin")]
     current_bin: Option<CurrentBin>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl Cumulative {
@@ -167,6 +170,9 @@ where
     y_bins: Option<Bins>,
     #[serde(rename = "ycalendar")]
     y_calendar: Option<Calendar>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl<H> Histogram<H>
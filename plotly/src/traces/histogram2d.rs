@@ -0,0 +1,524 @@
+//! Two-dimensional histogram traces
+
+use plotly_derive::FieldSetter;
+use serde::Serialize;
+
+use crate::{
+    common::{
+        Calendar, ColorBar, ColorScale, Dim, HoverInfo, Label, LegendGroupTitle, Line, PlotType,
+        Visible,
+    },
+    traces::{
+        contour::Contours,
+        heat_map::Smoothing,
+        histogram::{Bins, HistFunc, HistNorm},
+    },
+    Trace,
+};
+
+/// Construct a two-dimensional histogram trace, rendered as a heat map of
+/// the number of points falling within each bin.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::{histogram::Bins, Histogram2d};
+///
+/// let trace = Histogram2d::new(vec![0.1, 0.5, 0.9], vec![1.0, 1.5, 2.0])
+///     .x_bins(Bins::new(0.0, 1.0, 0.5))
+///     .n_bins_y(4);
+///
+/// let expected = serde_json::json!({
+///     "type": "histogram2d",
+///     "x": [0.1, 0.5, 0.9],
+///     "y": [1.0, 1.5, 2.0],
+///     "xbins": {"start": 0.0, "end": 1.0, "size": 0.5},
+///     "nbinsy": 4
+/// });
+///
+/// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+#[field_setter(box_self, kind = "trace")]
+pub struct Histogram2d<X, Y>
+where
+    X: Serialize + Clone,
+    Y: Serialize + Clone,
+{
+    #[field_setter(default = "PlotType::Histogram2d")]
+    r#type: PlotType,
+    /// Sets the trace name. The trace name appear as the legend item and on
+    /// hover.
+    name: Option<String>,
+    /// Determines whether or not this trace is visible. If
+    /// `Visible::LegendOnly`, the trace is not drawn, but can appear as a
+    /// legend item (provided that the legend itself is visible).
+    visible: Option<Visible>,
+    /// Determines whether or not an item corresponding to this trace is shown
+    /// in the legend.
+    #[serde(rename = "showlegend")]
+    show_legend: Option<bool>,
+    /// Sets the legend group for this trace. Traces part of the same legend
+    /// group hide/show at the same time when toggling legend items.
+    #[serde(rename = "legendgroup")]
+    legend_group: Option<String>,
+    /// Set and style the title to appear for the legend group
+    #[serde(rename = "legendgrouptitle")]
+    legend_group_title: Option<LegendGroupTitle>,
+    /// Sets the opacity of the trace.
+    opacity: Option<f64>,
+    /// Sets the sample data to be binned on the x axis.
+    x: Option<Vec<X>>,
+    /// Sets the sample data to be binned on the y axis.
+    y: Option<Vec<Y>>,
+    /// Sets the aggregation data, used together with `hist_func`.
+    z: Option<Vec<f64>>,
+    /// Sets a reference between this trace's x coordinates and a 2D cartesian
+    /// x axis. If "x" (the default value), the x coordinates refer to
+    /// `Layout::x_axis`. If "x2", the x coordinates refer to
+    /// `Layout::x_axis2`, and so on.
+    #[serde(rename = "xaxis")]
+    x_axis: Option<String>,
+    /// Sets a reference between this trace's y coordinates and a 2D cartesian
+    /// y axis. If "y" (the default value), the y coordinates refer to
+    /// `Layout::y_axis`. If "y2", the y coordinates refer to
+    /// `Layout::y_axis2`, and so on.
+    #[serde(rename = "yaxis")]
+    y_axis: Option<String>,
+    /// Specifies the maximum number of desired bins along the x axis. This
+    /// value will be used in an algorithm that will decide the optimal bin
+    /// size such that the histogram best visualizes the distribution of the
+    /// data. Ignored if `x_bins.size` is provided.
+    #[serde(rename = "nbinsx")]
+    n_bins_x: Option<usize>,
+    /// Specifies the maximum number of desired bins along the y axis. Ignored
+    /// if `y_bins.size` is provided.
+    #[serde(rename = "nbinsy")]
+    n_bins_y: Option<usize>,
+    #[serde(rename = "xbins")]
+    x_bins: Option<Bins>,
+    #[serde(rename = "ybins")]
+    y_bins: Option<Bins>,
+    #[serde(rename = "autobinx")]
+    auto_bin_x: Option<bool>,
+    #[serde(rename = "autobiny")]
+    auto_bin_y: Option<bool>,
+    /// Set the `x_bin_group` and `y_bin_group` from one attribute.
+    #[serde(rename = "bingroup")]
+    bin_group: Option<String>,
+    #[serde(rename = "xbingroup")]
+    x_bin_group: Option<String>,
+    #[serde(rename = "ybingroup")]
+    y_bin_group: Option<String>,
+    /// Specifies the binning function used for this histogram trace.
+    #[serde(rename = "histfunc")]
+    hist_func: Option<HistFunc>,
+    /// Specifies the type of normalization used for this histogram trace.
+    #[serde(rename = "histnorm")]
+    hist_norm: Option<HistNorm>,
+    /// Sets the horizontal gap (in pixels) between bricks.
+    #[serde(rename = "xgap")]
+    x_gap: Option<f64>,
+    /// Sets the vertical gap (in pixels) between bricks.
+    #[serde(rename = "ygap")]
+    y_gap: Option<f64>,
+    /// Picks a smoothing algorithm use to smooth `z` data.
+    zsmooth: Option<Smoothing>,
+    #[serde(rename = "autocolorscale")]
+    auto_color_scale: Option<bool>,
+    #[serde(rename = "colorscale")]
+    color_scale: Option<ColorScale>,
+    #[serde(rename = "reversescale")]
+    reverse_scale: Option<bool>,
+    #[serde(rename = "showscale")]
+    show_scale: Option<bool>,
+    #[serde(rename = "colorbar")]
+    color_bar: Option<ColorBar>,
+    zauto: Option<bool>,
+    zmin: Option<f64>,
+    zmax: Option<f64>,
+    zmid: Option<f64>,
+    /// Sets the hover text formatting rule for `z` using d3 formatting
+    /// mini-languages which are very similar to those in Python.
+    #[serde(rename = "zhoverformat")]
+    zhover_format: Option<String>,
+    /// Determines which trace information appear on hover. If `HoverInfo::None`
+    /// or `HoverInfo::Skip` are set, no information is displayed upon
+    /// hovering. But, if `HoverInfo::None` is set, click and hover events
+    /// are still fired.
+    #[serde(rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    /// Properties of label displayed on mouse hover.
+    #[serde(rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    /// Template string used for rendering the information that appear on hover
+    /// box. Note that this will override `HoverInfo`.
+    #[serde(rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(rename = "xcalendar")]
+    x_calendar: Option<Calendar>,
+    #[serde(rename = "ycalendar")]
+    y_calendar: Option<Calendar>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl<X, Y> Histogram2d<X, Y>
+where
+    X: Serialize + Clone + 'static,
+    Y: Serialize + Clone + 'static,
+{
+    pub fn new(x: Vec<X>, y: Vec<Y>) -> Box<Self> {
+        Box::new(Self {
+            x: Some(x),
+            y: Some(y),
+            ..Default::default()
+        })
+    }
+}
+
+impl<X, Y> Trace for Histogram2d<X, Y>
+where
+    X: Serialize + Clone,
+    Y: Serialize + Clone,
+{
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+/// Construct a two-dimensional histogram contour trace, rendered as contour
+/// lines of the number of points falling within each bin.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::{contour::Contours, Histogram2dContour};
+///
+/// let trace = Histogram2dContour::new(vec![0.1, 0.5, 0.9], vec![1.0, 1.5, 2.0])
+///     .n_contours(10)
+///     .contours(Contours::new().show_labels(true));
+///
+/// let expected = serde_json::json!({
+///     "type": "histogram2dcontour",
+///     "x": [0.1, 0.5, 0.9],
+///     "y": [1.0, 1.5, 2.0],
+///     "ncontours": 10,
+///     "contours": {"showlabels": true}
+/// });
+///
+/// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+#[field_setter(box_self, kind = "trace")]
+pub struct Histogram2dContour<X, Y>
+where
+    X: Serialize + Clone,
+    Y: Serialize + Clone,
+{
+    #[field_setter(default = "PlotType::Histogram2dContour")]
+    r#type: PlotType,
+    /// Sets the trace name. The trace name appear as the legend item and on
+    /// hover.
+    name: Option<String>,
+    /// Determines whether or not this trace is visible. If
+    /// `Visible::LegendOnly`, the trace is not drawn, but can appear as a
+    /// legend item (provided that the legend itself is visible).
+    visible: Option<Visible>,
+    /// Determines whether or not an item corresponding to this trace is shown
+    /// in the legend.
+    #[serde(rename = "showlegend")]
+    show_legend: Option<bool>,
+    /// Sets the legend group for this trace. Traces part of the same legend
+    /// group hide/show at the same time when toggling legend items.
+    #[serde(rename = "legendgroup")]
+    legend_group: Option<String>,
+    /// Set and style the title to appear for the legend group
+    #[serde(rename = "legendgrouptitle")]
+    legend_group_title: Option<LegendGroupTitle>,
+    /// Sets the opacity of the trace.
+    opacity: Option<f64>,
+    /// Sets the sample data to be binned on the x axis.
+    x: Option<Vec<X>>,
+    /// Sets the sample data to be binned on the y axis.
+    y: Option<Vec<Y>>,
+    /// Sets the aggregation data, used together with `hist_func`.
+    z: Option<Vec<f64>>,
+    /// Sets a reference between this trace's x coordinates and a 2D cartesian
+    /// x axis. If "x" (the default value), the x coordinates refer to
+    /// `Layout::x_axis`. If "x2", the x coordinates refer to
+    /// `Layout::x_axis2`, and so on.
+    #[serde(rename = "xaxis")]
+    x_axis: Option<String>,
+    /// Sets a reference between this trace's y coordinates and a 2D cartesian
+    /// y axis. If "y" (the default value), the y coordinates refer to
+    /// `Layout::y_axis`. If "y2", the y coordinates refer to
+    /// `Layout::y_axis2`, and so on.
+    #[serde(rename = "yaxis")]
+    y_axis: Option<String>,
+    /// Specifies the maximum number of desired bins along the x axis. Ignored
+    /// if `x_bins.size` is provided.
+    #[serde(rename = "nbinsx")]
+    n_bins_x: Option<usize>,
+    /// Specifies the maximum number of desired bins along the y axis. Ignored
+    /// if `y_bins.size` is provided.
+    #[serde(rename = "nbinsy")]
+    n_bins_y: Option<usize>,
+    #[serde(rename = "xbins")]
+    x_bins: Option<Bins>,
+    #[serde(rename = "ybins")]
+    y_bins: Option<Bins>,
+    #[serde(rename = "autobinx")]
+    auto_bin_x: Option<bool>,
+    #[serde(rename = "autobiny")]
+    auto_bin_y: Option<bool>,
+    /// Set the `x_bin_group` and `y_bin_group` from one attribute.
+    #[serde(rename = "bingroup")]
+    bin_group: Option<String>,
+    #[serde(rename = "xbingroup")]
+    x_bin_group: Option<String>,
+    #[serde(rename = "ybingroup")]
+    y_bin_group: Option<String>,
+    /// Specifies the binning function used for this histogram trace.
+    #[serde(rename = "histfunc")]
+    hist_func: Option<HistFunc>,
+    /// Specifies the type of normalization used for this histogram trace.
+    #[serde(rename = "histnorm")]
+    hist_norm: Option<HistNorm>,
+    /// Determines whether or not the contour level attributes are picked by an
+    /// algorithm. If `false`, the `contours` start, end and size must be set.
+    #[serde(rename = "autocontour")]
+    auto_contour: Option<bool>,
+    /// Sets the maximum number of contour levels. The actual number of
+    /// contours will be chosen automatically to be less than or equal to the
+    /// value of `n_contours`. Has an effect only if `auto_contour` is `true`.
+    #[serde(rename = "ncontours")]
+    n_contours: Option<usize>,
+    contours: Option<Contours>,
+    line: Option<Line>,
+    #[serde(rename = "autocolorscale")]
+    auto_color_scale: Option<bool>,
+    #[serde(rename = "colorscale")]
+    color_scale: Option<ColorScale>,
+    #[serde(rename = "reversescale")]
+    reverse_scale: Option<bool>,
+    #[serde(rename = "showscale")]
+    show_scale: Option<bool>,
+    #[serde(rename = "colorbar")]
+    color_bar: Option<ColorBar>,
+    zauto: Option<bool>,
+    zmin: Option<f64>,
+    zmax: Option<f64>,
+    zmid: Option<f64>,
+    /// Sets the hover text formatting rule for `z` using d3 formatting
+    /// mini-languages which are very similar to those in Python.
+    #[serde(rename = "zhoverformat")]
+    zhover_format: Option<String>,
+    /// Determines which trace information appear on hover. If `HoverInfo::None`
+    /// or `HoverInfo::Skip` are set, no information is displayed upon
+    /// hovering. But, if `HoverInfo::None` is set, click and hover events
+    /// are still fired.
+    #[serde(rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    /// Properties of label displayed on mouse hover.
+    #[serde(rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    /// Template string used for rendering the information that appear on hover
+    /// box. Note that this will override `HoverInfo`.
+    #[serde(rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(rename = "xcalendar")]
+    x_calendar: Option<Calendar>,
+    #[serde(rename = "ycalendar")]
+    y_calendar: Option<Calendar>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl<X, Y> Histogram2dContour<X, Y>
+where
+    X: Serialize + Clone + 'static,
+    Y: Serialize + Clone + 'static,
+{
+    pub fn new(x: Vec<X>, y: Vec<Y>) -> Box<Self> {
+        Box::new(Self {
+            x: Some(x),
+            y: Some(y),
+            ..Default::default()
+        })
+    }
+}
+
+impl<X, Y> Trace for Histogram2dContour<X, Y>
+where
+    X: Serialize + Clone,
+    Y: Serialize + Clone,
+{
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+    use crate::common::ColorScalePalette;
+
+    #[test]
+    fn test_serialize_default_histogram2d() {
+        let trace = Histogram2d::<f64, f64>::default();
+        let expected = json!({"type": "histogram2d"});
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_histogram2d() {
+        let trace = Histogram2d::new(vec![0, 1, 2], vec!["a", "b", "c"])
+            .name("histogram2d_trace")
+            .visible(Visible::LegendOnly)
+            .show_legend(true)
+            .legend_group("legend_group")
+            .legend_group_title(LegendGroupTitle::new("Legend Group Title"))
+            .opacity(0.5)
+            .z(vec![1.0, 2.0, 3.0])
+            .x_axis("x2")
+            .y_axis("y2")
+            .n_bins_x(5)
+            .n_bins_y(10)
+            .x_bins(Bins::new(0.0, 2.0, 1.0))
+            .y_bins(Bins::new(1.0, 3.0, 0.5))
+            .auto_bin_x(false)
+            .auto_bin_y(true)
+            .bin_group("bin_group")
+            .x_bin_group("x_bin_group")
+            .y_bin_group("y_bin_group")
+            .hist_func(HistFunc::Sum)
+            .hist_norm(HistNorm::Probability)
+            .x_gap(1.0)
+            .y_gap(2.0)
+            .zsmooth(Smoothing::Best)
+            .auto_color_scale(false)
+            .color_scale(ColorScale::Palette(ColorScalePalette::Viridis))
+            .reverse_scale(true)
+            .show_scale(false)
+            .color_bar(ColorBar::new())
+            .zauto(false)
+            .zmin(0.0)
+            .zmax(10.0)
+            .zmid(5.0)
+            .zhover_format(".2f")
+            .hover_info(HoverInfo::Z)
+            .hover_label(Label::new())
+            .hover_template("hover_template")
+            .x_calendar(Calendar::Coptic)
+            .y_calendar(Calendar::Ethiopian);
+        let expected = json!({
+            "type": "histogram2d",
+            "name": "histogram2d_trace",
+            "visible": "legendonly",
+            "showlegend": true,
+            "legendgroup": "legend_group",
+            "legendgrouptitle": {"text": "Legend Group Title"},
+            "opacity": 0.5,
+            "x": [0, 1, 2],
+            "y": ["a", "b", "c"],
+            "z": [1.0, 2.0, 3.0],
+            "xaxis": "x2",
+            "yaxis": "y2",
+            "nbinsx": 5,
+            "nbinsy": 10,
+            "xbins": {"start": 0.0, "end": 2.0, "size": 1.0},
+            "ybins": {"start": 1.0, "end": 3.0, "size": 0.5},
+            "autobinx": false,
+            "autobiny": true,
+            "bingroup": "bin_group",
+            "xbingroup": "x_bin_group",
+            "ybingroup": "y_bin_group",
+            "histfunc": "sum",
+            "histnorm": "probability",
+            "xgap": 1.0,
+            "ygap": 2.0,
+            "zsmooth": "best",
+            "autocolorscale": false,
+            "colorscale": "Viridis",
+            "reversescale": true,
+            "showscale": false,
+            "colorbar": {},
+            "zauto": false,
+            "zmin": 0.0,
+            "zmax": 10.0,
+            "zmid": 5.0,
+            "zhoverformat": ".2f",
+            "hoverinfo": "z",
+            "hoverlabel": {},
+            "hovertemplate": "hover_template",
+            "xcalendar": "coptic",
+            "ycalendar": "ethiopian",
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_default_histogram2d_contour() {
+        let trace = Histogram2dContour::<f64, f64>::default();
+        let expected = json!({"type": "histogram2dcontour"});
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_histogram2d_contour() {
+        let trace = Histogram2dContour::new(vec![0.0, 1.0], vec![2.0, 3.0])
+            .name("histogram2d_contour_trace")
+            .z(vec![1.0, 2.0])
+            .n_bins_x(20)
+            .n_bins_y(30)
+            .x_bins(Bins::new(0.0, 1.0, 0.1))
+            .y_bins(Bins::new(2.0, 3.0, 0.1))
+            .hist_func(HistFunc::Average)
+            .hist_norm(HistNorm::Density)
+            .auto_contour(false)
+            .n_contours(8)
+            .contours(Contours::new())
+            .line(Line::new())
+            .color_scale(ColorScale::Palette(ColorScalePalette::Hot))
+            .show_scale(true)
+            .zmin(0.0)
+            .zmax(1.0)
+            .hover_info(HoverInfo::All)
+            .x_calendar(Calendar::Gregorian);
+        let expected = json!({
+            "type": "histogram2dcontour",
+            "name": "histogram2d_contour_trace",
+            "x": [0.0, 1.0],
+            "y": [2.0, 3.0],
+            "z": [1.0, 2.0],
+            "nbinsx": 20,
+            "nbinsy": 30,
+            "xbins": {"start": 0.0, "end": 1.0, "size": 0.1},
+            "ybins": {"start": 2.0, "end": 3.0, "size": 0.1},
+            "histfunc": "avg",
+            "histnorm": "density",
+            "autocontour": false,
+            "ncontours": 8,
+            "contours": {},
+            "line": {},
+            "colorscale": "Hot",
+            "showscale": true,
+            "zmin": 0.0,
+            "zmax": 1.0,
+            "hoverinfo": "all",
+            "xcalendar": "gregorian",
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+}
@@ -0,0 +1,666 @@
+//! Indicator trace
+
+use plotly_derive::FieldSetter;
+use serde::Serialize;
+
+use crate::{
+    color::Color,
+    common::{Domain, ExponentFormat, Font, PlotType, TickMode, Ticks, Visible},
+    private::{NumOrString, NumOrStringCollection},
+    Trace,
+};
+
+/// Determines how the value is displayed on the graph. `Number` displays the
+/// value numerically in text, `Delta` displays the difference to a reference
+/// value in text and `Gauge` displays the value graphically on an axis.
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum IndicatorMode {
+    Number,
+    Delta,
+    Gauge,
+    #[serde(rename = "number+delta")]
+    NumberDelta,
+    #[serde(rename = "number+gauge")]
+    NumberGauge,
+    #[serde(rename = "delta+gauge")]
+    DeltaGauge,
+    #[serde(rename = "number+delta+gauge")]
+    NumberDeltaGauge,
+}
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum Align {
+    Left,
+    Center,
+    Right,
+}
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum DeltaPosition {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum GaugeShape {
+    Angular,
+    Bullet,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct Title {
+    /// Sets the title of this indicator.
+    text: Option<String>,
+    /// Sets the horizontal alignment of the title. It defaults to `center`
+    /// except for bullet charts for which it defaults to right.
+    align: Option<Align>,
+    /// Set the font used to display the title.
+    font: Option<Font>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl From<&str> for Title {
+    fn from(title: &str) -> Self {
+        Title::new(title)
+    }
+}
+
+impl Title {
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: Some(text.to_owned()),
+            ..Default::default()
+        }
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct Number {
+    /// Set the font used to display the main number.
+    font: Option<Font>,
+    /// Sets a prefix appearing before the number.
+    prefix: Option<String>,
+    /// Sets a suffix appearing next to the number.
+    suffix: Option<String>,
+    /// Sets the value formatting rule using d3 formatting mini-languages which
+    /// are very similar to those in Python. For numbers, see: https://github.com/d3/d3-format/tree/v1.4.5#d3-format.
+    #[serde(rename = "valueformat")]
+    value_format: Option<String>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl Number {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+/// Styling of the delta when the value is increasing or decreasing with
+/// respect to the reference.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct DeltaDirection {
+    /// Sets the color for the delta.
+    color: Option<Box<dyn Color>>,
+    /// Sets the symbol to display next to the delta.
+    symbol: Option<String>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl DeltaDirection {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct Delta {
+    /// Sets the reference value to compute the delta. By default, it is set to
+    /// the current value.
+    reference: Option<f64>,
+    /// Show relative change.
+    relative: Option<bool>,
+    /// Sets the position of delta with respect to the number.
+    position: Option<DeltaPosition>,
+    increasing: Option<DeltaDirection>,
+    decreasing: Option<DeltaDirection>,
+    /// Set the font used to display the delta.
+    font: Option<Font>,
+    /// Sets a prefix appearing before the delta.
+    prefix: Option<String>,
+    /// Sets a suffix appearing next to the delta.
+    suffix: Option<String>,
+    /// Sets the value formatting rule using d3 formatting mini-languages which
+    /// are very similar to those in Python. For numbers, see: https://github.com/d3/d3-format/tree/v1.4.5#d3-format.
+    #[serde(rename = "valueformat")]
+    value_format: Option<String>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl Delta {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct Line {
+    /// Sets the color of the line.
+    color: Option<Box<dyn Color>>,
+    /// Sets the width (in px) of the line.
+    width: Option<f64>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl Line {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+/// The bar representing the value on the gauge.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct GaugeBar {
+    /// Sets the background color of the bar.
+    color: Option<Box<dyn Color>>,
+    line: Option<Line>,
+    /// Sets the thickness of the bar as a fraction of the total thickness of
+    /// the gauge.
+    thickness: Option<f64>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl GaugeBar {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct GaugeAxis {
+    /// Sets the range of this axis.
+    range: Option<Vec<f64>>,
+    /// Determines whether or not this axis is visible.
+    visible: Option<bool>,
+    #[serde(rename = "tickmode")]
+    tick_mode: Option<TickMode>,
+    #[serde(rename = "nticks")]
+    n_ticks: Option<usize>,
+    tick0: Option<f64>,
+    dtick: Option<f64>,
+    #[serde(rename = "tickvals")]
+    tick_values: Option<Vec<f64>>,
+    #[serde(rename = "ticktext")]
+    tick_text: Option<Vec<String>>,
+    ticks: Option<Ticks>,
+    #[serde(rename = "ticklen")]
+    tick_length: Option<usize>,
+    #[serde(rename = "tickwidth")]
+    tick_width: Option<usize>,
+    #[serde(rename = "tickcolor")]
+    tick_color: Option<Box<dyn Color>>,
+    #[serde(rename = "showticklabels")]
+    show_tick_labels: Option<bool>,
+    #[serde(rename = "tickfont")]
+    tick_font: Option<Font>,
+    #[serde(rename = "tickangle")]
+    tick_angle: Option<f64>,
+    #[serde(rename = "tickformat")]
+    tick_format: Option<String>,
+    #[serde(rename = "tickprefix")]
+    tick_prefix: Option<String>,
+    #[serde(rename = "ticksuffix")]
+    tick_suffix: Option<String>,
+    #[serde(rename = "exponentformat")]
+    exponent_format: Option<ExponentFormat>,
+    #[serde(rename = "separatethousands")]
+    separate_thousands: Option<bool>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl GaugeAxis {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+/// A colored range drawn on the gauge, e.g. to mark "good" or "bad" zones.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct GaugeStep {
+    /// Sets the background color of the arc.
+    color: Option<Box<dyn Color>>,
+    line: Option<Line>,
+    name: Option<String>,
+    /// Sets the range of this axis.
+    range: Option<Vec<f64>>,
+    /// Sets the thickness of the bar as a fraction of the total thickness of
+    /// the gauge.
+    thickness: Option<f64>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl GaugeStep {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct Threshold {
+    line: Option<Line>,
+    /// Sets the thickness of the threshold line as a fraction of the thickness
+    /// of the gauge.
+    thickness: Option<f64>,
+    /// Sets a threshold value drawn as a line.
+    value: Option<f64>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl Threshold {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+/// The gauge of the Indicator plot.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct Gauge {
+    /// Set the shape of the gauge.
+    shape: Option<GaugeShape>,
+    bar: Option<GaugeBar>,
+    /// Sets the gauge background color.
+    #[serde(rename = "bgcolor")]
+    background_color: Option<Box<dyn Color>>,
+    /// Sets the color of the border enclosing the gauge.
+    #[serde(rename = "bordercolor")]
+    border_color: Option<Box<dyn Color>>,
+    /// Sets the width (in px) of the border enclosing the gauge.
+    #[serde(rename = "borderwidth")]
+    border_width: Option<f64>,
+    axis: Option<GaugeAxis>,
+    steps: Option<Vec<GaugeStep>>,
+    threshold: Option<Threshold>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl Gauge {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+/// Construct an indicator trace.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::{
+///     common::Domain,
+///     indicator::{Delta, IndicatorMode},
+///     Indicator,
+/// };
+///
+/// let trace = Indicator::new(400.0)
+///     .mode(IndicatorMode::NumberDelta)
+///     .delta(Delta::new().reference(320.0).relative(true))
+///     .domain(Domain::new().row(0).column(1));
+///
+/// let expected = serde_json::json!({
+///     "type": "indicator",
+///     "value": 400.0,
+///     "mode": "number+delta",
+///     "delta": {"reference": 320.0, "relative": true},
+///     "domain": {"row": 0, "column": 1}
+/// });
+///
+/// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+#[field_setter(box_self, kind = "trace")]
+pub struct Indicator {
+    #[field_setter(default = "PlotType::Indicator")]
+    r#type: PlotType,
+    /// Sets the trace name. The trace name appear as the legend item and on
+    /// hover.
+    name: Option<String>,
+    title: Option<Title>,
+    /// Determines whether or not this trace is visible. If
+    /// `Visible::LegendOnly`, the trace is not drawn, but can appear as a
+    /// legend item (provided that the legend itself is visible).
+    visible: Option<Visible>,
+    /// Sets the domain within which the indicator will be drawn. Use
+    /// `Domain::row` and `Domain::column` to place it on a `LayoutGrid`.
+    domain: Option<Domain>,
+    /// Determines how the value is displayed on the graph.
+    mode: Option<IndicatorMode>,
+    /// Sets the number to be displayed.
+    value: Option<f64>,
+    /// Sets the horizontal alignment of the `text` within the box. Note that
+    /// this attribute has no effect if an angular gauge is displayed: in this
+    /// case, it is always centered.
+    align: Option<Align>,
+    number: Option<Number>,
+    delta: Option<Delta>,
+    gauge: Option<Gauge>,
+    /// Assigns id labels to each datum. These ids for object constancy of data
+    /// points during animation. Should be an array of strings, not numbers
+    /// or any other type.
+    ids: Option<Vec<String>>,
+    /// Assigns extra meta information associated with this trace that can be
+    /// used in various text attributes. Attributes such as trace `name`,
+    /// graph, axis and colorbar `title.text`, annotation `text`
+    /// `rangeselector`, `updatemenues` and `sliders` `label` text all support
+    /// `meta`. To access the trace `meta` values in an attribute in the same
+    /// trace, simply use `%{meta[i]}` where `i` is the index or key of the
+    /// `meta` item in question. To access trace `meta` in layout
+    /// attributes, use `%{data[n[.meta[i]}` where `i` is the index or key of
+    /// the `meta` and `n` is the trace index.
+    meta: Option<NumOrString>,
+    /// Assigns extra data each datum. This may be useful when listening to
+    /// hover, click and selection events.
+    #[serde(rename = "customdata")]
+    custom_data: Option<NumOrStringCollection>,
+    /// Controls persistence of some user-driven changes to the trace. Defaults
+    /// to `layout.uirevision`.
+    #[serde(rename = "uirevision")]
+    ui_revision: Option<NumOrString>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl Indicator {
+    pub fn new(value: f64) -> Box<Self> {
+        Box::new(Self {
+            value: Some(value),
+            ..Default::default()
+        })
+    }
+}
+
+impl Trace for Indicator {
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+    use crate::color::NamedColor;
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_serialize_indicator_mode() {
+        assert_eq!(to_value(IndicatorMode::Number).unwrap(), json!("number"));
+        assert_eq!(to_value(IndicatorMode::Delta).unwrap(), json!("delta"));
+        assert_eq!(to_value(IndicatorMode::Gauge).unwrap(), json!("gauge"));
+        assert_eq!(to_value(IndicatorMode::NumberDelta).unwrap(), json!("number+delta"));
+        assert_eq!(to_value(IndicatorMode::NumberGauge).unwrap(), json!("number+gauge"));
+        assert_eq!(to_value(IndicatorMode::DeltaGauge).unwrap(), json!("delta+gauge"));
+        assert_eq!(to_value(IndicatorMode::NumberDeltaGauge).unwrap(), json!("number+delta+gauge"));
+    }
+
+    #[test]
+    fn test_serialize_align() {
+        assert_eq!(to_value(Align::Left).unwrap(), json!("left"));
+        assert_eq!(to_value(Align::Center).unwrap(), json!("center"));
+        assert_eq!(to_value(Align::Right).unwrap(), json!("right"));
+    }
+
+    #[test]
+    fn test_serialize_delta_position() {
+        assert_eq!(to_value(DeltaPosition::Top).unwrap(), json!("top"));
+        assert_eq!(to_value(DeltaPosition::Bottom).unwrap(), json!("bottom"));
+        assert_eq!(to_value(DeltaPosition::Left).unwrap(), json!("left"));
+        assert_eq!(to_value(DeltaPosition::Right).unwrap(), json!("right"));
+    }
+
+    #[test]
+    fn test_serialize_gauge_shape() {
+        assert_eq!(to_value(GaugeShape::Angular).unwrap(), json!("angular"));
+        assert_eq!(to_value(GaugeShape::Bullet).unwrap(), json!("bullet"));
+    }
+
+    #[test]
+    fn test_serialize_title() {
+        let title = Title::new("Speed")
+            .align(Align::Left)
+            .font(Font::new().size(20));
+        let expected = json!({
+            "text": "Speed",
+            "align": "left",
+            "font": {"size": 20},
+        });
+
+        assert_eq!(to_value(title).unwrap(), expected);
+        assert_eq!(
+            to_value(Title::from("Speed")).unwrap(),
+            json!({"text": "Speed"})
+        );
+    }
+
+    #[test]
+    fn test_serialize_number() {
+        let number = Number::new()
+            .font(Font::new())
+            .prefix("$")
+            .suffix("k")
+            .value_format(".2f");
+        let expected = json!({
+            "font": {},
+            "prefix": "$",
+            "suffix": "k",
+            "valueformat": ".2f",
+        });
+
+        assert_eq!(to_value(number).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_delta() {
+        let delta = Delta::new()
+            .reference(100.0)
+            .relative(true)
+            .position(DeltaPosition::Top)
+            .increasing(DeltaDirection::new().color(NamedColor::Green).symbol("+"))
+            .decreasing(DeltaDirection::new().color(NamedColor::Red).symbol("-"))
+            .font(Font::new())
+            .prefix("(")
+            .suffix(")")
+            .value_format(".1%");
+        let expected = json!({
+            "reference": 100.0,
+            "relative": true,
+            "position": "top",
+            "increasing": {"color": "green", "symbol": "+"},
+            "decreasing": {"color": "red", "symbol": "-"},
+            "font": {},
+            "prefix": "(",
+            "suffix": ")",
+            "valueformat": ".1%",
+        });
+
+        assert_eq!(to_value(delta).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_gauge() {
+        let gauge = Gauge::new()
+            .shape(GaugeShape::Bullet)
+            .bar(
+                GaugeBar::new()
+                    .color(NamedColor::DarkBlue)
+                    .line(Line::new().color(NamedColor::Black).width(1.0))
+                    .thickness(0.5),
+            )
+            .background_color(NamedColor::White)
+            .border_color(NamedColor::Gray)
+            .border_width(2.0)
+            .axis(
+                GaugeAxis::new()
+                    .range(vec![0.0, 500.0])
+                    .visible(true)
+                    .tick_mode(TickMode::Array)
+                    .n_ticks(5)
+                    .tick0(0.0)
+                    .dtick(100.0)
+                    .tick_values(vec![0.0, 250.0, 500.0])
+                    .tick_text(vec!["low", "mid", "high"])
+                    .ticks(Ticks::Outside)
+                    .tick_length(5)
+                    .tick_width(1)
+                    .tick_color(NamedColor::Black)
+                    .show_tick_labels(true)
+                    .tick_font(Font::new())
+                    .tick_angle(45.0)
+                    .tick_format(".0f")
+                    .tick_prefix("<")
+                    .tick_suffix(">")
+                    .exponent_format(ExponentFormat::Power)
+                    .separate_thousands(false),
+            )
+            .steps(vec![GaugeStep::new()
+                .color(NamedColor::LightGray)
+                .line(Line::new())
+                .name("low")
+                .range(vec![0.0, 250.0])
+                .thickness(0.75)])
+            .threshold(
+                Threshold::new()
+                    .line(Line::new().color(NamedColor::Red).width(4.0))
+                    .thickness(0.75)
+                    .value(490.0),
+            );
+        let expected = json!({
+            "shape": "bullet",
+            "bar": {
+                "color": "darkblue",
+                "line": {"color": "black", "width": 1.0},
+                "thickness": 0.5,
+            },
+            "bgcolor": "white",
+            "bordercolor": "gray",
+            "borderwidth": 2.0,
+            "axis": {
+                "range": [0.0, 500.0],
+                "visible": true,
+                "tickmode": "array",
+                "nticks": 5,
+                "tick0": 0.0,
+                "dtick": 100.0,
+                "tickvals": [0.0, 250.0, 500.0],
+                "ticktext": ["low", "mid", "high"],
+                "ticks": "outside",
+                "ticklen": 5,
+                "tickwidth": 1,
+                "tickcolor": "black",
+                "showticklabels": true,
+                "tickfont": {},
+                "tickangle": 45.0,
+                "tickformat": ".0f",
+                "tickprefix": "<",
+                "ticksuffix": ">",
+                "exponentformat": "power",
+                "separatethousands": false,
+            },
+            "steps": [{
+                "color": "lightgray",
+                "line": {},
+                "name": "low",
+                "range": [0.0, 250.0],
+                "thickness": 0.75,
+            }],
+            "threshold": {
+                "line": {"color": "red", "width": 4.0},
+                "thickness": 0.75,
+                "value": 490.0,
+            },
+        });
+
+        assert_eq!(to_value(gauge).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_default_indicator() {
+        let trace = Indicator::default();
+        let expected = json!({"type": "indicator"});
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_indicator() {
+        let trace = Indicator::new(270.0)
+            .name("indicator_trace")
+            .title(Title::new("Speed"))
+            .visible(Visible::True)
+            .domain(Domain::new().x(&[0.0, 1.0]).y(&[0.0, 1.0]))
+            .mode(IndicatorMode::NumberDeltaGauge)
+            .align(Align::Center)
+            .number(Number::new())
+            .delta(Delta::new())
+            .gauge(Gauge::new())
+            .ids(vec!["one"])
+            .meta("meta")
+            .custom_data(vec!["custom_data"])
+            .ui_revision(1);
+        let expected = json!({
+            "type": "indicator",
+            "value": 270.0,
+            "name": "indicator_trace",
+            "title": {"text": "Speed"},
+            "visible": true,
+            "domain": {"x": [0.0, 1.0], "y": [0.0, 1.0]},
+            "mode": "number+delta+gauge",
+            "align": "center",
+            "number": {},
+            "delta": {},
+            "gauge": {},
+            "ids": ["one"],
+            "meta": "meta",
+            "customdata": ["custom_data"],
+            "uirevision": 1,
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+}
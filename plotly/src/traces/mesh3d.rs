@@ -34,6 +34,9 @@ pub struct Contour {
     show: Option<bool>,
     /// Sets the width of the contour lines.
     width: Option<usize>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl Contour {
@@ -69,6 +72,9 @@ pub struct Lighting {
     /// degenerate geometry.
     #[serde(rename = "vertexnormalsepsilon")]
     vertex_normals_epsilon: Option<f64>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl Lighting {
@@ -86,6 +92,9 @@ pub struct LightPosition {
     y: Option<Vec<f64>>,
     /// Numeric vector, representing the Z coordinate for each vertex.
     z: Option<Vec<f64>>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl LightPosition {
@@ -377,6 +386,9 @@ where
     /// each trace a `uid` that stays with it as it moves.
     #[serde(rename = "uirevision")]
     ui_revision: Option<NumOrString>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl<X, Y, Z> Mesh3D<X, Y, Z>
@@ -1,34 +1,76 @@
 //! The various supported traces
 
 pub mod bar;
+mod bar_polar;
 pub mod box_plot;
 mod candlestick;
+pub mod carpet;
+mod choropleth_map;
+pub mod choropleth_mapbox;
+pub mod cone;
 pub mod contour;
-mod heat_map;
+mod contour_carpet;
+mod density_map;
+mod density_mapbox;
+pub mod heat_map;
 pub mod histogram;
+pub mod histogram2d;
 pub mod image;
+pub mod indicator;
 pub mod mesh3d;
 mod ohlc;
+pub mod parcats;
+pub mod parcoords;
+mod raw_trace;
 pub mod sankey;
 mod scatter;
 mod scatter3d;
+mod scatter_carpet;
+pub mod scatter_gl;
+mod scatter_map;
 pub mod scatter_mapbox;
 mod scatter_polar;
+mod scatter_smith;
+pub mod splom;
+pub mod streamtube;
 pub mod surface;
+pub mod table;
+pub mod volume;
 
 pub use bar::Bar;
+pub use bar_polar::{BarPolar, RestyleBarPolar};
 pub use box_plot::BoxPlot;
 pub use candlestick::Candlestick;
+pub use carpet::Carpet;
+pub use choropleth_map::{ChoroplethMap, RestyleChoroplethMap};
+pub use choropleth_mapbox::ChoroplethMapbox;
+pub use cone::Cone;
 pub use contour::Contour;
+pub use contour_carpet::{ContourCarpet, RestyleContourCarpet};
+pub use density_map::{DensityMap, RestyleDensityMap};
+pub use density_mapbox::{DensityMapbox, RestyleDensityMapbox};
 pub use heat_map::HeatMap;
 pub use histogram::Histogram;
+pub use histogram2d::{Histogram2d, Histogram2dContour};
+pub use indicator::Indicator;
 pub use mesh3d::Mesh3D;
 pub use ohlc::Ohlc;
+pub use parcats::Parcats;
+pub use parcoords::Parcoords;
+pub use raw_trace::RawTrace;
 pub use sankey::Sankey;
-pub use scatter::Scatter;
-pub use scatter3d::Scatter3D;
+pub use scatter::{RestyleScatter, Scatter};
+pub use scatter3d::{RestyleScatter3D, Scatter3D};
+pub use scatter_carpet::{RestyleScatterCarpet, ScatterCarpet};
+pub use scatter_gl::ScatterGL;
+pub use scatter_map::{RestyleScatterMap, ScatterMap};
 pub use scatter_mapbox::ScatterMapbox;
-pub use scatter_polar::ScatterPolar;
+pub use scatter_polar::{RestyleScatterPolar, ScatterPolar};
+pub use scatter_smith::{RestyleScatterSmith, ScatterSmith};
+pub use splom::Splom;
+pub use streamtube::Streamtube;
 pub use surface::Surface;
+pub use table::Table;
+pub use volume::{Isosurface, Volume};
 
 pub use self::image::Image;
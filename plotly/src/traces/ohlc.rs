@@ -73,6 +73,9 @@ where
     visible: Option<Visible>,
     #[serde(rename = "xcalendar")]
     x_calendar: Option<Calendar>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl<X, O> Ohlc<X, O>
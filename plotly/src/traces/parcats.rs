@@ -0,0 +1,399 @@
+//! Parallel categories trace
+
+use plotly_derive::FieldSetter;
+use serde::Serialize;
+
+use crate::{
+    color::{Color, ColorArray},
+    common::{ColorBar, ColorScale, Dim, Domain, Font, PlotType, Visible},
+    private::{NumOrString, NumOrStringCollection},
+    Trace,
+};
+
+#[derive(Serialize, Clone, Debug)]
+pub enum CategoryOrder {
+    #[serde(rename = "trace")]
+    Trace,
+    #[serde(rename = "category ascending")]
+    CategoryAscending,
+    #[serde(rename = "category descending")]
+    CategoryDescending,
+    #[serde(rename = "array")]
+    Array,
+}
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum Arrangement {
+    Perpendicular,
+    Freeform,
+    Fixed,
+}
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum SortPaths {
+    Forward,
+    Backward,
+}
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum HoverOn {
+    Category,
+    Color,
+    Dimension,
+}
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum LineShape {
+    Linear,
+    Hspline,
+}
+
+/// A single categorical dimension of a parallel categories plot.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct Dimension {
+    /// The shown name of the dimension.
+    label: Option<String>,
+    /// Dimension values. `values[n]` represents the category value of the
+    /// `n`th point in the dataset, therefore the `values` vector for all
+    /// dimensions must be the same (longer vectors will be truncated).
+    values: Option<NumOrStringCollection>,
+    /// Specifies the ordering logic for the categories in the dimension.
+    #[serde(rename = "categoryorder")]
+    category_order: Option<CategoryOrder>,
+    /// Sets the order in which categories in this dimension appear. Only has
+    /// an effect if `category_order` is set to `CategoryOrder::Array`.
+    #[serde(rename = "categoryarray")]
+    category_array: Option<NumOrStringCollection>,
+    /// Sets alternative tick labels for the categories in this dimension. Only
+    /// has an effect if `category_order` is set to `CategoryOrder::Array`.
+    #[serde(rename = "ticktext")]
+    tick_text: Option<Vec<String>>,
+    /// The display index of dimension, from left to right, zero indexed,
+    /// defaults to dimension index.
+    #[serde(rename = "displayindex")]
+    display_index: Option<usize>,
+    /// Shows the dimension when set to `true` (the default). Hides the
+    /// dimension for `false`.
+    visible: Option<bool>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl Dimension {
+    pub fn new<V: Into<NumOrString> + Clone>(label: &str, values: Vec<V>) -> Self {
+        Self {
+            label: Some(label.to_owned()),
+            values: Some(values.into()),
+            ..Default::default()
+        }
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct Line {
+    /// Sets the line color. It accepts either a specific color or an array of
+    /// numbers that are mapped to the colorscale relative to the max and min
+    /// values of the array or relative to `cmin` and `cmax` if set.
+    #[field_setter(skip)]
+    color: Option<Dim<Box<dyn Color>>>,
+    #[serde(rename = "colorscale")]
+    color_scale: Option<ColorScale>,
+    #[serde(rename = "autocolorscale")]
+    auto_color_scale: Option<bool>,
+    #[serde(rename = "reversescale")]
+    reverse_scale: Option<bool>,
+    #[serde(rename = "showscale")]
+    show_scale: Option<bool>,
+    #[serde(rename = "colorbar")]
+    color_bar: Option<ColorBar>,
+    cauto: Option<bool>,
+    cmin: Option<f64>,
+    cmax: Option<f64>,
+    cmid: Option<f64>,
+    /// Sets a reference to a shared color axis, e.g. "coloraxis",
+    /// "coloraxis2".
+    #[serde(rename = "coloraxis")]
+    color_axis: Option<String>,
+    /// Sets the shape of the paths. If `Linear`, paths are composed of straight
+    /// lines. If `Hspline`, paths are composed of horizontal curved splines.
+    shape: Option<LineShape>,
+    /// Template string used for rendering the information that appear on hover
+    /// box. This value here applies when hovering over lines.
+    #[serde(rename = "hovertemplate")]
+    hover_template: Option<String>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl Line {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn color<C: Color>(mut self, color: C) -> Self {
+        self.color = Some(Dim::Scalar(Box::new(color)));
+        self
+    }
+
+    pub fn color_array<C: Color>(mut self, colors: Vec<C>) -> Self {
+        self.color = Some(Dim::Vector(ColorArray(colors).into()));
+        self
+    }
+}
+
+/// Construct a parallel categories trace.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::{parcats::Dimension, Parcats};
+///
+/// let trace = Parcats::new(vec![
+///     Dimension::new("Hair", vec!["Black", "Brown", "Brown"]),
+///     Dimension::new("Eye", vec!["Brown", "Blue", "Brown"]),
+/// ])
+/// .counts_array(vec![2.0, 1.0, 4.0]);
+///
+/// let expected = serde_json::json!({
+///     "type": "parcats",
+///     "dimensions": [
+///         {"label": "Hair", "values": ["Black", "Brown", "Brown"]},
+///         {"label": "Eye", "values": ["Brown", "Blue", "Brown"]}
+///     ],
+///     "counts": [2.0, 1.0, 4.0]
+/// });
+///
+/// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+#[field_setter(box_self, kind = "trace")]
+pub struct Parcats {
+    #[field_setter(default = "PlotType::Parcats")]
+    r#type: PlotType,
+    /// Sets the trace name. The trace name appear as the legend item and on
+    /// hover.
+    name: Option<String>,
+    /// Determines whether or not this trace is visible. If
+    /// `Visible::LegendOnly`, the trace is not drawn, but can appear as a
+    /// legend item (provided that the legend itself is visible).
+    visible: Option<Visible>,
+    /// Sets the domain within which the parallel categories plot will be
+    /// drawn.
+    domain: Option<Domain>,
+    /// The dimensions (variables) of the parallel categories diagram.
+    dimensions: Option<Vec<Dimension>>,
+    line: Option<Line>,
+    /// The number of observations represented by each state. Defaults to 1 so
+    /// that each state represents one observation.
+    counts: Option<Dim<f64>>,
+    /// Sets the drag interaction mode for categories and dimensions.
+    arrangement: Option<Arrangement>,
+    /// Sort paths so that like colors are bundled together within each
+    /// category.
+    #[serde(rename = "bundlecolors")]
+    bundle_colors: Option<bool>,
+    /// Sets the path sorting algorithm.
+    #[serde(rename = "sortpaths")]
+    sort_paths: Option<SortPaths>,
+    /// Sets the hover interaction mode for the parcats diagram.
+    #[serde(rename = "hoveron")]
+    hover_on: Option<HoverOn>,
+    /// Template string used for rendering the information that appear on hover
+    /// box. This value here applies when hovering over dimensions.
+    #[serde(rename = "hovertemplate")]
+    hover_template: Option<String>,
+    /// Sets the font for the `dimension` labels.
+    #[serde(rename = "labelfont")]
+    label_font: Option<Font>,
+    /// Sets the font for the `category` labels.
+    #[serde(rename = "tickfont")]
+    tick_font: Option<Font>,
+    /// Assigns extra meta information associated with this trace that can be
+    /// used in various text attributes. To access the trace `meta` values in
+    /// an attribute in the same trace, simply use `%{meta[i]}` where `i` is
+    /// the index or key of the `meta` item in question.
+    meta: Option<NumOrString>,
+    /// Controls persistence of some user-driven changes to the trace. Defaults
+    /// to `layout.uirevision`.
+    #[serde(rename = "uirevision")]
+    ui_revision: Option<NumOrString>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl Parcats {
+    pub fn new(dimensions: Vec<Dimension>) -> Box<Self> {
+        Box::new(Self {
+            dimensions: Some(dimensions),
+            ..Default::default()
+        })
+    }
+}
+
+impl Trace for Parcats {
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+    use crate::{color::NamedColor, common::ColorScalePalette};
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_serialize_category_order() {
+        assert_eq!(to_value(CategoryOrder::Trace).unwrap(), json!("trace"));
+        assert_eq!(to_value(CategoryOrder::CategoryAscending).unwrap(), json!("category ascending"));
+        assert_eq!(to_value(CategoryOrder::CategoryDescending).unwrap(), json!("category descending"));
+        assert_eq!(to_value(CategoryOrder::Array).unwrap(), json!("array"));
+    }
+
+    #[test]
+    fn test_serialize_arrangement() {
+        assert_eq!(
+            to_value(Arrangement::Perpendicular).unwrap(),
+            json!("perpendicular")
+        );
+        assert_eq!(to_value(Arrangement::Freeform).unwrap(), json!("freeform"));
+        assert_eq!(to_value(Arrangement::Fixed).unwrap(), json!("fixed"));
+    }
+
+    #[test]
+    fn test_serialize_sort_paths() {
+        assert_eq!(to_value(SortPaths::Forward).unwrap(), json!("forward"));
+        assert_eq!(to_value(SortPaths::Backward).unwrap(), json!("backward"));
+    }
+
+    #[test]
+    fn test_serialize_hover_on() {
+        assert_eq!(to_value(HoverOn::Category).unwrap(), json!("category"));
+        assert_eq!(to_value(HoverOn::Color).unwrap(), json!("color"));
+        assert_eq!(to_value(HoverOn::Dimension).unwrap(), json!("dimension"));
+    }
+
+    #[test]
+    fn test_serialize_line_shape() {
+        assert_eq!(to_value(LineShape::Linear).unwrap(), json!("linear"));
+        assert_eq!(to_value(LineShape::Hspline).unwrap(), json!("hspline"));
+    }
+
+    #[test]
+    fn test_serialize_dimension() {
+        let dimension = Dimension::new("dim", vec![1, 2, 1])
+            .category_order(CategoryOrder::Array)
+            .category_array(vec![2, 1])
+            .tick_text(vec!["two", "one"])
+            .display_index(1)
+            .visible(true);
+        let expected = json!({
+            "label": "dim",
+            "values": [1, 2, 1],
+            "categoryorder": "array",
+            "categoryarray": [2, 1],
+            "ticktext": ["two", "one"],
+            "displayindex": 1,
+            "visible": true,
+        });
+
+        assert_eq!(to_value(dimension).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_line() {
+        let line = Line::new()
+            .color_array(vec![0.0, 1.0, 0.0])
+            .color_scale(ColorScale::Palette(ColorScalePalette::Bluered))
+            .auto_color_scale(false)
+            .reverse_scale(false)
+            .show_scale(true)
+            .color_bar(ColorBar::new())
+            .cauto(true)
+            .cmin(0.0)
+            .cmax(1.0)
+            .cmid(0.5)
+            .color_axis("coloraxis2")
+            .shape(LineShape::Hspline)
+            .hover_template("%{count}");
+        let expected = json!({
+            "color": [0.0, 1.0, 0.0],
+            "colorscale": "Bluered",
+            "autocolorscale": false,
+            "reversescale": false,
+            "showscale": true,
+            "colorbar": {},
+            "cauto": true,
+            "cmin": 0.0,
+            "cmax": 1.0,
+            "cmid": 0.5,
+            "coloraxis": "coloraxis2",
+            "shape": "hspline",
+            "hovertemplate": "%{count}",
+        });
+
+        assert_eq!(to_value(line).unwrap(), expected);
+
+        let line = Line::new().color(NamedColor::Red);
+        assert_eq!(to_value(line).unwrap(), json!({"color": "red"}));
+    }
+
+    #[test]
+    fn test_serialize_default_parcats() {
+        let trace = Parcats::default();
+        let expected = json!({"type": "parcats"});
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_parcats() {
+        let trace = Parcats::new(vec![Dimension::new("a", vec!["x"])])
+            .name("parcats_trace")
+            .visible(Visible::False)
+            .domain(Domain::new())
+            .line(Line::new())
+            .counts(2.0)
+            .arrangement(Arrangement::Freeform)
+            .bundle_colors(true)
+            .sort_paths(SortPaths::Backward)
+            .hover_on(HoverOn::Color)
+            .hover_template("template")
+            .label_font(Font::new())
+            .tick_font(Font::new())
+            .meta("meta")
+            .ui_revision("rev");
+        let expected = json!({
+            "type": "parcats",
+            "dimensions": [{"label": "a", "values": ["x"]}],
+            "name": "parcats_trace",
+            "visible": false,
+            "domain": {},
+            "line": {},
+            "counts": 2.0,
+            "arrangement": "freeform",
+            "bundlecolors": true,
+            "sortpaths": "backward",
+            "hoveron": "color",
+            "hovertemplate": "template",
+            "labelfont": {},
+            "tickfont": {},
+            "meta": "meta",
+            "uirevision": "rev",
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+}
@@ -0,0 +1,394 @@
+//! Parallel coordinates trace
+
+use plotly_derive::FieldSetter;
+use serde::Serialize;
+
+use crate::{
+    color::{Color, ColorArray},
+    common::{ColorBar, ColorScale, Dim, Domain, Font, PlotType, Visible},
+    private::{NumOrString, NumOrStringCollection},
+    Trace,
+};
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum LabelSide {
+    Top,
+    Bottom,
+}
+
+/// A single axis of a parallel coordinates plot.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct Dimension {
+    /// The shown name of the dimension.
+    label: Option<String>,
+    /// Dimension values. `values[n]` represents the value of the `n`th point
+    /// in the dataset, therefore the `values` vector for all dimensions must
+    /// be the same (longer vectors will be truncated).
+    values: Option<Vec<f64>>,
+    /// The domain range that represents the full, shown axis extent. Defaults
+    /// to the `values` extent.
+    range: Option<Vec<f64>>,
+    /// The domain range to which the filter on the dimension is constrained.
+    /// Must be a `[min, max]` pair, or an array of such pairs if
+    /// `multiselect` is enabled.
+    #[serde(rename = "constraintrange")]
+    constraint_range: Option<Dim<Vec<f64>>>,
+    /// Do we allow multiple selection ranges or just a single range?
+    #[serde(rename = "multiselect")]
+    multi_select: Option<bool>,
+    /// Sets the values at which ticks on this axis appear.
+    #[serde(rename = "tickvals")]
+    tick_values: Option<Vec<f64>>,
+    /// Sets the text displayed at the ticks position via `tick_values`.
+    #[serde(rename = "ticktext")]
+    tick_text: Option<Vec<String>>,
+    /// Sets the tick label formatting rule using d3 formatting mini-languages
+    /// which are very similar to those in Python.
+    #[serde(rename = "tickformat")]
+    tick_format: Option<String>,
+    /// Shows the dimension when set to `true` (the default). Hides the
+    /// dimension for `false`.
+    visible: Option<bool>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl Dimension {
+    pub fn new(label: &str, values: Vec<f64>) -> Self {
+        Self {
+            label: Some(label.to_owned()),
+            values: Some(values),
+            ..Default::default()
+        }
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct Line {
+    /// Sets the line color. It accepts either a specific color or an array of
+    /// numbers that are mapped to the colorscale relative to the max and min
+    /// values of the array or relative to `cmin` and `cmax` if set.
+    #[field_setter(skip)]
+    color: Option<Dim<Box<dyn Color>>>,
+    #[serde(rename = "colorscale")]
+    color_scale: Option<ColorScale>,
+    #[serde(rename = "autocolorscale")]
+    auto_color_scale: Option<bool>,
+    #[serde(rename = "reversescale")]
+    reverse_scale: Option<bool>,
+    #[serde(rename = "showscale")]
+    show_scale: Option<bool>,
+    #[serde(rename = "colorbar")]
+    color_bar: Option<ColorBar>,
+    cauto: Option<bool>,
+    cmin: Option<f64>,
+    cmax: Option<f64>,
+    cmid: Option<f64>,
+    /// Sets a reference to a shared color axis, e.g. "coloraxis",
+    /// "coloraxis2".
+    #[serde(rename = "coloraxis")]
+    color_axis: Option<String>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl Line {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn color<C: Color>(mut self, color: C) -> Self {
+        self.color = Some(Dim::Scalar(Box::new(color)));
+        self
+    }
+
+    pub fn color_array<C: Color>(mut self, colors: Vec<C>) -> Self {
+        self.color = Some(Dim::Vector(ColorArray(colors).into()));
+        self
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct UnselectedLine {
+    /// Sets the base color of unselected lines.
+    color: Option<Box<dyn Color>>,
+    /// Sets the opacity of unselected lines.
+    opacity: Option<f64>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl UnselectedLine {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct Unselected {
+    line: Option<UnselectedLine>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl Unselected {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+/// Construct a parallel coordinates trace.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::{
+///     common::ColorScalePalette,
+///     parcoords::{Dimension, Line},
+///     Parcoords,
+/// };
+///
+/// let trace = Parcoords::new(vec![
+///     Dimension::new("lr", vec![0.1, 0.01]).range(vec![0.0, 0.2]),
+///     Dimension::new("loss", vec![0.5, 0.3]),
+/// ])
+/// .line(Line::new().color_array(vec![0.5, 0.3]).color_scale(ColorScalePalette::Viridis.into()));
+///
+/// let expected = serde_json::json!({
+///     "type": "parcoords",
+///     "dimensions": [
+///         {"label": "lr", "values": [0.1, 0.01], "range": [0.0, 0.2]},
+///         {"label": "loss", "values": [0.5, 0.3]}
+///     ],
+///     "line": {"color": [0.5, 0.3], "colorscale": "Viridis"}
+/// });
+///
+/// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+#[field_setter(box_self, kind = "trace")]
+pub struct Parcoords {
+    #[field_setter(default = "PlotType::Parcoords")]
+    r#type: PlotType,
+    /// Sets the trace name. The trace name appear as the legend item and on
+    /// hover.
+    name: Option<String>,
+    /// Determines whether or not this trace is visible. If
+    /// `Visible::LegendOnly`, the trace is not drawn, but can appear as a
+    /// legend item (provided that the legend itself is visible).
+    visible: Option<Visible>,
+    /// Sets the domain within which the parallel coordinates plot will be
+    /// drawn.
+    domain: Option<Domain>,
+    /// The dimensions (variables) of the parallel coordinates chart.
+    dimensions: Option<Vec<Dimension>>,
+    line: Option<Line>,
+    /// Sets the angle of the labels with respect to the horizontal.
+    #[serde(rename = "labelangle")]
+    label_angle: Option<f64>,
+    /// Sets the font for the `dimension` labels.
+    #[serde(rename = "labelfont")]
+    label_font: Option<Font>,
+    /// Specifies the location of the `label`.
+    #[serde(rename = "labelside")]
+    label_side: Option<LabelSide>,
+    /// Sets the font for the `dimension` range values.
+    #[serde(rename = "rangefont")]
+    range_font: Option<Font>,
+    /// Sets the font for the `dimension` tick values.
+    #[serde(rename = "tickfont")]
+    tick_font: Option<Font>,
+    unselected: Option<Unselected>,
+    /// Assigns id labels to each datum. These ids for object constancy of data
+    /// points during animation. Should be an array of strings, not numbers
+    /// or any other type.
+    ids: Option<Vec<String>>,
+    /// Assigns extra meta information associated with this trace that can be
+    /// used in various text attributes. To access the trace `meta` values in
+    /// an attribute in the same trace, simply use `%{meta[i]}` where `i` is
+    /// the index or key of the `meta` item in question.
+    meta: Option<NumOrString>,
+    /// Assigns extra data each datum. This may be useful when listening to
+    /// hover, click and selection events.
+    #[serde(rename = "customdata")]
+    custom_data: Option<NumOrStringCollection>,
+    /// Controls persistence of some user-driven changes to the trace, such as
+    /// `constraintrange`. Defaults to `layout.uirevision`.
+    #[serde(rename = "uirevision")]
+    ui_revision: Option<NumOrString>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl Parcoords {
+    pub fn new(dimensions: Vec<Dimension>) -> Box<Self> {
+        Box::new(Self {
+            dimensions: Some(dimensions),
+            ..Default::default()
+        })
+    }
+}
+
+impl Trace for Parcoords {
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+    use crate::{color::NamedColor, common::ColorScalePalette};
+
+    #[test]
+    fn test_serialize_label_side() {
+        assert_eq!(to_value(LabelSide::Top).unwrap(), json!("top"));
+        assert_eq!(to_value(LabelSide::Bottom).unwrap(), json!("bottom"));
+    }
+
+    #[test]
+    fn test_serialize_dimension() {
+        let dimension = Dimension::new("dim", vec![1.0, 2.0, 3.0])
+            .range(vec![0.0, 4.0])
+            .constraint_range(vec![1.5, 2.5])
+            .multi_select(false)
+            .tick_values(vec![0.0, 2.0, 4.0])
+            .tick_text(vec!["zero", "two", "four"])
+            .tick_format(".1f")
+            .visible(true);
+        let expected = json!({
+            "label": "dim",
+            "values": [1.0, 2.0, 3.0],
+            "range": [0.0, 4.0],
+            "constraintrange": [1.5, 2.5],
+            "multiselect": false,
+            "tickvals": [0.0, 2.0, 4.0],
+            "ticktext": ["zero", "two", "four"],
+            "tickformat": ".1f",
+            "visible": true,
+        });
+
+        assert_eq!(to_value(dimension).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_dimension_multiple_constraint_ranges() {
+        let dimension = Dimension::new("dim", vec![1.0])
+            .multi_select(true)
+            .constraint_range_array(vec![vec![0.0, 1.0], vec![2.0, 3.0]]);
+        let expected = json!({
+            "label": "dim",
+            "values": [1.0],
+            "multiselect": true,
+            "constraintrange": [[0.0, 1.0], [2.0, 3.0]],
+        });
+
+        assert_eq!(to_value(dimension).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_line() {
+        let line = Line::new()
+            .color_array(vec![0.0, 1.0])
+            .color_scale(ColorScale::Palette(ColorScalePalette::Jet))
+            .auto_color_scale(false)
+            .reverse_scale(true)
+            .show_scale(true)
+            .color_bar(ColorBar::new())
+            .cauto(false)
+            .cmin(0.0)
+            .cmax(1.0)
+            .cmid(0.5)
+            .color_axis("coloraxis");
+        let expected = json!({
+            "color": [0.0, 1.0],
+            "colorscale": "Jet",
+            "autocolorscale": false,
+            "reversescale": true,
+            "showscale": true,
+            "colorbar": {},
+            "cauto": false,
+            "cmin": 0.0,
+            "cmax": 1.0,
+            "cmid": 0.5,
+            "coloraxis": "coloraxis",
+        });
+
+        assert_eq!(to_value(line).unwrap(), expected);
+
+        let line = Line::new().color(NamedColor::Blue);
+        assert_eq!(to_value(line).unwrap(), json!({"color": "blue"}));
+    }
+
+    #[test]
+    fn test_serialize_unselected() {
+        let unselected = Unselected::new().line(
+            UnselectedLine::new()
+                .color(NamedColor::LightGray)
+                .opacity(0.2),
+        );
+        let expected = json!({"line": {"color": "lightgray", "opacity": 0.2}});
+
+        assert_eq!(to_value(unselected).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_default_parcoords() {
+        let trace = Parcoords::default();
+        let expected = json!({"type": "parcoords"});
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_parcoords() {
+        let trace = Parcoords::new(vec![Dimension::new("a", vec![0.0])])
+            .name("parcoords_trace")
+            .visible(Visible::True)
+            .domain(Domain::new())
+            .line(Line::new())
+            .label_angle(30.0)
+            .label_font(Font::new())
+            .label_side(LabelSide::Bottom)
+            .range_font(Font::new())
+            .tick_font(Font::new())
+            .unselected(Unselected::new())
+            .ids(vec!["one"])
+            .meta("meta")
+            .custom_data(vec!["custom_data"])
+            .ui_revision(1);
+        let expected = json!({
+            "type": "parcoords",
+            "dimensions": [{"label": "a", "values": [0.0]}],
+            "name": "parcoords_trace",
+            "visible": true,
+            "domain": {},
+            "line": {},
+            "labelangle": 30.0,
+            "labelfont": {},
+            "labelside": "bottom",
+            "rangefont": {},
+            "tickfont": {},
+            "unselected": {},
+            "ids": ["one"],
+            "meta": "meta",
+            "customdata": ["custom_data"],
+            "uirevision": 1,
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+}
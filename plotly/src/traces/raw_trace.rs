@@ -0,0 +1,76 @@
+//! Raw JSON trace
+
+use serde::Serialize;
+
+use crate::Trace;
+
+/// A trace built directly from a JSON value, for trace types or attributes
+/// that are not (yet) supported by this crate. The value is passed to
+/// plotly.js as-is, so it must contain at least a `"type"` key.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::{Plot, RawTrace};
+/// use serde_json::json;
+///
+/// let trace = RawTrace::new(json!({
+///     "type": "scatter",
+///     "x": [1, 2, 3],
+///     "y": [4, 5, 6],
+///     "marker": {"symbol": "star"}
+/// }));
+///
+/// let mut plot = Plot::new();
+/// plot.add_trace(trace);
+/// ```
+#[derive(Serialize, Clone, Debug, PartialEq)]
+#[serde(transparent)]
+pub struct RawTrace(serde_json::Value);
+
+impl RawTrace {
+    pub fn new(value: impl Into<serde_json::Value>) -> Box<Self> {
+        Box::new(Self(value.into()))
+    }
+}
+
+impl From<serde_json::Value> for RawTrace {
+    fn from(value: serde_json::Value) -> Self {
+        Self(value)
+    }
+}
+
+impl Trace for RawTrace {
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+
+    #[test]
+    fn test_serialize_raw_trace() {
+        let value = json!({
+            "type": "scatterternary",
+            "a": [1, 2],
+            "b": [2, 1],
+            "c": [3, 3],
+            "mode": "markers"
+        });
+        let trace = RawTrace::new(value.clone());
+
+        assert_eq!(to_value(&trace).unwrap(), value);
+        assert_eq!(trace.to_json(), value.to_string());
+    }
+
+    #[test]
+    fn test_raw_trace_from_value() {
+        let trace = RawTrace::from(json!({"type": "bar"}));
+
+        assert_eq!(to_value(trace).unwrap(), json!({"type": "bar"}));
+    }
+}
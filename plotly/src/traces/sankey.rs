@@ -343,6 +343,9 @@ where
     /// trace is not drawn, but can appear as a legend item (provided that
     /// the legend itself is visible).
     visible: Option<bool>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl<V> Sankey<V>
@@ -287,6 +287,9 @@ where
     /// Sets the calendar system to use with `y` date data.
     #[serde(rename = "ycalendar")]
     y_calendar: Option<Calendar>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl<X, Y> Scatter<X, Y>
@@ -392,6 +395,16 @@ where
         };
         Box::new(self)
     }
+
+    /// Enables WebGL if the trace has more than `point_count` points, as SVG
+    /// rendering becomes slow for large data sets. This should be called after
+    /// the data has been set. For a trace that is always rendered with WebGL,
+    /// and which only exposes the attributes WebGL supports, see `ScatterGL`.
+    pub fn web_gl_mode_above(self, point_count: usize) -> Box<Self> {
+        let len = self.x.as_ref().map_or(0, Vec::len);
+        let len = len.max(self.y.as_ref().map_or(0, Vec::len));
+        self.web_gl_mode(len > point_count)
+    }
 }
 
 impl<X, Y> Trace for Scatter<X, Y>
@@ -432,6 +445,15 @@ mod tests {
         assert_eq!(to_value(trace).unwrap(), expected);
     }
 
+    #[test]
+    fn test_web_gl_mode_above() {
+        let trace = Scatter::new(vec![0, 1, 2], vec![2, 1, 0]).web_gl_mode_above(3);
+        assert_eq!(to_value(trace).unwrap()["type"], json!("scatter"));
+
+        let trace = Scatter::new(vec![0, 1, 2], vec![2, 1, 0]).web_gl_mode_above(2);
+        assert_eq!(to_value(trace).unwrap()["type"], json!("scattergl"));
+    }
+
     #[test]
     fn test_serialize_scatter() {
         use crate::common::ErrorType;
@@ -530,4 +552,17 @@ mod tests {
 
         assert_eq!(to_value(trace).unwrap(), expected);
     }
+
+    #[test]
+    fn test_serialize_scatter_extra() {
+        let trace = Scatter::new(vec![0, 1], vec![2, 3])
+            .name("name")
+            .extra("zorder", 2)
+            .extra("name", "overridden");
+
+        let value = to_value(trace).unwrap();
+        assert_eq!(value["zorder"], json!(2));
+        assert_eq!(value["name"], json!("overridden"));
+        assert_eq!(value["x"], json!([0, 1]));
+    }
 }
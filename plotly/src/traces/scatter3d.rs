@@ -20,6 +20,9 @@ pub struct ProjectionCoord {
     opacity: Option<f64>,
     scale: Option<f64>,
     show: Option<bool>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl ProjectionCoord {
@@ -34,6 +37,9 @@ pub struct Projection {
     x: Option<ProjectionCoord>,
     y: Option<ProjectionCoord>,
     z: Option<ProjectionCoord>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl Projection {
@@ -267,6 +273,9 @@ where
     /// Sets the calendar system to use with `z` date data.
     #[serde(rename = "zcalendar")]
     z_calendar: Option<Calendar>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl<X, Y, Z> Scatter3D<X, Y, Z>
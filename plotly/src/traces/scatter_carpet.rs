@@ -0,0 +1,250 @@
+//! Scatter carpet trace
+
+use plotly_derive::FieldSetter;
+use serde::Serialize;
+
+use crate::{
+    color::Color,
+    common::{
+        Dim, Fill, Font, HoverInfo, HoverOn, Label, LegendGroupTitle, Line, Marker, Mode, PlotType,
+        Position, Visible,
+    },
+    private::{NumOrString, NumOrStringCollection},
+    Trace,
+};
+
+/// Construct a scatter trace plotted on a `Carpet`, with points given in the
+/// carpet's `(a, b)` coordinates.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::{common::Mode, ScatterCarpet};
+///
+/// let trace = ScatterCarpet::new(vec![4.0, 4.5], vec![1.5, 2.5])
+///     .carpet("c1")
+///     .mode(Mode::LinesMarkers);
+///
+/// let expected = serde_json::json!({
+///     "type": "scattercarpet",
+///     "a": [4.0, 4.5],
+///     "b": [1.5, 2.5],
+///     "carpet": "c1",
+///     "mode": "lines+markers"
+/// });
+///
+/// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+#[field_setter(box_self, kind = "trace")]
+pub struct ScatterCarpet {
+    #[field_setter(default = "PlotType::ScatterCarpet")]
+    r#type: PlotType,
+    /// Sets the trace name. The trace name appear as the legend item and on
+    /// hover.
+    name: Option<String>,
+    /// Determines whether or not this trace is visible. If
+    /// `Visible::LegendOnly`, the trace is not drawn, but can appear as a
+    /// legend item (provided that the legend itself is visible).
+    visible: Option<Visible>,
+    /// Determines whether or not an item corresponding to this trace is shown
+    /// in the legend.
+    #[serde(rename = "showlegend")]
+    show_legend: Option<bool>,
+    /// Sets the legend group for this trace. Traces part of the same legend
+    /// group hide/show at the same time when toggling legend items.
+    #[serde(rename = "legendgroup")]
+    legend_group: Option<String>,
+    /// Set and style the title to appear for the legend group
+    #[serde(rename = "legendgrouptitle")]
+    legend_group_title: Option<LegendGroupTitle>,
+    /// Sets the opacity of the trace.
+    opacity: Option<f64>,
+    /// An identifier for this carpet, so that `ScatterCarpet` and
+    /// `ContourCarpet` traces can specify a carpet plot on which they lie.
+    carpet: Option<String>,
+    /// Sets the a-axis coordinates.
+    a: Option<Vec<f64>>,
+    /// Sets the b-axis coordinates.
+    b: Option<Vec<f64>>,
+    /// Determines the drawing mode for this scatter trace.
+    mode: Option<Mode>,
+    marker: Option<Marker>,
+    line: Option<Line>,
+    /// Sets text elements associated with each (a,b) point.
+    text: Option<Dim<String>>,
+    /// Sets the positions of the `text` elements with respects to the (x,y)
+    /// coordinates.
+    #[serde(rename = "textposition")]
+    text_position: Option<Dim<Position>>,
+    /// Sets the text font.
+    #[serde(rename = "textfont")]
+    text_font: Option<Font>,
+    /// Sets the area to fill with a solid color. Use with `fill_color` if not
+    /// "none".
+    fill: Option<Fill>,
+    /// Sets the fill color. Defaults to a half-transparent variant of the line
+    /// color, marker color, or marker line color, whichever is available.
+    #[serde(rename = "fillcolor")]
+    fill_color: Option<Box<dyn Color>>,
+    /// Determines whether or not gaps (i.e. {nan} or missing values) in the
+    /// provided data arrays are connected.
+    #[serde(rename = "connectgaps")]
+    connect_gaps: Option<bool>,
+    /// Sets hover text elements associated with each (a,b) point.
+    #[serde(rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    /// Determines which trace information appear on hover. If `HoverInfo::None`
+    /// or `HoverInfo::Skip` are set, no information is displayed upon
+    /// hovering. But, if `HoverInfo::None` is set, click and hover events
+    /// are still fired.
+    #[serde(rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    /// Template string used for rendering the information that appear on hover
+    /// box. Note that this will override `HoverInfo`.
+    #[serde(rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    /// Properties of label displayed on mouse hover.
+    #[serde(rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    /// Do the hover effects highlight individual points (markers or line
+    /// points) or do they highlight filled regions?
+    #[serde(rename = "hoveron")]
+    hover_on: Option<HoverOn>,
+    /// Sets a reference between this trace's x coordinates and a 2D cartesian
+    /// x axis. If "x" (the default value), the x coordinates refer to
+    /// `Layout::x_axis`. If "x2", the x coordinates refer to
+    /// `Layout::x_axis2`, and so on.
+    #[serde(rename = "xaxis")]
+    x_axis: Option<String>,
+    /// Sets a reference between this trace's y coordinates and a 2D cartesian
+    /// y axis. If "y" (the default value), the y coordinates refer to
+    /// `Layout::y_axis`. If "y2", the y coordinates refer to
+    /// `Layout::y_axis2`, and so on.
+    #[serde(rename = "yaxis")]
+    y_axis: Option<String>,
+    /// Vector containing integer indices of selected points. Has an effect only
+    /// for traces that support selections.
+    #[serde(rename = "selectedpoints")]
+    selected_points: Option<Vec<usize>>,
+    /// Assigns id labels to each datum. These ids for object constancy of data
+    /// points during animation. Should be an array of strings, not numbers
+    /// or any other type.
+    ids: Option<Vec<String>>,
+    /// Assigns extra meta information associated with this trace that can be
+    /// used in various text attributes. To access the trace `meta` values in
+    /// an attribute in the same trace, simply use `%{meta[i]}` where `i` is
+    /// the index or key of the `meta` item in question.
+    meta: Option<NumOrString>,
+    /// Assigns extra data each datum. This may be useful when listening to
+    /// hover, click and selection events.
+    #[serde(rename = "customdata")]
+    custom_data: Option<NumOrStringCollection>,
+    /// Controls persistence of some user-driven changes to the trace. Defaults
+    /// to `layout.uirevision`.
+    #[serde(rename = "uirevision")]
+    ui_revision: Option<NumOrString>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl ScatterCarpet {
+    pub fn new(a: Vec<f64>, b: Vec<f64>) -> Box<Self> {
+        Box::new(Self {
+            a: Some(a),
+            b: Some(b),
+            ..Default::default()
+        })
+    }
+}
+
+impl Trace for ScatterCarpet {
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+
+    #[test]
+    fn test_serialize_default_scatter_carpet() {
+        let trace = ScatterCarpet::default();
+        let expected = json!({"type": "scattercarpet"});
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_scatter_carpet() {
+        let trace = ScatterCarpet::new(vec![1.0, 2.0], vec![3.0, 4.0])
+            .name("scatter_carpet_trace")
+            .visible(Visible::True)
+            .show_legend(true)
+            .legend_group("legend_group")
+            .legend_group_title(LegendGroupTitle::new("Legend Group Title"))
+            .opacity(0.6)
+            .carpet("c1")
+            .mode(Mode::Markers)
+            .marker(Marker::new())
+            .line(Line::new())
+            .text("text")
+            .text_position(Position::TopCenter)
+            .text_font(Font::new())
+            .fill(Fill::ToSelf)
+            .fill_color("#123456")
+            .connect_gaps(true)
+            .hover_text_array(vec!["a", "b"])
+            .hover_info(HoverInfo::Name)
+            .hover_template("hover_template")
+            .hover_label(Label::new())
+            .hover_on(HoverOn::Fills)
+            .x_axis("x")
+            .y_axis("y")
+            .selected_points(vec![1])
+            .ids(vec!["one", "two"])
+            .meta("meta")
+            .custom_data(vec!["custom_data"])
+            .ui_revision("rev");
+        let expected = json!({
+            "type": "scattercarpet",
+            "name": "scatter_carpet_trace",
+            "visible": true,
+            "showlegend": true,
+            "legendgroup": "legend_group",
+            "legendgrouptitle": {"text": "Legend Group Title"},
+            "opacity": 0.6,
+            "carpet": "c1",
+            "a": [1.0, 2.0],
+            "b": [3.0, 4.0],
+            "mode": "markers",
+            "marker": {},
+            "line": {},
+            "text": "text",
+            "textposition": "top center",
+            "textfont": {},
+            "fill": "toself",
+            "fillcolor": "#123456",
+            "connectgaps": true,
+            "hovertext": ["a", "b"],
+            "hoverinfo": "name",
+            "hovertemplate": "hover_template",
+            "hoverlabel": {},
+            "hoveron": "fills",
+            "xaxis": "x",
+            "yaxis": "y",
+            "selectedpoints": [1],
+            "ids": ["one", "two"],
+            "meta": "meta",
+            "customdata": ["custom_data"],
+            "uirevision": "rev",
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+}
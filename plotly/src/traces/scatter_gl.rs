@@ -0,0 +1,369 @@
+//! WebGL scatter trace
+
+#[cfg(feature = "plotly_ndarray")]
+use ndarray::{Array, Ix1};
+use plotly_derive::FieldSetter;
+use serde::Serialize;
+
+use crate::{
+    color::Color,
+    common::{
+        Calendar, DashType, Dim, ErrorData, Fill, Font, HoverInfo, Label, LegendGroupTitle, Marker,
+        Mode, PlotType, Position, Visible,
+    },
+    private::{NumOrString, NumOrStringCollection},
+    Trace,
+};
+
+/// Line shapes supported by WebGL scatter traces. Unlike `common::LineShape`
+/// there is no spline interpolation.
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum LineShape {
+    Linear,
+    Hv,
+    Vh,
+    Hvh,
+    Vhv,
+}
+
+/// Line display properties of a `ScatterGL` trace.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct Line {
+    /// Sets the line color.
+    color: Option<Box<dyn Color>>,
+    /// Sets the line width (in px).
+    width: Option<f64>,
+    /// Determines the line shape.
+    shape: Option<LineShape>,
+    /// Sets the style of the lines.
+    dash: Option<DashType>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl Line {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+/// Construct a WebGL scatter trace. This renders considerably faster than
+/// `Scatter` for large numbers of points, at the cost of a reduced set of
+/// attributes; e.g. stacking and spline line shapes are not available.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::ScatterGL;
+///
+/// let trace = ScatterGL::new(vec![0, 1, 2], vec![2, 1, 0]);
+///
+/// let expected = serde_json::json!({
+///     "type": "scattergl",
+///     "x": [0, 1, 2],
+///     "y": [2, 1, 0]
+/// });
+///
+/// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+#[field_setter(box_self, kind = "trace")]
+pub struct ScatterGL<X, Y>
+where
+    X: Serialize + Clone + 'static,
+    Y: Serialize + Clone + 'static,
+{
+    #[field_setter(default = "PlotType::ScatterGL")]
+    r#type: PlotType,
+    /// Sets the trace name. The trace name appear as the legend item and on
+    /// hover.
+    name: Option<String>,
+    /// Determines whether or not this trace is visible. If
+    /// `Visible::LegendOnly`, the trace is not drawn, but can appear as a
+    /// legend item (provided that the legend itself is visible).
+    visible: Option<Visible>,
+    /// Determines whether or not an item corresponding to this trace is shown
+    /// in the legend.
+    #[serde(rename = "showlegend")]
+    show_legend: Option<bool>,
+    /// Sets the legend group for this trace. Traces part of the same legend
+    /// group hide/show at the same time when toggling legend items.
+    #[serde(rename = "legendgroup")]
+    legend_group: Option<String>,
+    /// Set and style the title to appear for the legend group
+    #[serde(rename = "legendgrouptitle")]
+    legend_group_title: Option<LegendGroupTitle>,
+    /// Sets the opacity of the trace.
+    opacity: Option<f64>,
+    /// Determines the drawing mode for this scatter trace. If the provided
+    /// `Mode` includes "Text" then the `text` elements appear at the
+    /// coordinates. Otherwise, the `text` elements appear on hover.
+    mode: Option<Mode>,
+    /// Assigns id labels to each datum. These ids for object constancy of data
+    /// points during animation. Should be an array of strings, not numbers
+    /// or any other type.
+    ids: Option<Vec<String>>,
+    x: Option<Vec<X>>,
+    /// Alternate to `x`. Builds a linear space of x coordinates. Use with `dx`
+    /// where `x0` is the starting coordinate and `dx` the step.
+    x0: Option<NumOrString>,
+    /// Sets the x coordinate step. See `x0` for more info.
+    dx: Option<f64>,
+    y: Option<Vec<Y>>,
+    /// Alternate to `y`. Builds a linear space of y coordinates. Use with `dy`
+    /// where `y0` is the starting coordinate and `dy` the step.
+    y0: Option<NumOrString>,
+    /// Sets the y coordinate step. See `y0` for more info.
+    dy: Option<f64>,
+    /// Sets text elements associated with each (x,y) pair. If a single string,
+    /// the same string appears over all the data points. If an array of
+    /// strings, the items are mapped in order to the this trace's (x,y)
+    /// coordinates.
+    text: Option<Dim<String>>,
+    /// Sets the positions of the `text` elements with respects to the (x,y)
+    /// coordinates.
+    #[serde(rename = "textposition")]
+    text_position: Option<Dim<Position>>,
+    /// Template string used for rendering the information text that appear on
+    /// points. Note that this will override `textinfo`.
+    #[serde(rename = "texttemplate")]
+    text_template: Option<Dim<String>>,
+    /// Sets hover text elements associated with each (x,y) pair. To be seen,
+    /// trace `HoverInfo` must contain a "Text" flag.
+    #[serde(rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    /// Determines which trace information appear on hover. If `HoverInfo::None`
+    /// or `HoverInfo::Skip` are set, no information is displayed upon
+    /// hovering. But, if `HoverInfo::None` is set, click and hover events
+    /// are still fired.
+    #[serde(rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    /// Template string used for rendering the information that appear on hover
+    /// box. Note that this will override `HoverInfo`.
+    #[serde(rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    /// Assigns extra meta information associated with this trace that can be
+    /// used in various text attributes. To access the trace `meta` values in
+    /// an attribute in the same trace, simply use `%{meta[i]}` where `i` is
+    /// the index or key of the `meta` item in question.
+    meta: Option<NumOrString>,
+    /// Assigns extra data each datum. This may be useful when listening to
+    /// hover, click and selection events.
+    #[serde(rename = "customdata")]
+    custom_data: Option<NumOrStringCollection>,
+    /// Sets a reference between this trace's x coordinates and a 2D cartesian x
+    /// axis. If "x" ( the default value), the x coordinates refer to
+    /// `Layout::x_axis`. If "x2", the x coordinates
+    /// refer to `Layout::x_axis2`, and so on.
+    #[serde(rename = "xaxis")]
+    x_axis: Option<String>,
+    /// Sets a reference between this trace's y coordinates and a 2D cartesian y
+    /// axis. If "y" (the default value), the y coordinates refer to
+    /// `Layout::y_axis`. If "y2", the y coordinates
+    /// refer to `Layout::y_axis2`, and so on.
+    #[serde(rename = "yaxis")]
+    y_axis: Option<String>,
+    /// Determines how points are displayed and joined.
+    marker: Option<Marker>,
+    /// Line display properties.
+    line: Option<Line>,
+    /// Sets the text font.
+    #[serde(rename = "textfont")]
+    text_font: Option<Font>,
+    /// x-axis error display properties
+    error_x: Option<ErrorData>,
+    /// y-axis error display properties.
+    error_y: Option<ErrorData>,
+    /// Determines whether or not gaps (i.e. {nan} or missing values) in the
+    /// provided data arrays are connected.
+    #[serde(rename = "connectgaps")]
+    connect_gaps: Option<bool>,
+    /// Sets the area to fill with a solid color. "tozerox" and "tozeroy" fill
+    /// to x=0 and y=0 respectively. "tonextx" and "tonexty" fill between
+    /// the endpoints of this trace and the endpoints of the trace before it.
+    /// "toself" connects the endpoints of the trace into a closed shape.
+    /// "tonext" fills the space between two traces if one completely encloses
+    /// the other.
+    fill: Option<Fill>,
+    /// Sets the fill color. Defaults to a half-transparent variant of the line
+    /// color, marker color, or marker line color, whichever is available.
+    #[serde(rename = "fillcolor")]
+    fill_color: Option<Box<dyn Color>>,
+    /// Properties of label displayed on mouse hover.
+    #[serde(rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    /// Vector containing integer indices of selected points. Has an effect only
+    /// for traces that support selections.
+    #[serde(rename = "selectedpoints")]
+    selected_points: Option<Vec<usize>>,
+    /// Sets the calendar system to use with `x` date data.
+    #[serde(rename = "xcalendar")]
+    x_calendar: Option<Calendar>,
+    /// Sets the calendar system to use with `y` date data.
+    #[serde(rename = "ycalendar")]
+    y_calendar: Option<Calendar>,
+    /// Controls persistence of some user-driven changes to the trace. Defaults
+    /// to `layout.uirevision`.
+    #[serde(rename = "uirevision")]
+    ui_revision: Option<NumOrString>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl<X, Y> ScatterGL<X, Y>
+where
+    X: Serialize + Clone + 'static,
+    Y: Serialize + Clone + 'static,
+{
+    pub fn new(x: Vec<X>, y: Vec<Y>) -> Box<Self> {
+        Box::new(Self {
+            x: Some(x),
+            y: Some(y),
+            ..Default::default()
+        })
+    }
+
+    #[cfg(feature = "plotly_ndarray")]
+    pub fn from_array(x: Array<X, Ix1>, y: Array<Y, Ix1>) -> Box<Self> {
+        Box::new(ScatterGL {
+            x: Some(x.to_vec()),
+            y: Some(y.to_vec()),
+            ..Default::default()
+        })
+    }
+}
+
+impl<X, Y> Trace for ScatterGL<X, Y>
+where
+    X: Serialize + Clone + 'static,
+    Y: Serialize + Clone + 'static,
+{
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+
+    #[test]
+    fn test_serialize_line_shape() {
+        assert_eq!(to_value(LineShape::Linear).unwrap(), json!("linear"));
+        assert_eq!(to_value(LineShape::Hv).unwrap(), json!("hv"));
+        assert_eq!(to_value(LineShape::Vh).unwrap(), json!("vh"));
+        assert_eq!(to_value(LineShape::Hvh).unwrap(), json!("hvh"));
+        assert_eq!(to_value(LineShape::Vhv).unwrap(), json!("vhv"));
+    }
+
+    #[test]
+    fn test_serialize_line() {
+        let line = Line::new()
+            .color("#ff0000")
+            .width(2.0)
+            .shape(LineShape::Hv)
+            .dash(DashType::Dot);
+        let expected = json!({"color": "#ff0000", "width": 2.0, "shape": "hv", "dash": "dot"});
+
+        assert_eq!(to_value(line).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_default_scatter_gl() {
+        let trace = ScatterGL::<u32, u32>::default();
+        let expected = json!({"type": "scattergl"});
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_scatter_gl() {
+        use crate::common::ErrorType;
+
+        let trace = ScatterGL::new(vec![0, 1], vec![2, 3])
+            .name("scatter_gl_trace")
+            .visible(Visible::True)
+            .show_legend(false)
+            .legend_group("legend_group")
+            .legend_group_title(LegendGroupTitle::new("Legend Group Title"))
+            .opacity(0.6)
+            .mode(Mode::LinesMarkers)
+            .ids(vec!["1"])
+            .x0(0)
+            .dx(1.0)
+            .y0(2)
+            .dy(4.0)
+            .text_array(vec!["text"])
+            .text_position(Position::MiddleCenter)
+            .text_template("text_template")
+            .hover_text("hover_text")
+            .hover_info(HoverInfo::Name)
+            .hover_template("hover_template")
+            .meta("meta")
+            .custom_data(vec!["custom_data"])
+            .x_axis("x2")
+            .y_axis("y2")
+            .marker(Marker::new())
+            .line(Line::new())
+            .text_font(Font::new())
+            .error_x(ErrorData::new(ErrorType::Percent))
+            .error_y(ErrorData::new(ErrorType::Data))
+            .connect_gaps(true)
+            .fill(Fill::ToZeroY)
+            .fill_color("#789456")
+            .hover_label(Label::new())
+            .selected_points(vec![0])
+            .x_calendar(Calendar::Chinese)
+            .y_calendar(Calendar::Coptic)
+            .ui_revision(1);
+        let expected = json!({
+            "type": "scattergl",
+            "name": "scatter_gl_trace",
+            "visible": true,
+            "showlegend": false,
+            "legendgroup": "legend_group",
+            "legendgrouptitle": {"text": "Legend Group Title"},
+            "opacity": 0.6,
+            "mode": "lines+markers",
+            "ids": ["1"],
+            "x": [0, 1],
+            "x0": 0,
+            "dx": 1.0,
+            "y": [2, 3],
+            "y0": 2,
+            "dy": 4.0,
+            "text": ["text"],
+            "textposition": "middle center",
+            "texttemplate": "text_template",
+            "hovertext": "hover_text",
+            "hoverinfo": "name",
+            "hovertemplate": "hover_template",
+            "meta": "meta",
+            "customdata": ["custom_data"],
+            "xaxis": "x2",
+            "yaxis": "y2",
+            "marker": {},
+            "line": {},
+            "textfont": {},
+            "error_x": {"type": "percent"},
+            "error_y": {"type": "data"},
+            "connectgaps": true,
+            "fill": "tozeroy",
+            "fillcolor": "#789456",
+            "hoverlabel": {},
+            "selectedpoints": [0],
+            "xcalendar": "chinese",
+            "ycalendar": "coptic",
+            "uirevision": 1,
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+}
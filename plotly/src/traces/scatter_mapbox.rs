@@ -257,6 +257,9 @@ where
     /// each trace a `uid` that stays with it as it moves.
     #[serde(rename = "uirevision")]
     ui_revision: Option<NumOrString>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl<Lat, Lon> ScatterMapbox<Lat, Lon>
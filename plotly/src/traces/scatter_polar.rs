@@ -217,6 +217,9 @@ where
     /// "fills", otherwise it is "points".
     #[serde(rename = "hoveron")]
     hover_on: Option<HoverOn>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl<Theta, R> ScatterPolar<Theta, R>
@@ -0,0 +1,301 @@
+//! Smith chart scatter trace
+
+#[cfg(feature = "plotly_num_complex")]
+use num_complex::Complex;
+use plotly_derive::FieldSetter;
+use serde::Serialize;
+
+use crate::{
+    color::Color,
+    common::{
+        Dim, Fill, Font, HoverInfo, HoverOn, Label, LegendGroupTitle, Line, Marker, Mode, PlotType,
+        Position, Visible,
+    },
+    private::{NumOrString, NumOrStringCollection},
+    Trace,
+};
+
+/// Construct a scatter trace on a Smith chart, given the real and imaginary
+/// parts of normalized impedances.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::ScatterSmith;
+///
+/// let trace = ScatterSmith::new(vec![0.5, 1.0, 2.0], vec![0.5, 0.0, -1.0]);
+///
+/// let expected = serde_json::json!({
+///     "type": "scattersmith",
+///     "real": [0.5, 1.0, 2.0],
+///     "imag": [0.5, 0.0, -1.0]
+/// });
+///
+/// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+#[field_setter(box_self, kind = "trace")]
+pub struct ScatterSmith<Re, Im>
+where
+    Re: Serialize + Clone + 'static,
+    Im: Serialize + Clone + 'static,
+{
+    #[field_setter(default = "PlotType::ScatterSmith")]
+    r#type: PlotType,
+    /// Sets the trace name. The trace name appear as the legend item and on
+    /// hover.
+    name: Option<String>,
+    /// Determines whether or not this trace is visible. If
+    /// `Visible::LegendOnly`, the trace is not drawn, but can appear as a
+    /// legend item (provided that the legend itself is visible).
+    visible: Option<Visible>,
+    /// Determines whether or not an item corresponding to this trace is shown
+    /// in the legend.
+    #[serde(rename = "showlegend")]
+    show_legend: Option<bool>,
+    /// Sets the legend group for this trace. Traces part of the same legend
+    /// group hide/show at the same time when toggling legend items.
+    #[serde(rename = "legendgroup")]
+    legend_group: Option<String>,
+    #[serde(rename = "legendgrouptitle")]
+    legend_group_title: Option<LegendGroupTitle>,
+    /// Sets the opacity of the trace.
+    opacity: Option<f64>,
+    /// Determines the drawing mode for this scatter trace. If the provided
+    /// `Mode` includes "Text" then the `text` elements appear at the
+    /// coordinates. Otherwise, the `text` elements appear on hover. If
+    /// there are less than 20 points and the trace is not stacked then the
+    /// default is `Mode::LinesMarkers`, otherwise it is `Mode::Lines`.
+    mode: Option<Mode>,
+    /// Assigns id labels to each datum. These ids for object constancy of data
+    /// points during animation. Should be an array of strings, not numbers
+    /// or any other type.
+    ids: Option<Vec<String>>,
+    /// Sets the real component of the data, in units of normalized impedance
+    /// such that real=1 is on the center of the chart.
+    real: Option<Vec<Re>>,
+    /// Sets the imaginary component of the data, in units of normalized
+    /// impedance such that real=1, imag=0 is the center of the chart.
+    imag: Option<Vec<Im>>,
+    /// Sets a reference between this trace's data coordinates and a smith
+    /// subplot. If "smith" (the default value), the data refer to
+    /// `layout.smith`. If "smith2", the data refer to `layout.smith2`, set
+    /// with `Layout::smith_n`, and so on.
+    subplot: Option<String>,
+    /// Sets text elements associated with each (real,imag) pair. If a single
+    /// string, the same string appears over all the data points. If an array
+    /// of string, the items are mapped in order to the this trace's
+    /// (real,imag) coordinates. If trace `HoverInfo` contains a "text" flag
+    /// and `hover_text` is not set, these elements will be seen in the hover
+    /// labels.
+    text: Option<Dim<String>>,
+    /// Sets the positions of the `text` elements with respects to the
+    /// (real,imag) coordinates.
+    #[serde(rename = "textposition")]
+    text_position: Option<Dim<Position>>,
+    /// Template string used for rendering the information text that appear on
+    /// points. Note that this will override `textinfo`.
+    #[serde(rename = "texttemplate")]
+    text_template: Option<Dim<String>>,
+    /// Sets hover text elements associated with each (real,imag) pair. To be
+    /// seen, trace `HoverInfo` must contain a "Text" flag.
+    #[serde(rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    /// Determines which trace information appear on hover. If `HoverInfo::None`
+    /// or `HoverInfo::Skip` are set, no information is displayed upon
+    /// hovering. But, if `HoverInfo::None` is set, click and hover events
+    /// are still fired.
+    #[serde(rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    /// Template string used for rendering the information that appear on hover
+    /// box. Note that this will override `HoverInfo`.
+    #[serde(rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    /// Assigns extra meta information associated with this trace that can be
+    /// used in various text attributes. To access the trace `meta` values in
+    /// an attribute in the same trace, simply use `%{meta[i]}` where `i` is
+    /// the index or key of the `meta` item in question.
+    meta: Option<NumOrString>,
+    /// Assigns extra data each datum. This may be useful when listening to
+    /// hover, click and selection events.
+    #[serde(rename = "customdata")]
+    custom_data: Option<NumOrStringCollection>,
+    /// Array containing integer indices of selected points. Has an effect only
+    /// for traces that support selections.
+    #[serde(rename = "selectedpoints")]
+    selected_points: Option<Vec<u32>>,
+    /// Determines how points are displayed and joined.
+    marker: Option<Marker>,
+    /// Line display properties.
+    line: Option<Line>,
+    /// Sets the text font.
+    #[serde(rename = "textfont")]
+    text_font: Option<Font>,
+    /// Determines whether or not markers and text nodes are clipped about the
+    /// subplot axes.
+    #[serde(rename = "cliponaxis")]
+    clip_on_axis: Option<bool>,
+    /// Determines whether or not gaps (i.e. {nan} or missing values) in the
+    /// provided data arrays are connected.
+    #[serde(rename = "connectgaps")]
+    connect_gaps: Option<bool>,
+    /// Sets the area to fill with a solid color. Only `Fill::None`,
+    /// `Fill::ToSelf` and `Fill::ToNext` are supported by Smith charts.
+    fill: Option<Fill>,
+    /// Sets the fill color. Defaults to a half-transparent variant of the line
+    /// color, marker color, or marker line color, whichever is available.
+    #[serde(rename = "fillcolor")]
+    fill_color: Option<Box<dyn Color>>,
+    /// Properties of label displayed on mouse hover.
+    #[serde(rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    /// Do the hover effects highlight individual points (markers or line
+    /// points) or do they highlight filled regions? If the fill is "toself"
+    /// or "tonext" and there are no markers or text, then the default is
+    /// "fills", otherwise it is "points".
+    #[serde(rename = "hoveron")]
+    hover_on: Option<HoverOn>,
+    /// Controls persistence of some user-driven changes to the trace. Defaults
+    /// to `layout.uirevision`.
+    #[serde(rename = "uirevision")]
+    ui_revision: Option<NumOrString>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl<Re, Im> ScatterSmith<Re, Im>
+where
+    Re: Serialize + Clone + 'static,
+    Im: Serialize + Clone + 'static,
+{
+    pub fn new(real: Vec<Re>, imag: Vec<Im>) -> Box<Self> {
+        Box::new(Self {
+            real: Some(real),
+            imag: Some(imag),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(feature = "plotly_num_complex")]
+impl<T> ScatterSmith<T, T>
+where
+    T: Serialize + Clone + 'static,
+{
+    /// Construct a `ScatterSmith` trace from complex (normalized) impedances.
+    /// This function requires the `plotly_num_complex` feature.
+    pub fn from_complex(z: Vec<Complex<T>>) -> Box<Self> {
+        let (real, imag) = z.into_iter().map(|c| (c.re, c.im)).unzip();
+        Self::new(real, imag)
+    }
+}
+
+impl<Re, Im> Trace for ScatterSmith<Re, Im>
+where
+    Re: Serialize + Clone + 'static,
+    Im: Serialize + Clone + 'static,
+{
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+
+    #[test]
+    fn test_serialize_default_scatter_smith() {
+        let trace = ScatterSmith::<f64, f64>::default();
+        let expected = json!({"type": "scattersmith"});
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_scatter_smith() {
+        let trace = ScatterSmith::new(vec![0.5, 1.0], vec![1.0, -0.5])
+            .clip_on_axis(true)
+            .connect_gaps(false)
+            .custom_data(vec!["custom_data"])
+            .fill(Fill::ToSelf)
+            .fill_color("#789456")
+            .hover_info(HoverInfo::Name)
+            .hover_label(Label::new())
+            .hover_on(HoverOn::Fills)
+            .hover_template("hover_template")
+            .hover_text_array(vec!["hover_text"])
+            .ids(vec!["1"])
+            .legend_group("legend_group")
+            .legend_group_title(LegendGroupTitle::new("Legend Group Title"))
+            .line(Line::new())
+            .marker(Marker::new())
+            .meta("meta")
+            .mode(Mode::LinesMarkers)
+            .name("scatter_smith_trace")
+            .opacity(0.6)
+            .selected_points(vec![1])
+            .show_legend(false)
+            .subplot("smith2")
+            .text("text")
+            .text_font(Font::new())
+            .text_position(Position::MiddleCenter)
+            .text_template("text_template")
+            .ui_revision(1)
+            .visible(Visible::True);
+
+        let expected = json!({
+            "type": "scattersmith",
+            "real": [0.5, 1.0],
+            "imag": [1.0, -0.5],
+            "cliponaxis": true,
+            "connectgaps": false,
+            "customdata": ["custom_data"],
+            "fill": "toself",
+            "fillcolor": "#789456",
+            "hoverinfo": "name",
+            "hoverlabel": {},
+            "hoveron": "fills",
+            "hovertemplate": "hover_template",
+            "hovertext": ["hover_text"],
+            "ids": ["1"],
+            "legendgroup": "legend_group",
+            "legendgrouptitle": {"text": "Legend Group Title"},
+            "line": {},
+            "marker": {},
+            "meta": "meta",
+            "mode": "lines+markers",
+            "name": "scatter_smith_trace",
+            "opacity": 0.6,
+            "selectedpoints": [1],
+            "showlegend": false,
+            "subplot": "smith2",
+            "text": "text",
+            "textfont": {},
+            "textposition": "middle center",
+            "texttemplate": "text_template",
+            "uirevision": 1,
+            "visible": true
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+
+    #[test]
+    #[cfg(feature = "plotly_num_complex")]
+    fn test_scatter_smith_from_complex() {
+        let trace =
+            ScatterSmith::from_complex(vec![Complex::new(1.0, 0.5), Complex::new(2.0, -1.0)]);
+        let expected = json!({
+            "type": "scattersmith",
+            "real": [1.0, 2.0],
+            "imag": [0.5, -1.0]
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+}
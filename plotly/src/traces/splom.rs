@@ -0,0 +1,346 @@
+//! Scatter plot matrix trace
+
+use plotly_derive::FieldSetter;
+use serde::Serialize;
+
+use crate::{
+    common::{Dim, HoverInfo, Label, LegendGroupTitle, Marker, PlotType, Visible},
+    layout::AxisType,
+    private::{NumOrString, NumOrStringCollection},
+    Trace,
+};
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct DimensionAxis {
+    /// Sets the axis type for this dimension's generated x and y axes. Note
+    /// that the axis `type` values set in layout take precedence over this
+    /// attribute.
+    #[field_setter(skip)]
+    r#type: Option<AxisType>,
+    /// Determines whether or not the x & y axes generated by this dimension
+    /// match. Equivalent to setting the `matches` axis attribute in the layout
+    /// with the correct axis id.
+    matches: Option<bool>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl DimensionAxis {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn type_(mut self, t: AxisType) -> Self {
+        self.r#type = Some(t);
+        self
+    }
+}
+
+/// A single variable of the scatter plot matrix.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct Dimension {
+    /// Sets the label corresponding to this splom dimension.
+    label: Option<String>,
+    /// Sets the dimension values to be plotted.
+    values: Option<NumOrStringCollection>,
+    axis: Option<DimensionAxis>,
+    /// Determines whether or not this dimension is shown on the graph. Note
+    /// that even visible false dimension contribute to the default grid
+    /// generate by this splom trace.
+    visible: Option<bool>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl Dimension {
+    pub fn new<V: Into<NumOrString> + Clone>(label: &str, values: Vec<V>) -> Self {
+        Self {
+            label: Some(label.to_owned()),
+            values: Some(values.into()),
+            ..Default::default()
+        }
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct Diagonal {
+    /// Determines whether or not subplots on the diagonal are displayed.
+    visible: Option<bool>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl Diagonal {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+/// Construct a scatter plot matrix trace.
+///
+/// Each dimension generates a row and a column of subplots; the subplot axes
+/// are `xaxis`, `xaxis2`, ... and `yaxis`, `yaxis2`, ... in dimension order
+/// and can be styled with `Layout::x_axis_n` and `Layout::y_axis_n`.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::Splom;
+///
+/// let trace = Splom::from_columns(vec![
+///     ("sepal length", vec![5.1, 4.9]),
+///     ("sepal width", vec![3.5, 3.0]),
+/// ])
+/// .show_upper_half(false);
+///
+/// let expected = serde_json::json!({
+///     "type": "splom",
+///     "dimensions": [
+///         {"label": "sepal length", "values": [5.1, 4.9]},
+///         {"label": "sepal width", "values": [3.5, 3.0]}
+///     ],
+///     "showupperhalf": false
+/// });
+///
+/// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+#[field_setter(box_self, kind = "trace")]
+pub struct Splom {
+    #[field_setter(default = "PlotType::Splom")]
+    r#type: PlotType,
+    /// Sets the trace name. The trace name appear as the legend item and on
+    /// hover.
+    name: Option<String>,
+    /// Determines whether or not this trace is visible. If
+    /// `Visible::LegendOnly`, the trace is not drawn, but can appear as a
+    /// legend item (provided that the legend itself is visible).
+    visible: Option<Visible>,
+    /// Determines whether or not an item corresponding to this trace is shown
+    /// in the legend.
+    #[serde(rename = "showlegend")]
+    show_legend: Option<bool>,
+    /// Sets the legend group for this trace. Traces part of the same legend
+    /// group hide/show at the same time when toggling legend items.
+    #[serde(rename = "legendgroup")]
+    legend_group: Option<String>,
+    /// Set and style the title to appear for the legend group
+    #[serde(rename = "legendgrouptitle")]
+    legend_group_title: Option<LegendGroupTitle>,
+    /// Sets the opacity of the trace.
+    opacity: Option<f64>,
+    /// The variables of the scatter plot matrix.
+    dimensions: Option<Vec<Dimension>>,
+    diagonal: Option<Diagonal>,
+    /// Determines whether or not subplots on the upper half from the diagonal
+    /// are displayed.
+    #[serde(rename = "showupperhalf")]
+    show_upper_half: Option<bool>,
+    /// Determines whether or not subplots on the lower half from the diagonal
+    /// are displayed.
+    #[serde(rename = "showlowerhalf")]
+    show_lower_half: Option<bool>,
+    /// Sets the list of x axes corresponding to dimensions of this splom
+    /// trace. By default, a splom will match the first N xaxes where N is the
+    /// number of input dimensions.
+    #[serde(rename = "xaxes")]
+    x_axes: Option<Vec<String>>,
+    /// Sets the list of y axes corresponding to dimensions of this splom
+    /// trace. By default, a splom will match the first N yaxes where N is the
+    /// number of input dimensions.
+    #[serde(rename = "yaxes")]
+    y_axes: Option<Vec<String>>,
+    marker: Option<Marker>,
+    /// Sets text elements associated with each (x,y) pair to appear on hover.
+    text: Option<Dim<String>>,
+    /// Same as `text`.
+    #[serde(rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    /// Determines which trace information appear on hover. If `HoverInfo::None`
+    /// or `HoverInfo::Skip` are set, no information is displayed upon
+    /// hovering. But, if `HoverInfo::None` is set, click and hover events
+    /// are still fired.
+    #[serde(rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    /// Template string used for rendering the information that appear on hover
+    /// box. Note that this will override `HoverInfo`.
+    #[serde(rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    /// Properties of label displayed on mouse hover.
+    #[serde(rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    /// Vector containing integer indices of selected points. Has an effect only
+    /// for traces that support selections.
+    #[serde(rename = "selectedpoints")]
+    selected_points: Option<Vec<usize>>,
+    /// Assigns id labels to each datum. These ids for object constancy of data
+    /// points during animation. Should be an array of strings, not numbers
+    /// or any other type.
+    ids: Option<Vec<String>>,
+    /// Assigns extra meta information associated with this trace that can be
+    /// used in various text attributes. To access the trace `meta` values in
+    /// an attribute in the same trace, simply use `%{meta[i]}` where `i` is
+    /// the index or key of the `meta` item in question.
+    meta: Option<NumOrString>,
+    /// Assigns extra data each datum. This may be useful when listening to
+    /// hover, click and selection events.
+    #[serde(rename = "customdata")]
+    custom_data: Option<NumOrStringCollection>,
+    /// Controls persistence of some user-driven changes to the trace. Defaults
+    /// to `layout.uirevision`.
+    #[serde(rename = "uirevision")]
+    ui_revision: Option<NumOrString>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl Splom {
+    pub fn new(dimensions: Vec<Dimension>) -> Box<Self> {
+        Box::new(Self {
+            dimensions: Some(dimensions),
+            ..Default::default()
+        })
+    }
+
+    /// Creates a scatter plot matrix with one dimension per `(label, values)`
+    /// column.
+    pub fn from_columns<S, V>(columns: Vec<(S, Vec<V>)>) -> Box<Self>
+    where
+        S: AsRef<str>,
+        V: Into<NumOrString> + Clone,
+    {
+        Self::new(
+            columns
+                .into_iter()
+                .map(|(label, values)| Dimension::new(label.as_ref(), values))
+                .collect(),
+        )
+    }
+}
+
+impl Trace for Splom {
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+
+    #[test]
+    fn test_serialize_dimension_axis() {
+        let axis = DimensionAxis::new().type_(AxisType::Log).matches(true);
+        let expected = json!({"type": "log", "matches": true});
+
+        assert_eq!(to_value(axis).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_dimension() {
+        let dimension = Dimension::new("dim", vec!["a", "b"])
+            .axis(DimensionAxis::new())
+            .visible(false);
+        let expected = json!({
+            "label": "dim",
+            "values": ["a", "b"],
+            "axis": {},
+            "visible": false,
+        });
+
+        assert_eq!(to_value(dimension).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_diagonal() {
+        let diagonal = Diagonal::new().visible(false);
+        assert_eq!(to_value(diagonal).unwrap(), json!({"visible": false}));
+    }
+
+    #[test]
+    fn test_serialize_default_splom() {
+        let trace = Splom::default();
+        let expected = json!({"type": "splom"});
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_splom() {
+        let trace = Splom::new(vec![Dimension::new("a", vec![1, 2])])
+            .name("splom_trace")
+            .visible(Visible::True)
+            .show_legend(true)
+            .legend_group("legend_group")
+            .legend_group_title(LegendGroupTitle::new("Legend Group Title"))
+            .opacity(0.5)
+            .diagonal(Diagonal::new())
+            .show_upper_half(false)
+            .show_lower_half(true)
+            .x_axes(vec!["x", "x2"])
+            .y_axes(vec!["y", "y2"])
+            .marker(Marker::new())
+            .text("text")
+            .hover_text_array(vec!["hover_text"])
+            .hover_info(HoverInfo::Text)
+            .hover_template("hover_template")
+            .hover_label(Label::new())
+            .selected_points(vec![0])
+            .ids(vec!["one"])
+            .meta("meta")
+            .custom_data(vec!["custom_data"])
+            .ui_revision(1);
+        let expected = json!({
+            "type": "splom",
+            "dimensions": [{"label": "a", "values": [1, 2]}],
+            "name": "splom_trace",
+            "visible": true,
+            "showlegend": true,
+            "legendgroup": "legend_group",
+            "legendgrouptitle": {"text": "Legend Group Title"},
+            "opacity": 0.5,
+            "diagonal": {},
+            "showupperhalf": false,
+            "showlowerhalf": true,
+            "xaxes": ["x", "x2"],
+            "yaxes": ["y", "y2"],
+            "marker": {},
+            "text": "text",
+            "hovertext": ["hover_text"],
+            "hoverinfo": "text",
+            "hovertemplate": "hover_template",
+            "hoverlabel": {},
+            "selectedpoints": [0],
+            "ids": ["one"],
+            "meta": "meta",
+            "customdata": ["custom_data"],
+            "uirevision": 1,
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_splom_from_columns() {
+        let trace = Splom::from_columns(vec![("a", vec![1.0]), ("b", vec![2.0])]);
+        let expected = json!({
+            "type": "splom",
+            "dimensions": [
+                {"label": "a", "values": [1.0]},
+                {"label": "b", "values": [2.0]},
+            ],
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+}
@@ -0,0 +1,318 @@
+//! Streamtube trace
+
+use plotly_derive::FieldSetter;
+use serde::Serialize;
+
+use crate::{
+    common::{ColorBar, ColorScale, HoverInfo, Label, LegendGroupTitle, PlotType, Visible},
+    private::{NumOrString, NumOrStringCollection},
+    traces::mesh3d::{LightPosition, Lighting},
+    Trace,
+};
+
+/// Starting positions of the streamtubes.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct Starts {
+    /// Sets the x components of the starting position of the streamtubes.
+    x: Option<Vec<f64>>,
+    /// Sets the y components of the starting position of the streamtubes.
+    y: Option<Vec<f64>>,
+    /// Sets the z components of the starting position of the streamtubes.
+    z: Option<Vec<f64>>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl Starts {
+    pub fn new(x: Vec<f64>, y: Vec<f64>, z: Vec<f64>) -> Self {
+        Self {
+            x: Some(x),
+            y: Some(y),
+            z: Some(z),
+            ..Default::default()
+        }
+    }
+}
+
+/// Construct a streamtube trace, drawing tubes along the streamlines of the
+/// vector field `(u, v, w)` sampled at the `(x, y, z)` grid positions.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::{streamtube::Starts, Streamtube};
+///
+/// let trace = Streamtube::new(
+///     vec![0.0, 1.0],
+///     vec![0.0, 1.0],
+///     vec![0.0, 1.0],
+///     vec![1.0, 1.0],
+///     vec![0.0, 0.0],
+///     vec![0.0, 0.0],
+/// )
+/// .starts(Starts::new(vec![0.0], vec![0.5], vec![0.5]))
+/// .max_displayed(100);
+///
+/// let expected = serde_json::json!({
+///     "type": "streamtube",
+///     "x": [0.0, 1.0],
+///     "y": [0.0, 1.0],
+///     "z": [0.0, 1.0],
+///     "u": [1.0, 1.0],
+///     "v": [0.0, 0.0],
+///     "w": [0.0, 0.0],
+///     "starts": {"x": [0.0], "y": [0.5], "z": [0.5]},
+///     "maxdisplayed": 100
+/// });
+///
+/// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+#[field_setter(box_self, kind = "trace")]
+pub struct Streamtube {
+    #[field_setter(default = "PlotType::Streamtube")]
+    r#type: PlotType,
+    /// Sets the trace name. The trace name appear as the legend item and on
+    /// hover.
+    name: Option<String>,
+    /// Determines whether or not this trace is visible. If
+    /// `Visible::LegendOnly`, the trace is not drawn, but can appear as a
+    /// legend item (provided that the legend itself is visible).
+    visible: Option<Visible>,
+    /// Determines whether or not an item corresponding to this trace is shown
+    /// in the legend.
+    #[serde(rename = "showlegend")]
+    show_legend: Option<bool>,
+    /// Sets the legend group for this trace. Traces part of the same legend
+    /// group hide/show at the same time when toggling legend items.
+    #[serde(rename = "legendgroup")]
+    legend_group: Option<String>,
+    /// Set and style the title to appear for the legend group
+    #[serde(rename = "legendgrouptitle")]
+    legend_group_title: Option<LegendGroupTitle>,
+    /// Sets the opacity of the surface.
+    opacity: Option<f64>,
+    /// Sets the x coordinates of the vector field.
+    x: Option<Vec<f64>>,
+    /// Sets the y coordinates of the vector field.
+    y: Option<Vec<f64>>,
+    /// Sets the z coordinates of the vector field.
+    z: Option<Vec<f64>>,
+    /// Sets the x components of the vector field.
+    u: Option<Vec<f64>>,
+    /// Sets the y components of the vector field.
+    v: Option<Vec<f64>>,
+    /// Sets the z components of the vector field.
+    w: Option<Vec<f64>>,
+    starts: Option<Starts>,
+    /// The maximum number of displayed segments in a streamtube.
+    #[serde(rename = "maxdisplayed")]
+    max_displayed: Option<usize>,
+    /// The scaling factor for the streamtubes. The default is 1, which avoids
+    /// two max divergence tubes from touching at adjacent starting positions.
+    #[serde(rename = "sizeref")]
+    size_ref: Option<f64>,
+    lighting: Option<Lighting>,
+    #[serde(rename = "lightposition")]
+    light_position: Option<LightPosition>,
+    /// Sets a text element associated with this trace.
+    text: Option<String>,
+    /// Same as `text`.
+    #[serde(rename = "hovertext")]
+    hover_text: Option<String>,
+    /// Determines which trace information appear on hover. If `HoverInfo::None`
+    /// or `HoverInfo::Skip` are set, no information is displayed upon
+    /// hovering. But, if `HoverInfo::None` is set, click and hover events
+    /// are still fired.
+    #[serde(rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    /// Template string used for rendering the information that appear on hover
+    /// box. Note that this will override `HoverInfo`.
+    #[serde(rename = "hovertemplate")]
+    hover_template: Option<String>,
+    /// Properties of label displayed on mouse hover.
+    #[serde(rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(rename = "autocolorscale")]
+    auto_color_scale: Option<bool>,
+    #[serde(rename = "colorscale")]
+    color_scale: Option<ColorScale>,
+    #[serde(rename = "reversescale")]
+    reverse_scale: Option<bool>,
+    #[serde(rename = "showscale")]
+    show_scale: Option<bool>,
+    #[serde(rename = "colorbar")]
+    color_bar: Option<ColorBar>,
+    cauto: Option<bool>,
+    cmin: Option<f64>,
+    cmax: Option<f64>,
+    cmid: Option<f64>,
+    /// Sets a reference to a shared color axis. References to these shared
+    /// color axes are "coloraxis", "coloraxis2", "coloraxis3", etc.
+    #[serde(rename = "coloraxis")]
+    color_axis: Option<String>,
+    /// Sets a reference between this trace's 3D coordinate system and a 3D
+    /// scene. If "scene" (the default value), the (x,y,z) coordinates refer
+    /// to `layout.scene`. If "scene2", the (x, y, z) coordinates refer to
+    /// `layout.scene2`, and so on.
+    scene: Option<String>,
+    /// Assigns id labels to each datum. These ids for object constancy of data
+    /// points during animation. Should be an array of strings, not numbers
+    /// or any other type.
+    ids: Option<Vec<String>>,
+    /// Assigns extra meta information associated with this trace that can be
+    /// used in various text attributes. To access the trace `meta` values in
+    /// an attribute in the same trace, simply use `%{meta[i]}` where `i` is
+    /// the index or key of the `meta` item in question.
+    meta: Option<NumOrString>,
+    /// Assigns extra data each datum. This may be useful when listening to
+    /// hover, click and selection events.
+    #[serde(rename = "customdata")]
+    custom_data: Option<NumOrStringCollection>,
+    /// Controls persistence of some user-driven changes to the trace. Defaults
+    /// to `layout.uirevision`.
+    #[serde(rename = "uirevision")]
+    ui_revision: Option<NumOrString>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl Streamtube {
+    pub fn new(
+        x: Vec<f64>,
+        y: Vec<f64>,
+        z: Vec<f64>,
+        u: Vec<f64>,
+        v: Vec<f64>,
+        w: Vec<f64>,
+    ) -> Box<Self> {
+        Box::new(Self {
+            x: Some(x),
+            y: Some(y),
+            z: Some(z),
+            u: Some(u),
+            v: Some(v),
+            w: Some(w),
+            ..Default::default()
+        })
+    }
+}
+
+impl Trace for Streamtube {
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+    use crate::common::ColorScalePalette;
+
+    #[test]
+    fn test_serialize_starts() {
+        let starts = Starts::new(vec![0.0], vec![1.0], vec![2.0]);
+        let expected = json!({"x": [0.0], "y": [1.0], "z": [2.0]});
+
+        assert_eq!(to_value(starts).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_default_streamtube() {
+        let trace = Streamtube::default();
+        let expected = json!({"type": "streamtube"});
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_streamtube() {
+        let trace = Streamtube::new(
+            vec![0.0],
+            vec![1.0],
+            vec![2.0],
+            vec![3.0],
+            vec![4.0],
+            vec![5.0],
+        )
+        .name("streamtube_trace")
+        .visible(Visible::False)
+        .show_legend(false)
+        .legend_group("legend_group")
+        .legend_group_title(LegendGroupTitle::new("Legend Group Title"))
+        .opacity(0.9)
+        .starts(Starts::new(vec![0.0], vec![1.0], vec![2.0]))
+        .max_displayed(500)
+        .size_ref(0.5)
+        .lighting(Lighting::new())
+        .light_position(LightPosition::new())
+        .text("text")
+        .hover_text("hover_text")
+        .hover_info(HoverInfo::Skip)
+        .hover_template("hover_template")
+        .hover_label(Label::new())
+        .auto_color_scale(true)
+        .color_scale(ColorScale::Palette(ColorScalePalette::Portland))
+        .reverse_scale(true)
+        .show_scale(false)
+        .color_bar(ColorBar::new())
+        .cauto(false)
+        .cmin(1.0)
+        .cmax(2.0)
+        .cmid(1.5)
+        .color_axis("coloraxis")
+        .scene("scene3")
+        .ids(vec!["one"])
+        .meta("meta")
+        .custom_data(vec!["custom_data"])
+        .ui_revision(3);
+        let expected = json!({
+            "type": "streamtube",
+            "name": "streamtube_trace",
+            "visible": false,
+            "showlegend": false,
+            "legendgroup": "legend_group",
+            "legendgrouptitle": {"text": "Legend Group Title"},
+            "opacity": 0.9,
+            "x": [0.0],
+            "y": [1.0],
+            "z": [2.0],
+            "u": [3.0],
+            "v": [4.0],
+            "w": [5.0],
+            "starts": {"x": [0.0], "y": [1.0], "z": [2.0]},
+            "maxdisplayed": 500,
+            "sizeref": 0.5,
+            "lighting": {},
+            "lightposition": {},
+            "text": "text",
+            "hovertext": "hover_text",
+            "hoverinfo": "skip",
+            "hovertemplate": "hover_template",
+            "hoverlabel": {},
+            "autocolorscale": true,
+            "colorscale": "Portland",
+            "reversescale": true,
+            "showscale": false,
+            "colorbar": {},
+            "cauto": false,
+            "cmin": 1.0,
+            "cmax": 2.0,
+            "cmid": 1.5,
+            "coloraxis": "coloraxis",
+            "scene": "scene3",
+            "ids": ["one"],
+            "meta": "meta",
+            "customdata": ["custom_data"],
+            "uirevision": 3,
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+}
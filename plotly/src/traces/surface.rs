@@ -19,6 +19,9 @@ pub struct Lighting {
     fresnel: Option<f64>,
     roughness: Option<f64>,
     specular: Option<f64>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl Lighting {
@@ -46,6 +49,9 @@ pub struct PlaneProject {
     x: Option<bool>,
     y: Option<bool>,
     z: Option<bool>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl PlaneProject {
@@ -71,6 +77,9 @@ pub struct PlaneContours {
     #[serde(rename = "usecolormap")]
     use_colormap: Option<bool>,
     width: Option<usize>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl PlaneContours {
@@ -85,6 +94,9 @@ pub struct SurfaceContours {
     x: Option<PlaneContours>,
     y: Option<PlaneContours>,
     z: Option<PlaneContours>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl SurfaceContours {
@@ -172,6 +184,9 @@ where
     y_calendar: Option<Calendar>,
     #[serde(rename = "zcalendar")]
     z_calendar: Option<Calendar>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
 }
 
 impl<X, Y, Z> Surface<X, Y, Z>
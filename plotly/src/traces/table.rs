@@ -0,0 +1,400 @@
+//! Table trace
+
+use plotly_derive::FieldSetter;
+use serde::Serialize;
+
+use crate::{
+    color::{Color, ColorArray},
+    common::{Dim, Domain, Font, HoverInfo, Label, PlotType, Visible},
+    layout::HAlign,
+    private::{NumOrString, NumOrStringCollection},
+    Trace,
+};
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct Fill {
+    color: Option<Dim<Box<dyn Color>>>,
+}
+
+impl Fill {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Sets the cell fill color.
+    pub fn color<C: Color>(mut self, color: C) -> Self {
+        self.color = Some(Dim::Scalar(Box::new(color)));
+        self
+    }
+
+    /// Sets the cell fill color of each cell. Colors are applied per column.
+    pub fn color_array<C: Color>(mut self, colors: Vec<C>) -> Self {
+        self.color = Some(Dim::Vector(ColorArray(colors).into()));
+        self
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct Line {
+    color: Option<Dim<Box<dyn Color>>>,
+    width: Option<f64>,
+}
+
+impl Line {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Sets the color of the cell borders.
+    pub fn color<C: Color>(mut self, color: C) -> Self {
+        self.color = Some(Dim::Scalar(Box::new(color)));
+        self
+    }
+
+    /// Sets the color of the cell borders, per column.
+    pub fn color_array<C: Color>(mut self, colors: Vec<C>) -> Self {
+        self.color = Some(Dim::Vector(ColorArray(colors).into()));
+        self
+    }
+
+    /// Sets the width (in px) of the cell borders.
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = Some(width);
+        self
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct Header {
+    /// Header cell values. `values[m]` is the header of the m-th column.
+    values: Option<NumOrStringCollection>,
+    /// Sets the cell value formatting rule using d3 formatting mini-languages
+    /// which are very similar to those in Python. For numbers, see: https://github.com/d3/d3-format/tree/v1.4.5#d3-format.
+    format: Option<Vec<String>>,
+    /// Prefix for cell values.
+    prefix: Option<Dim<String>>,
+    /// Suffix for cell values.
+    suffix: Option<Dim<String>>,
+    /// Sets the horizontal alignment of the `text` within the box.
+    align: Option<Dim<HAlign>>,
+    /// The height of cells.
+    height: Option<f64>,
+    fill: Option<Fill>,
+    font: Option<Font>,
+    line: Option<Line>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl Header {
+    pub fn new<T: Into<NumOrString> + Clone>(values: Vec<T>) -> Self {
+        Self {
+            values: Some(values.into()),
+            ..Default::default()
+        }
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct Cells {
+    /// Cell values. `values[m][n]` represents the value of the n-th point in
+    /// the m-th column.
+    #[field_setter(skip)]
+    values: Option<Vec<NumOrStringCollection>>,
+    /// Sets the cell value formatting rule using d3 formatting mini-languages
+    /// which are very similar to those in Python. For numbers, see: https://github.com/d3/d3-format/tree/v1.4.5#d3-format.
+    format: Option<Vec<String>>,
+    /// Prefix for cell values.
+    prefix: Option<Dim<String>>,
+    /// Suffix for cell values.
+    suffix: Option<Dim<String>>,
+    /// Sets the horizontal alignment of the `text` within the box.
+    align: Option<Dim<HAlign>>,
+    /// The height of cells.
+    height: Option<f64>,
+    fill: Option<Fill>,
+    font: Option<Font>,
+    line: Option<Line>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl Cells {
+    /// Create the table cells from a vector of columns.
+    pub fn new<T: Into<NumOrString> + Clone>(columns: Vec<Vec<T>>) -> Self {
+        Self::default().values(columns)
+    }
+
+    /// Create the table cells from an iterator over rows. The rows are
+    /// transposed into the column-major layout expected by plotly.js. Rows
+    /// shorter than the longest one are padded with empty cells.
+    pub fn from_rows<I, R, T>(rows: I) -> Self
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+        T: Into<NumOrString>,
+    {
+        let mut columns: Vec<Vec<NumOrString>> = Vec::new();
+        for (n, row) in rows.into_iter().enumerate() {
+            let mut row = row.into_iter().map(Into::into);
+            for column in columns.iter_mut() {
+                column.push(row.next().unwrap_or_else(|| String::new().into()));
+            }
+            for value in row {
+                // A new column is empty in all the previous rows.
+                let mut column = vec![NumOrString::from(String::new()); n];
+                column.push(value);
+                columns.push(column);
+            }
+        }
+        Self::new(columns)
+    }
+
+    /// Cell values. `values[m][n]` represents the value of the n-th point in
+    /// the m-th column.
+    pub fn values<T: Into<NumOrString> + Clone>(mut self, columns: Vec<Vec<T>>) -> Self {
+        self.values = Some(columns.into_iter().map(|c| c.into()).collect());
+        self
+    }
+}
+
+/// Construct a table trace.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::{
+///     table::{Cells, Header},
+///     Table,
+/// };
+///
+/// let trace = Table::new(
+///     Header::new(vec!["Name", "Score"]),
+///     Cells::from_rows(vec![vec!["a", "1"], vec!["b", "2"]]),
+/// );
+///
+/// let expected = serde_json::json!({
+///     "type": "table",
+///     "header": {"values": ["Name", "Score"]},
+///     "cells": {"values": [["a", "b"], ["1", "2"]]}
+/// });
+///
+/// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+#[field_setter(box_self, kind = "trace")]
+pub struct Table {
+    #[field_setter(default = "PlotType::Table")]
+    r#type: PlotType,
+    /// Sets the trace name. The trace name appear as the legend item and on
+    /// hover.
+    name: Option<String>,
+    /// Determines whether or not this trace is visible. If
+    /// `Visible::LegendOnly`, the trace is not drawn, but can appear as a
+    /// legend item (provided that the legend itself is visible).
+    visible: Option<Visible>,
+    /// Sets the domain within which the table will be drawn.
+    domain: Option<Domain>,
+    /// The width of columns expressed as a ratio. Columns fill the available
+    /// width in proportion of their specified column widths.
+    #[serde(rename = "columnwidth")]
+    column_width: Option<Dim<f64>>,
+    /// Specifies the rendered order of the data columns; for example, a value
+    /// `2` at position `0` means that column index `0` in the data will be
+    /// rendered as the third column, as columns have an index base of zero.
+    #[serde(rename = "columnorder")]
+    column_order: Option<Vec<usize>>,
+    header: Option<Header>,
+    cells: Option<Cells>,
+    /// Assigns id labels to each datum. These ids for object constancy of data
+    /// points during animation. Should be an array of strings, not numbers
+    /// or any other type.
+    ids: Option<Vec<String>>,
+    /// Determines which trace information appear on hover. If `HoverInfo::None`
+    /// or `HoverInfo::Skip` are set, no information is displayed upon
+    /// hovering. But, if `HoverInfo::None` is set, click and hover events
+    /// are still fired.
+    #[serde(rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    /// Properties of label displayed on mouse hover.
+    #[serde(rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    /// Assigns extra meta information associated with this trace that can be
+    /// used in various text attributes. To access the trace `meta` values in
+    /// an attribute in the same trace, simply use `%{meta[i]}` where `i` is
+    /// the index or key of the `meta` item in question.
+    meta: Option<NumOrString>,
+    /// Assigns extra data each datum. This may be useful when listening to
+    /// hover, click and selection events.
+    #[serde(rename = "customdata")]
+    custom_data: Option<NumOrStringCollection>,
+    /// Controls persistence of some user-driven changes to the trace. Defaults
+    /// to `layout.uirevision`.
+    #[serde(rename = "uirevision")]
+    ui_revision: Option<NumOrString>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl Table {
+    pub fn new(header: Header, cells: Cells) -> Box<Self> {
+        Box::new(Self {
+            header: Some(header),
+            cells: Some(cells),
+            ..Default::default()
+        })
+    }
+}
+
+impl Trace for Table {
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+    use crate::color::NamedColor;
+
+    #[test]
+    fn test_serialize_fill() {
+        let fill = Fill::new().color(NamedColor::Blue);
+        assert_eq!(to_value(fill).unwrap(), json!({"color": "blue"}));
+
+        let fill = Fill::new().color_array(vec![NamedColor::Blue, NamedColor::Red]);
+        assert_eq!(to_value(fill).unwrap(), json!({"color": ["blue", "red"]}));
+    }
+
+    #[test]
+    fn test_serialize_line() {
+        let line = Line::new().color("#123456").width(1.5);
+        let expected = json!({"color": "#123456", "width": 1.5});
+
+        assert_eq!(to_value(line).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_header() {
+        let header = Header::new(vec!["A", "B"])
+            .format(vec!["", ".2f"])
+            .prefix("<")
+            .suffix_array(vec![">", "]"])
+            .align(HAlign::Center)
+            .height(30.0)
+            .fill(Fill::new())
+            .font(Font::new())
+            .line(Line::new());
+        let expected = json!({
+            "values": ["A", "B"],
+            "format": ["", ".2f"],
+            "prefix": "<",
+            "suffix": [">", "]"],
+            "align": "center",
+            "height": 30.0,
+            "fill": {},
+            "font": {},
+            "line": {},
+        });
+
+        assert_eq!(to_value(header).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_cells() {
+        let cells = Cells::new(vec![vec![1, 2], vec![3, 4]])
+            .format(vec![".1f"])
+            .prefix_array(vec!["$", "€"])
+            .suffix("!")
+            .align_array(vec![HAlign::Left, HAlign::Right])
+            .height(20.0)
+            .fill(Fill::new())
+            .font(Font::new())
+            .line(Line::new());
+        let expected = json!({
+            "values": [[1, 2], [3, 4]],
+            "format": [".1f"],
+            "prefix": ["$", "€"],
+            "suffix": "!",
+            "align": ["left", "right"],
+            "height": 20.0,
+            "fill": {},
+            "font": {},
+            "line": {},
+        });
+
+        assert_eq!(to_value(cells).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_cells_from_rows() {
+        let rows = vec![
+            vec![NumOrString::from("a"), NumOrString::from(1)],
+            vec![NumOrString::from("b"), NumOrString::from(2.5)],
+        ];
+        let cells = Cells::from_rows(rows);
+        let expected = json!({"values": [["a", "b"], [1, 2.5]]});
+
+        assert_eq!(to_value(cells).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_cells_from_ragged_rows() {
+        let cells = Cells::from_rows(vec![vec!["a"], vec!["b", "c"], vec![]]);
+        let expected = json!({"values": [["a", "b", ""], ["", "c", ""]]});
+
+        assert_eq!(to_value(cells).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_default_table() {
+        let trace = Table::default();
+        let expected = json!({"type": "table"});
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_table() {
+        let trace = Table::new(Header::new(vec!["x"]), Cells::new(vec![vec![0.5]]))
+            .name("table_trace")
+            .visible(Visible::False)
+            .domain(Domain::new().row(0).column(0))
+            .column_width_array(vec![1.0, 2.0])
+            .column_order(vec![1, 0])
+            .ids(vec!["one"])
+            .hover_info(HoverInfo::Skip)
+            .hover_label(Label::new())
+            .meta("meta")
+            .custom_data(vec!["custom_data"])
+            .ui_revision("rev");
+        let expected = json!({
+            "type": "table",
+            "header": {"values": ["x"]},
+            "cells": {"values": [[0.5]]},
+            "name": "table_trace",
+            "visible": false,
+            "domain": {"row": 0, "column": 0},
+            "columnwidth": [1.0, 2.0],
+            "columnorder": [1, 0],
+            "ids": ["one"],
+            "hoverinfo": "skip",
+            "hoverlabel": {},
+            "meta": "meta",
+            "customdata": ["custom_data"],
+            "uirevision": "rev",
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+}
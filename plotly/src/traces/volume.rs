@@ -0,0 +1,817 @@
+//! Volume and isosurface traces
+
+#[cfg(feature = "plotly_ndarray")]
+use ndarray::{Array, Ix1, Ix3};
+use plotly_derive::FieldSetter;
+use serde::Serialize;
+
+use crate::{
+    common::{ColorBar, ColorScale, Dim, HoverInfo, Label, LegendGroupTitle, PlotType, Visible},
+    private::{NumOrString, NumOrStringCollection},
+    traces::mesh3d::{Contour, LightPosition, Lighting},
+    Trace,
+};
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum SurfacePattern {
+    All,
+    Odd,
+    Even,
+}
+
+/// Controls the iso-surfaces drawn between `iso_min` and `iso_max`.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct SurfaceSettings {
+    /// Hides/displays surfaces between minimum and maximum iso-values.
+    show: Option<bool>,
+    /// Sets the number of iso-surfaces between minimum and maximum
+    /// iso-values. By default this value is 2 meaning that only minimum and
+    /// maximum surfaces would be drawn.
+    count: Option<usize>,
+    /// Sets the fill ratio of the iso-surface. The default fill value of the
+    /// surface is 1 meaning that they are entirely shaded. On the other hand
+    /// applying a `fill` ratio less than one would allow the creation of
+    /// openings parallel to the edges.
+    fill: Option<f64>,
+    /// Sets the surface pattern of the iso-surface 3-D sections.
+    pattern: Option<SurfacePattern>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl SurfaceSettings {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct CapSettings {
+    /// Determines whether or not the cap on this axis is drawn.
+    show: Option<bool>,
+    /// Sets the fill ratio of the `caps`. The default fill value of the `caps`
+    /// is 1 meaning that they are entirely shaded. On the other hand
+    /// applying a `fill` ratio less than one would allow the creation of
+    /// openings parallel to the edges.
+    fill: Option<f64>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl CapSettings {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+/// Caps drawn on the boundaries of the volume along each axis.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct Caps {
+    x: Option<CapSettings>,
+    y: Option<CapSettings>,
+    z: Option<CapSettings>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl Caps {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct SliceSettings {
+    /// Determines whether or not slice planes about this axis are drawn.
+    show: Option<bool>,
+    /// Sets the fill ratio of the `slices`.
+    fill: Option<f64>,
+    /// Specifies the location(s) of slices on the axis. When not specified
+    /// slices would be created for all points of the axis.
+    locations: Option<Vec<f64>>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl SliceSettings {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+/// Slice planes drawn perpendicular to each axis.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct Slices {
+    x: Option<SliceSettings>,
+    y: Option<SliceSettings>,
+    z: Option<SliceSettings>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl Slices {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+pub struct SpaceFrame {
+    /// Displays/hides tetrahedron shapes between minimum and maximum
+    /// iso-values. Often useful when either caps or surfaces are disabled or
+    /// filled with values less than 1.
+    show: Option<bool>,
+    /// Sets the fill ratio of the `spaceframe` elements.
+    fill: Option<f64>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl SpaceFrame {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum OpacityScalePreset {
+    Min,
+    Max,
+    Extremes,
+    Uniform,
+}
+
+/// Sets the opacity scale of a volume trace, either from a preset or from
+/// `(value, opacity)` pairs where both are in the range [0, 1].
+#[derive(Serialize, Clone, Debug)]
+#[serde(untagged)]
+pub enum OpacityScale {
+    Preset(OpacityScalePreset),
+    Custom(Vec<(f64, f64)>),
+}
+
+impl From<OpacityScalePreset> for OpacityScale {
+    fn from(preset: OpacityScalePreset) -> Self {
+        Self::Preset(preset)
+    }
+}
+
+impl From<Vec<(f64, f64)>> for OpacityScale {
+    fn from(scale: Vec<(f64, f64)>) -> Self {
+        Self::Custom(scale)
+    }
+}
+
+/// Flattens a scalar field sampled on the grid spanned by `x`, `y` and `z`
+/// into the per-point coordinate vectors expected by plotly.js.
+#[cfg(feature = "plotly_ndarray")]
+fn flatten_grid(
+    x: Array<f64, Ix1>,
+    y: Array<f64, Ix1>,
+    z: Array<f64, Ix1>,
+    value: Array<f64, Ix3>,
+) -> (Vec<f64>, Vec<f64>, Vec<f64>, Vec<f64>) {
+    assert_eq!(
+        value.shape(),
+        &[x.len(), y.len(), z.len()],
+        "`value` must have shape (x.len(), y.len(), z.len())"
+    );
+    let n = value.len();
+    let (mut xs, mut ys, mut zs, mut values) = (
+        Vec::with_capacity(n),
+        Vec::with_capacity(n),
+        Vec::with_capacity(n),
+        Vec::with_capacity(n),
+    );
+    for ((i, j, k), v) in value.indexed_iter() {
+        xs.push(x[i]);
+        ys.push(y[j]);
+        zs.push(z[k]);
+        values.push(*v);
+    }
+
+    (xs, ys, zs, values)
+}
+
+/// Construct a volume trace, rendering a 3D scalar field as a stack of
+/// semi-transparent iso-surfaces.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::{volume::SurfaceSettings, Volume};
+///
+/// let trace = Volume::new(
+///     vec![0.0, 1.0],
+///     vec![0.0, 1.0],
+///     vec![0.0, 1.0],
+///     vec![0.1, 0.2],
+/// )
+/// .iso_min(0.1)
+/// .iso_max(0.2)
+/// .surface(SurfaceSettings::new().count(5));
+///
+/// let expected = serde_json::json!({
+///     "type": "volume",
+///     "x": [0.0, 1.0],
+///     "y": [0.0, 1.0],
+///     "z": [0.0, 1.0],
+///     "value": [0.1, 0.2],
+///     "isomin": 0.1,
+///     "isomax": 0.2,
+///     "surface": {"count": 5}
+/// });
+///
+/// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+#[field_setter(box_self, kind = "trace")]
+pub struct Volume {
+    #[field_setter(default = "PlotType::Volume")]
+    r#type: PlotType,
+    /// Sets the trace name. The trace name appear as the legend item and on
+    /// hover.
+    name: Option<String>,
+    /// Determines whether or not this trace is visible. If
+    /// `Visible::LegendOnly`, the trace is not drawn, but can appear as a
+    /// legend item (provided that the legend itself is visible).
+    visible: Option<Visible>,
+    /// Determines whether or not an item corresponding to this trace is shown
+    /// in the legend.
+    #[serde(rename = "showlegend")]
+    show_legend: Option<bool>,
+    /// Sets the legend group for this trace. Traces part of the same legend
+    /// group hide/show at the same time when toggling legend items.
+    #[serde(rename = "legendgroup")]
+    legend_group: Option<String>,
+    /// Set and style the title to appear for the legend group
+    #[serde(rename = "legendgrouptitle")]
+    legend_group_title: Option<LegendGroupTitle>,
+    /// Sets the opacity of the surface. Please note that in the case of using
+    /// high `opacity` values for example a value greater than or equal to 0.5
+    /// on two surfaces (and 0.25 with four surfaces), an overlay of multiple
+    /// transparent surfaces may not perfectly be sorted in depth by the
+    /// webgl API.
+    opacity: Option<f64>,
+    /// Sets the opacity scale. The opacity scale maps the normalized `value`
+    /// of each point to an opacity between 0 and 1.
+    #[serde(rename = "opacityscale")]
+    opacity_scale: Option<OpacityScale>,
+    /// Sets the X coordinates of the vertices on X axis.
+    x: Option<Vec<f64>>,
+    /// Sets the Y coordinates of the vertices on Y axis.
+    y: Option<Vec<f64>>,
+    /// Sets the Z coordinates of the vertices on Z axis.
+    z: Option<Vec<f64>>,
+    /// Sets the 4th dimension (value) of the vertices.
+    value: Option<Vec<f64>>,
+    /// Sets the minimum boundary for iso-surface plot.
+    #[serde(rename = "isomin")]
+    iso_min: Option<f64>,
+    /// Sets the maximum boundary for iso-surface plot.
+    #[serde(rename = "isomax")]
+    iso_max: Option<f64>,
+    surface: Option<SurfaceSettings>,
+    caps: Option<Caps>,
+    slices: Option<Slices>,
+    #[serde(rename = "spaceframe")]
+    space_frame: Option<SpaceFrame>,
+    contour: Option<Contour>,
+    /// Determines whether or not normal smoothing is applied to the meshes,
+    /// creating meshes with an angular, low-poly look via flat reflections.
+    #[serde(rename = "flatshading")]
+    flat_shading: Option<bool>,
+    lighting: Option<Lighting>,
+    #[serde(rename = "lightposition")]
+    light_position: Option<LightPosition>,
+    /// Sets the text elements associated with the vertices.
+    text: Option<Dim<String>>,
+    /// Same as `text`.
+    #[serde(rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    /// Determines which trace information appear on hover. If `HoverInfo::None`
+    /// or `HoverInfo::Skip` are set, no information is displayed upon
+    /// hovering. But, if `HoverInfo::None` is set, click and hover events
+    /// are still fired.
+    #[serde(rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    /// Template string used for rendering the information that appear on hover
+    /// box. Note that this will override `HoverInfo`.
+    #[serde(rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    /// Properties of label displayed on mouse hover.
+    #[serde(rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(rename = "autocolorscale")]
+    auto_color_scale: Option<bool>,
+    #[serde(rename = "colorscale")]
+    color_scale: Option<ColorScale>,
+    #[serde(rename = "reversescale")]
+    reverse_scale: Option<bool>,
+    #[serde(rename = "showscale")]
+    show_scale: Option<bool>,
+    #[serde(rename = "colorbar")]
+    color_bar: Option<ColorBar>,
+    cauto: Option<bool>,
+    cmin: Option<f64>,
+    cmax: Option<f64>,
+    cmid: Option<f64>,
+    /// Sets a reference to a shared color axis. References to these shared
+    /// color axes are "coloraxis", "coloraxis2", "coloraxis3", etc.
+    #[serde(rename = "coloraxis")]
+    color_axis: Option<String>,
+    /// Sets a reference between this trace's 3D coordinate system and a 3D
+    /// scene. If "scene" (the default value), the (x,y,z) coordinates refer
+    /// to `layout.scene`. If "scene2", the (x, y, z) coordinates refer to
+    /// `layout.scene2`, and so on.
+    scene: Option<String>,
+    /// Assigns id labels to each datum. These ids for object constancy of data
+    /// points during animation. Should be an array of strings, not numbers
+    /// or any other type.
+    ids: Option<Vec<String>>,
+    /// Assigns extra meta information associated with this trace that can be
+    /// used in various text attributes. To access the trace `meta` values in
+    /// an attribute in the same trace, simply use `%{meta[i]}` where `i` is
+    /// the index or key of the `meta` item in question.
+    meta: Option<NumOrString>,
+    /// Assigns extra data each datum. This may be useful when listening to
+    /// hover, click and selection events.
+    #[serde(rename = "customdata")]
+    custom_data: Option<NumOrStringCollection>,
+    /// Controls persistence of some user-driven changes to the trace. Defaults
+    /// to `layout.uirevision`.
+    #[serde(rename = "uirevision")]
+    ui_revision: Option<NumOrString>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl Volume {
+    pub fn new(x: Vec<f64>, y: Vec<f64>, z: Vec<f64>, value: Vec<f64>) -> Box<Self> {
+        Box::new(Self {
+            x: Some(x),
+            y: Some(y),
+            z: Some(z),
+            value: Some(value),
+            ..Default::default()
+        })
+    }
+
+    /// Creates a volume trace from a scalar field sampled on a regular grid,
+    /// where `value[[i, j, k]]` is the value at `(x[i], y[j], z[k])`. This
+    /// function requires the `plotly_ndarray` feature.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shape of `value` is not `(x.len(), y.len(), z.len())`.
+    #[cfg(feature = "plotly_ndarray")]
+    pub fn from_array(
+        x: Array<f64, Ix1>,
+        y: Array<f64, Ix1>,
+        z: Array<f64, Ix1>,
+        value: Array<f64, Ix3>,
+    ) -> Box<Self> {
+        let (x, y, z, value) = flatten_grid(x, y, z, value);
+        Self::new(x, y, z, value)
+    }
+}
+
+impl Trace for Volume {
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+/// Construct an isosurface trace, rendering opaque iso-surfaces of a 3D
+/// scalar field between `iso_min` and `iso_max`.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::{
+///     volume::{CapSettings, Caps},
+///     Isosurface,
+/// };
+///
+/// let trace = Isosurface::new(
+///     vec![0.0, 1.0],
+///     vec![0.0, 1.0],
+///     vec![0.0, 1.0],
+///     vec![0.1, 0.2],
+/// )
+/// .caps(Caps::new().x(CapSettings::new().show(false)));
+///
+/// let expected = serde_json::json!({
+///     "type": "isosurface",
+///     "x": [0.0, 1.0],
+///     "y": [0.0, 1.0],
+///     "z": [0.0, 1.0],
+///     "value": [0.1, 0.2],
+///     "caps": {"x": {"show": false}}
+/// });
+///
+/// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, FieldSetter)]
+#[field_setter(box_self, kind = "trace")]
+pub struct Isosurface {
+    #[field_setter(default = "PlotType::Isosurface")]
+    r#type: PlotType,
+    /// Sets the trace name. The trace name appear as the legend item and on
+    /// hover.
+    name: Option<String>,
+    /// Determines whether or not this trace is visible. If
+    /// `Visible::LegendOnly`, the trace is not drawn, but can appear as a
+    /// legend item (provided that the legend itself is visible).
+    visible: Option<Visible>,
+    /// Determines whether or not an item corresponding to this trace is shown
+    /// in the legend.
+    #[serde(rename = "showlegend")]
+    show_legend: Option<bool>,
+    /// Sets the legend group for this trace. Traces part of the same legend
+    /// group hide/show at the same time when toggling legend items.
+    #[serde(rename = "legendgroup")]
+    legend_group: Option<String>,
+    /// Set and style the title to appear for the legend group
+    #[serde(rename = "legendgrouptitle")]
+    legend_group_title: Option<LegendGroupTitle>,
+    /// Sets the opacity of the surface.
+    opacity: Option<f64>,
+    /// Sets the X coordinates of the vertices on X axis.
+    x: Option<Vec<f64>>,
+    /// Sets the Y coordinates of the vertices on Y axis.
+    y: Option<Vec<f64>>,
+    /// Sets the Z coordinates of the vertices on Z axis.
+    z: Option<Vec<f64>>,
+    /// Sets the 4th dimension (value) of the vertices.
+    value: Option<Vec<f64>>,
+    /// Sets the minimum boundary for iso-surface plot.
+    #[serde(rename = "isomin")]
+    iso_min: Option<f64>,
+    /// Sets the maximum boundary for iso-surface plot.
+    #[serde(rename = "isomax")]
+    iso_max: Option<f64>,
+    surface: Option<SurfaceSettings>,
+    caps: Option<Caps>,
+    slices: Option<Slices>,
+    #[serde(rename = "spaceframe")]
+    space_frame: Option<SpaceFrame>,
+    contour: Option<Contour>,
+    /// Determines whether or not normal smoothing is applied to the meshes,
+    /// creating meshes with an angular, low-poly look via flat reflections.
+    #[serde(rename = "flatshading")]
+    flat_shading: Option<bool>,
+    lighting: Option<Lighting>,
+    #[serde(rename = "lightposition")]
+    light_position: Option<LightPosition>,
+    /// Sets the text elements associated with the vertices.
+    text: Option<Dim<String>>,
+    /// Same as `text`.
+    #[serde(rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    /// Determines which trace information appear on hover. If `HoverInfo::None`
+    /// or `HoverInfo::Skip` are set, no information is displayed upon
+    /// hovering. But, if `HoverInfo::None` is set, click and hover events
+    /// are still fired.
+    #[serde(rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    /// Template string used for rendering the information that appear on hover
+    /// box. Note that this will override `HoverInfo`.
+    #[serde(rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    /// Properties of label displayed on mouse hover.
+    #[serde(rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(rename = "autocolorscale")]
+    auto_color_scale: Option<bool>,
+    #[serde(rename = "colorscale")]
+    color_scale: Option<ColorScale>,
+    #[serde(rename = "reversescale")]
+    reverse_scale: Option<bool>,
+    #[serde(rename = "showscale")]
+    show_scale: Option<bool>,
+    #[serde(rename = "colorbar")]
+    color_bar: Option<ColorBar>,
+    cauto: Option<bool>,
+    cmin: Option<f64>,
+    cmax: Option<f64>,
+    cmid: Option<f64>,
+    /// Sets a reference to a shared color axis. References to these shared
+    /// color axes are "coloraxis", "coloraxis2", "coloraxis3", etc.
+    #[serde(rename = "coloraxis")]
+    color_axis: Option<String>,
+    /// Sets a reference between this trace's 3D coordinate system and a 3D
+    /// scene. If "scene" (the default value), the (x,y,z) coordinates refer
+    /// to `layout.scene`. If "scene2", the (x, y, z) coordinates refer to
+    /// `layout.scene2`, and so on.
+    scene: Option<String>,
+    /// Assigns id labels to each datum. These ids for object constancy of data
+    /// points during animation. Should be an array of strings, not numbers
+    /// or any other type.
+    ids: Option<Vec<String>>,
+    /// Assigns extra meta information associated with this trace that can be
+    /// used in various text attributes. To access the trace `meta` values in
+    /// an attribute in the same trace, simply use `%{meta[i]}` where `i` is
+    /// the index or key of the `meta` item in question.
+    meta: Option<NumOrString>,
+    /// Assigns extra data each datum. This may be useful when listening to
+    /// hover, click and selection events.
+    #[serde(rename = "customdata")]
+    custom_data: Option<NumOrStringCollection>,
+    /// Controls persistence of some user-driven changes to the trace. Defaults
+    /// to `layout.uirevision`.
+    #[serde(rename = "uirevision")]
+    ui_revision: Option<NumOrString>,
+    #[field_setter(extra)]
+    #[serde(flatten)]
+    extra: Option<crate::private::Extra>,
+}
+
+impl Isosurface {
+    pub fn new(x: Vec<f64>, y: Vec<f64>, z: Vec<f64>, value: Vec<f64>) -> Box<Self> {
+        Box::new(Self {
+            x: Some(x),
+            y: Some(y),
+            z: Some(z),
+            value: Some(value),
+            ..Default::default()
+        })
+    }
+
+    /// Creates an isosurface trace from a scalar field sampled on a regular
+    /// grid, where `value[[i, j, k]]` is the value at `(x[i], y[j], z[k])`.
+    /// This function requires the `plotly_ndarray` feature.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shape of `value` is not `(x.len(), y.len(), z.len())`.
+    #[cfg(feature = "plotly_ndarray")]
+    pub fn from_array(
+        x: Array<f64, Ix1>,
+        y: Array<f64, Ix1>,
+        z: Array<f64, Ix1>,
+        value: Array<f64, Ix3>,
+    ) -> Box<Self> {
+        let (x, y, z, value) = flatten_grid(x, y, z, value);
+        Self::new(x, y, z, value)
+    }
+}
+
+impl Trace for Isosurface {
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+    use crate::common::ColorScalePalette;
+
+    #[test]
+    fn test_serialize_surface_pattern() {
+        assert_eq!(to_value(SurfacePattern::All).unwrap(), json!("all"));
+        assert_eq!(to_value(SurfacePattern::Odd).unwrap(), json!("odd"));
+        assert_eq!(to_value(SurfacePattern::Even).unwrap(), json!("even"));
+    }
+
+    #[test]
+    fn test_serialize_surface_settings() {
+        let surface = SurfaceSettings::new()
+            .show(true)
+            .count(3)
+            .fill(0.5)
+            .pattern(SurfacePattern::Odd);
+        let expected = json!({"show": true, "count": 3, "fill": 0.5, "pattern": "odd"});
+
+        assert_eq!(to_value(surface).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_caps() {
+        let caps = Caps::new()
+            .x(CapSettings::new().show(false))
+            .y(CapSettings::new().fill(0.5))
+            .z(CapSettings::new());
+        let expected = json!({"x": {"show": false}, "y": {"fill": 0.5}, "z": {}});
+
+        assert_eq!(to_value(caps).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_slices() {
+        let slices = Slices::new()
+            .x(SliceSettings::new().show(true).locations(vec![0.5]))
+            .y(SliceSettings::new().fill(0.1))
+            .z(SliceSettings::new());
+        let expected = json!({
+            "x": {"show": true, "locations": [0.5]},
+            "y": {"fill": 0.1},
+            "z": {},
+        });
+
+        assert_eq!(to_value(slices).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_space_frame() {
+        let space_frame = SpaceFrame::new().show(true).fill(0.2);
+        let expected = json!({"show": true, "fill": 0.2});
+
+        assert_eq!(to_value(space_frame).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_opacity_scale() {
+        let scale: OpacityScale = OpacityScalePreset::Extremes.into();
+        assert_eq!(to_value(scale).unwrap(), json!("extremes"));
+
+        let scale: OpacityScale = vec![(0.0, 1.0), (0.5, 0.2), (1.0, 1.0)].into();
+        let expected = json!([[0.0, 1.0], [0.5, 0.2], [1.0, 1.0]]);
+        assert_eq!(to_value(scale).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_default_volume() {
+        let trace = Volume::default();
+        let expected = json!({"type": "volume"});
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_volume() {
+        let trace = Volume::new(vec![0.0], vec![1.0], vec![2.0], vec![3.0])
+            .name("volume_trace")
+            .visible(Visible::True)
+            .show_legend(true)
+            .legend_group("legend_group")
+            .legend_group_title(LegendGroupTitle::new("Legend Group Title"))
+            .opacity(0.1)
+            .opacity_scale(OpacityScale::Preset(OpacityScalePreset::Max))
+            .iso_min(0.0)
+            .iso_max(3.0)
+            .surface(SurfaceSettings::new())
+            .caps(Caps::new())
+            .slices(Slices::new())
+            .space_frame(SpaceFrame::new())
+            .contour(Contour::new())
+            .flat_shading(true)
+            .lighting(Lighting::new())
+            .light_position(LightPosition::new())
+            .text("text")
+            .hover_text_array(vec!["hover_text"])
+            .hover_info(HoverInfo::All)
+            .hover_template("hover_template")
+            .hover_label(Label::new())
+            .auto_color_scale(false)
+            .color_scale(ColorScale::Palette(ColorScalePalette::Jet))
+            .reverse_scale(true)
+            .show_scale(false)
+            .color_bar(ColorBar::new())
+            .cauto(false)
+            .cmin(0.0)
+            .cmax(3.0)
+            .cmid(1.5)
+            .color_axis("coloraxis")
+            .scene("scene2")
+            .ids(vec!["one"])
+            .meta("meta")
+            .custom_data(vec!["custom_data"])
+            .ui_revision(2);
+        let expected = json!({
+            "type": "volume",
+            "name": "volume_trace",
+            "visible": true,
+            "showlegend": true,
+            "legendgroup": "legend_group",
+            "legendgrouptitle": {"text": "Legend Group Title"},
+            "opacity": 0.1,
+            "opacityscale": "max",
+            "x": [0.0],
+            "y": [1.0],
+            "z": [2.0],
+            "value": [3.0],
+            "isomin": 0.0,
+            "isomax": 3.0,
+            "surface": {},
+            "caps": {},
+            "slices": {},
+            "spaceframe": {},
+            "contour": {},
+            "flatshading": true,
+            "lighting": {},
+            "lightposition": {},
+            "text": "text",
+            "hovertext": ["hover_text"],
+            "hoverinfo": "all",
+            "hovertemplate": "hover_template",
+            "hoverlabel": {},
+            "autocolorscale": false,
+            "colorscale": "Jet",
+            "reversescale": true,
+            "showscale": false,
+            "colorbar": {},
+            "cauto": false,
+            "cmin": 0.0,
+            "cmax": 3.0,
+            "cmid": 1.5,
+            "coloraxis": "coloraxis",
+            "scene": "scene2",
+            "ids": ["one"],
+            "meta": "meta",
+            "customdata": ["custom_data"],
+            "uirevision": 2,
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_default_isosurface() {
+        let trace = Isosurface::default();
+        let expected = json!({"type": "isosurface"});
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_isosurface() {
+        let trace = Isosurface::new(vec![0.0], vec![1.0], vec![2.0], vec![3.0])
+            .name("isosurface_trace")
+            .iso_min(1.0)
+            .iso_max(2.0)
+            .surface(SurfaceSettings::new().count(2))
+            .caps(Caps::new())
+            .slices(Slices::new())
+            .space_frame(SpaceFrame::new())
+            .flat_shading(false)
+            .lighting(Lighting::new())
+            .light_position(LightPosition::new())
+            .color_scale(ColorScale::Palette(ColorScalePalette::Viridis))
+            .show_scale(true)
+            .scene("scene");
+        let expected = json!({
+            "type": "isosurface",
+            "name": "isosurface_trace",
+            "x": [0.0],
+            "y": [1.0],
+            "z": [2.0],
+            "value": [3.0],
+            "isomin": 1.0,
+            "isomax": 2.0,
+            "surface": {"count": 2},
+            "caps": {},
+            "slices": {},
+            "spaceframe": {},
+            "flatshading": false,
+            "lighting": {},
+            "lightposition": {},
+            "colorscale": "Viridis",
+            "showscale": true,
+            "scene": "scene",
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+
+    #[test]
+    #[cfg(feature = "plotly_ndarray")]
+    fn test_volume_from_array() {
+        use ndarray::Array;
+
+        let x = Array::from(vec![0.0, 1.0]);
+        let y = Array::from(vec![2.0]);
+        let z = Array::from(vec![3.0, 4.0]);
+        let value = Array::from_shape_vec((2, 1, 2), vec![0.1, 0.2, 0.3, 0.4]).unwrap();
+        let trace = Volume::from_array(x, y, z, value);
+        let expected = json!({
+            "type": "volume",
+            "x": [0.0, 0.0, 1.0, 1.0],
+            "y": [2.0, 2.0, 2.0, 2.0],
+            "z": [3.0, 4.0, 3.0, 4.0],
+            "value": [0.1, 0.2, 0.3, 0.4],
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+}
@@ -0,0 +1,138 @@
+//! A [Yew](https://yew.rs) component for drawing plots, enabled by the `yew`
+//! feature.
+//!
+//! ```no_run
+//! use plotly::{yew::PlotlyChart, Plot, Scatter};
+//! use yew::prelude::*;
+//!
+//! #[function_component(App)]
+//! fn app() -> Html {
+//!     let plot = use_memo((), |_| {
+//!         let mut plot = Plot::new();
+//!         plot.add_trace(Scatter::new(vec![0, 1, 2], vec![2, 1, 0]));
+//!         plot
+//!     });
+//!     let on_click = use_callback((), |event: plotly::bindings::events::PointsEvent, _| {
+//!         web_sys::console::log_1(&format!("clicked {:?}", event.points).into());
+//!     });
+//!
+//!     html! {
+//!         <PlotlyChart plot={plot} on_click={on_click} />
+//!     }
+//! }
+//! ```
+
+use std::rc::Rc;
+
+use web_sys::HtmlElement;
+use yew::prelude::*;
+
+use crate::{
+    bindings::{
+        chart::{Chart, EventHandlers},
+        events::{LegendClickEvent, PointsEvent, RelayoutEvent, RestyleEvent, SelectionEvent},
+    },
+    Plot,
+};
+
+/// The properties of [`PlotlyChart`].
+///
+/// The chart is redrawn whenever the properties change. The plot and the
+/// callbacks compare by identity, so they should be created with `use_memo`
+/// and `use_callback` to avoid redrawing the chart on every render of the
+/// parent component. Comparing plots by value would serialize both of them.
+#[derive(Properties, Clone)]
+pub struct PlotlyChartProps {
+    pub plot: Rc<Plot>,
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+    #[prop_or_default]
+    pub class: Classes,
+    #[prop_or_default]
+    pub on_click: Option<Callback<PointsEvent>>,
+    #[prop_or_default]
+    pub on_hover: Option<Callback<PointsEvent>>,
+    #[prop_or_default]
+    pub on_unhover: Option<Callback<PointsEvent>>,
+    #[prop_or_default]
+    pub on_selected: Option<Callback<Option<SelectionEvent>>>,
+    #[prop_or_default]
+    pub on_relayout: Option<Callback<RelayoutEvent>>,
+    #[prop_or_default]
+    pub on_restyle: Option<Callback<RestyleEvent>>,
+    /// Return `false` to cancel toggling the visibility of the trace.
+    #[prop_or_default]
+    pub on_legend_click: Option<Callback<LegendClickEvent, bool>>,
+    #[prop_or_default]
+    pub on_double_click: Option<Callback<()>>,
+}
+
+impl PartialEq for PlotlyChartProps {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.plot, &other.plot)
+            && self.id == other.id
+            && self.class == other.class
+            && self.on_click == other.on_click
+            && self.on_hover == other.on_hover
+            && self.on_unhover == other.on_unhover
+            && self.on_selected == other.on_selected
+            && self.on_relayout == other.on_relayout
+            && self.on_restyle == other.on_restyle
+            && self.on_legend_click == other.on_legend_click
+            && self.on_double_click == other.on_double_click
+    }
+}
+
+impl PlotlyChartProps {
+    fn event_handlers(&self) -> EventHandlers {
+        fn handler<T: 'static, R: 'static>(
+            callback: &Option<Callback<T, R>>,
+        ) -> Option<Box<dyn FnMut(T) -> R>> {
+            callback
+                .clone()
+                .map(|callback| Box::new(move |event| callback.emit(event)) as Box<_>)
+        }
+
+        EventHandlers {
+            on_click: handler(&self.on_click),
+            on_hover: handler(&self.on_hover),
+            on_unhover: handler(&self.on_unhover),
+            on_selected: handler(&self.on_selected),
+            on_relayout: handler(&self.on_relayout),
+            on_restyle: handler(&self.on_restyle),
+            on_legend_click: handler(&self.on_legend_click),
+            on_double_click: self
+                .on_double_click
+                .clone()
+                .map(|callback| Box::new(move || callback.emit(())) as Box<_>),
+        }
+    }
+}
+
+/// A `div` with a chart of the `plot` property. The chart is redrawn with the
+/// plotly.js `react` function when the properties change, follows the size of
+/// the `div` and is purged when the component is unmounted.
+#[function_component(PlotlyChart)]
+pub fn plotly_chart(props: &PlotlyChartProps) -> Html {
+    let node = use_node_ref();
+    let chart = use_mut_ref(|| None::<Chart>);
+
+    {
+        let node = node.clone();
+        let chart = chart.clone();
+        use_effect_with(props.clone(), move |props| {
+            let mut chart = chart.borrow_mut();
+            if chart.is_none() {
+                *chart = node.cast::<HtmlElement>().map(Chart::new);
+            }
+            if let Some(chart) = chart.as_ref() {
+                chart.render((*props.plot).clone(), props.event_handlers());
+            }
+        });
+    }
+    use_effect_with((), move |_| move || drop(chart.borrow_mut().take()));
+
+    html! {
+        <div ref={node} id={props.id.clone()} class={props.class.clone()}></div>
+    }
+}
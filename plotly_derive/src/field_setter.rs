@@ -1,4 +1,4 @@
-use darling::{ast, FromDeriveInput, FromField, FromMeta};
+use darling::{ast, util::Flag, FromDeriveInput, FromField, FromMeta};
 use proc_macro2::{Ident, TokenStream};
 use quote::{quote, ToTokens, TokenStreamExt};
 use syn::{DeriveInput, GenericArgument, Generics, TypeGenerics, TypeParamBound};
@@ -245,6 +245,10 @@ struct FieldReceiver {
     #[darling(default)]
     skip: bool,
 
+    /// Marks the `#[serde(flatten)]` map field holding attributes set through
+    /// the generated `extra` method.
+    extra: Flag,
+
     #[darling(default)]
     default: Option<String>,
 }
@@ -292,6 +296,27 @@ impl FieldReceiver {
             (quote![Self], quote![self])
         };
 
+        if self.extra.is_present() {
+            let setter = quote! {
+                /// Sets an attribute that is not (yet) modelled by this struct. The
+                /// value is merged into the serialized object under `key`,
+                /// overriding any typed attribute of the same name. This allows
+                /// newer plotly.js features to be used before they are supported
+                /// by this crate.
+                pub fn extra(
+                    mut self,
+                    key: impl AsRef<str>,
+                    value: impl Into<serde_json::Value>,
+                ) -> #return_ty {
+                    self.#field_ident
+                        .get_or_insert_with(Default::default)
+                        .insert(key.as_ref().to_owned(), value.into());
+                    #return_stmt
+                }
+            };
+            return (setter, quote![]);
+        }
+
         let field_type = FieldType::infer(field_ty);
 
         let (value_type, value_convert, array_value_convert) = match &field_type {
@@ -35,12 +35,6 @@ struct KaleidoResult {
     version: Option<String>,
 }
 
-impl KaleidoResult {
-    fn from(result: &str) -> KaleidoResult {
-        serde_json::from_str(result).unwrap()
-    }
-}
-
 #[derive(Serialize)]
 struct PlotData<'a> {
     // TODO: as with `data`, it would be much better if this were a plotly::ImageFormat, but
@@ -76,25 +70,38 @@ pub struct Kaleido {
 }
 
 impl Kaleido {
+    /// Locate the Kaleido binary installed by the build script.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the binary cannot be found, see `Kaleido::try_new`.
     pub fn new() -> Kaleido {
-        let path = match Kaleido::binary_path() {
-            Ok(path) => path,
+        match Kaleido::try_new() {
+            Ok(kaleido) => kaleido,
             Err(msg) => panic!("{}", msg),
-        };
+        }
+    }
 
-        Kaleido { cmd_path: path }
+    /// Locate the Kaleido binary installed by the build script, returning an
+    /// error if it cannot be found.
+    pub fn try_new() -> Result<Kaleido, Box<dyn std::error::Error>> {
+        let path = Kaleido::binary_path()?;
+        Ok(Kaleido { cmd_path: path })
     }
 
     fn root_dir() -> Result<PathBuf, &'static str> {
         let project_dirs = ProjectDirs::from("org", "plotly", "kaleido")
-            .expect("Could not create plotly_kaleido config directory.");
+            .ok_or("could not find the plotly_kaleido config directory")?;
         Ok(project_dirs.config_dir().into())
     }
 
     #[cfg(target_os = "linux")]
     fn binary_path() -> Result<PathBuf, &'static str> {
         let mut p = Kaleido::root_dir()?;
-        p = p.join("kaleido").canonicalize().unwrap();
+        p = p
+            .join("kaleido")
+            .canonicalize()
+            .map_err(|_| "could not find kaleido executable in path")?;
         if !p.exists() {
             return Err("could not find kaleido executable in path");
         }
@@ -104,7 +111,10 @@ impl Kaleido {
     #[cfg(target_os = "macos")]
     fn binary_path() -> Result<PathBuf, &'static str> {
         let mut p = Kaleido::root_dir()?;
-        p = p.join("kaleido").canonicalize().unwrap();
+        p = p
+            .join("kaleido")
+            .canonicalize()
+            .map_err(|_| "could not find kaleido executable in path")?;
         if !p.exists() {
             return Err("could not find kaleido executable in path");
         }
@@ -133,12 +143,26 @@ impl Kaleido {
         let mut dst = PathBuf::from(dst);
         dst.set_extension(format);
 
-        let p = self.cmd_path.as_path();
-        let p = p.to_str().unwrap();
-        let p = String::from(p);
+        let data = self.image_data(plotly_data, format, width, height, scale)?;
+        let mut file = File::create(dst.as_path())?;
+        file.write_all(&data)?;
+        file.flush()?;
 
-        let process = Command::new(p.as_str())
-            .current_dir(self.cmd_path.parent().unwrap())
+        Ok(())
+    }
+
+    /// Convert the plot to an image of the given format, like `save`, but
+    /// return the contents of the image rather than writing them to a file.
+    pub fn image_data(
+        &self,
+        plotly_data: &Value,
+        format: &str,
+        width: usize,
+        height: usize,
+        scale: f64,
+    ) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        let process = Command::new(&self.cmd_path)
+            .current_dir(self.cmd_path.parent().unwrap_or_else(|| Path::new(".")))
             .args([
                 "plotly",
                 "--disable-gpu",
@@ -151,32 +175,41 @@ impl Kaleido {
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn()
-            .expect("failed to spawn Kaleido binary");
+            .map_err(|e| format!("failed to spawn Kaleido binary: {}", e))?;
 
         {
             let plot_data = PlotData::new(plotly_data, format, width, height, scale).to_json();
-            let mut process_stdin = process.stdin.unwrap();
+            let mut process_stdin = process.stdin.ok_or("couldn't open Kaleido stdin")?;
             process_stdin
                 .write_all(plot_data.as_bytes())
-                .expect("couldn't write to Kaleido stdin");
+                .map_err(|e| format!("couldn't write to Kaleido stdin: {}", e))?;
             process_stdin.flush()?;
         }
 
-        let output_lines = BufReader::new(process.stdout.unwrap()).lines();
-        for line in output_lines.flatten() {
-            let res = KaleidoResult::from(line.as_str());
+        let mut image = None;
+        let mut message = None;
+        let stdout = process.stdout.ok_or("couldn't open Kaleido stdout")?;
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            // Kaleido reports its results as JSON lines, and other output is
+            // ignored.
+            let Ok(res) = serde_json::from_str::<KaleidoResult>(&line) else {
+                continue;
+            };
             if let Some(image_data) = res.result {
                 let data: Vec<u8> = match format {
                     "svg" | "eps" => image_data.as_bytes().to_vec(),
-                    _ => base64::decode(image_data).unwrap(),
+                    _ => base64::decode(image_data)?,
                 };
-                let mut file = File::create(dst.as_path())?;
-                file.write_all(&data)?;
-                file.flush()?;
+                image = Some(data);
+            } else if res.message.is_some() {
+                message = res.message;
             }
         }
 
-        Ok(())
+        image.ok_or_else(|| {
+            let message = message.unwrap_or_else(|| "no image was returned".to_string());
+            format!("Kaleido failed to export the plot: {}", message).into()
+        })
     }
 }
 
@@ -297,4 +330,24 @@ mod tests {
         assert!(r.is_ok());
         assert!(std::fs::remove_file(dst.as_path()).is_ok());
     }
+
+    #[test]
+    fn test_image_data_svg() {
+        let test_plot = create_test_plot();
+        let k = Kaleido::new();
+        let data = k.image_data(&test_plot, "svg", 1200, 900, 4.5).unwrap();
+        assert!(String::from_utf8(data).unwrap().starts_with("<svg"));
+    }
+
+    #[test]
+    fn test_image_data_missing_binary() {
+        let test_plot = create_test_plot();
+        let k = Kaleido {
+            cmd_path: PathBuf::from("/nonexistent/kaleido"),
+        };
+        let error = k.image_data(&test_plot, "png", 1200, 900, 4.5).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("failed to spawn Kaleido binary"));
+    }
 }